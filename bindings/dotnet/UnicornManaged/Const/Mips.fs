@@ -198,7 +198,8 @@ module Mips =
     let UC_MIPS_REG_CP0_CONFIG3 = 137
     let UC_MIPS_REG_CP0_USERLOCAL = 138
     let UC_MIPS_REG_CP0_STATUS = 139
-    let UC_MIPS_REG_ENDING = 140
+    let UC_MIPS_REG_FCR31 = 140
+    let UC_MIPS_REG_ENDING = 141
     let UC_MIPS_REG_ZERO = 2
     let UC_MIPS_REG_AT = 3
     let UC_MIPS_REG_V0 = 4
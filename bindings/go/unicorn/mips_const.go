@@ -193,7 +193,8 @@ const (
 	MIPS_REG_CP0_CONFIG3 = 137
 	MIPS_REG_CP0_USERLOCAL = 138
 	MIPS_REG_CP0_STATUS = 139
-	MIPS_REG_ENDING = 140
+	MIPS_REG_FCR31 = 140
+	MIPS_REG_ENDING = 141
 	MIPS_REG_ZERO = 2
 	MIPS_REG_AT = 3
 	MIPS_REG_V0 = 4
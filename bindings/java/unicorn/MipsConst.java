@@ -195,7 +195,8 @@ public interface MipsConst {
    public static final int UC_MIPS_REG_CP0_CONFIG3 = 137;
    public static final int UC_MIPS_REG_CP0_USERLOCAL = 138;
    public static final int UC_MIPS_REG_CP0_STATUS = 139;
-   public static final int UC_MIPS_REG_ENDING = 140;
+   public static final int UC_MIPS_REG_FCR31 = 140;
+   public static final int UC_MIPS_REG_ENDING = 141;
    public static final int UC_MIPS_REG_ZERO = 2;
    public static final int UC_MIPS_REG_AT = 3;
    public static final int UC_MIPS_REG_V0 = 4;
@@ -193,7 +193,8 @@ module UnicornEngine
 	UC_MIPS_REG_CP0_CONFIG3 = 137
 	UC_MIPS_REG_CP0_USERLOCAL = 138
 	UC_MIPS_REG_CP0_STATUS = 139
-	UC_MIPS_REG_ENDING = 140
+	UC_MIPS_REG_FCR31 = 140
+	UC_MIPS_REG_ENDING = 141
 	UC_MIPS_REG_ZERO = 2
 	UC_MIPS_REG_AT = 3
 	UC_MIPS_REG_V0 = 4
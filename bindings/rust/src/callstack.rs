@@ -0,0 +1,353 @@
+//! Shadow call stack.
+//!
+//! Once enabled with `Unicorn::call_stack_enable`, every executed instruction is
+//! checked for being a call or a return:
+//!
+//! | Arch   | Calls                           | Returns                                  |
+//! |--------|---------------------------------|------------------------------------------|
+//! | x86    | `CALL`                          | `RET`                                    |
+//! | ARM    | `BL`, `BLX`                     | `BX LR`, `MOV PC, LR`, `POP {.., PC}`    |
+//! | ARM64  | `BL`, `BLR`                     | `RET`                                    |
+//! | MIPS   | `JAL`, `JALR`, `BAL`            | `JR RA`                                  |
+//! | RISC-V | `JAL`/`JALR` linking `ra`/`t0`  | `JALR`/`C.JR` through `ra`/`t0`          |
+//! | PPC    | `BL`, `BCL`, `BCTRL`, `BLRL`    | `BLR`                                    |
+//!
+//! Conditional calls and returns only count when their condition holds: the
+//! condition field of A32 instructions is checked against the CPSR flags, the
+//! branch condition of `BLTZAL`, `BGEZAL` and `BCL` against the registers they
+//! test. Thumb instructions skipped by an `IT` block do not reach the hook.
+//!
+//! A return pops the frame whose return address it jumps to, along with frames that
+//! were left without a return, e.g. by `longjmp`. A return to an address that is not
+//! on the shadow stack means the return address was overwritten, which is reported
+//! as `CrashKind::StackSmash` if requested.
+
+use crate::crash::{is_64bit, read_ptr, CrashKind};
+use crate::unicorn_const::{Arch, Mode, Query};
+use crate::Unicorn;
+use crate::{
+    ffi, RegisterARM, RegisterARM64, RegisterMIPS, RegisterPPC, RegisterRISCV, RegisterX86,
+};
+use alloc::vec::Vec;
+
+/// A call that has not returned yet.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CallFrame {
+    /// Address of the call instruction.
+    pub call_site: u64,
+    /// Address the callee is expected to return to.
+    pub return_address: u64,
+}
+
+/// State of the shadow call stack.
+#[derive(Debug, Clone)]
+pub struct CallStack {
+    /// Outermost frame first.
+    pub(crate) frames: Vec<CallFrame>,
+    pub(crate) detect_smashing: bool,
+    pub(crate) hook: ffi::uc_hook,
+}
+
+enum Event {
+    Call { return_address: u64 },
+    Return { target: u64 },
+}
+
+/// Update the shadow call stack for the instruction at `address`.
+pub(crate) fn on_insn<D>(uc: &mut Unicorn<D>, address: u64, size: u32) {
+    let event = match classify(uc, address, size) {
+        Some(event) => event,
+        None => return,
+    };
+    let stack = match uc.inner_mut().call_stack.as_mut() {
+        Some(stack) => stack,
+        None => return,
+    };
+    match event {
+        Event::Call { return_address } => stack.frames.push(CallFrame {
+            call_site: address,
+            return_address,
+        }),
+        Event::Return { target } => {
+            // ignore the thumb bit, it is not part of the address
+            let matches = |frame: &CallFrame| frame.return_address & !1 == target & !1;
+            if let Some(pos) = stack.frames.iter().rposition(matches) {
+                stack.frames.truncate(pos);
+            } else if let (Some(top), true) = (stack.frames.last(), stack.detect_smashing) {
+                let kind = CrashKind::StackSmash {
+                    expected: top.return_address,
+                    actual: target,
+                };
+                uc.record_crash(address, kind);
+                let _ = uc.emu_stop();
+            }
+        }
+    }
+}
+
+fn mode<D>(uc: &Unicorn<D>) -> Mode {
+    uc.query(Query::MODE)
+        .map(|mode| Mode::from_bits_truncate(mode as i32))
+        .unwrap_or(Mode::LITTLE_ENDIAN)
+}
+
+fn reg<D, T: Into<i32>>(uc: &Unicorn<D>, reg: T) -> u64 {
+    uc.reg_read(reg).unwrap_or(0)
+}
+
+/// Whether the A32 condition `cond` holds for the flags in `cpsr`.
+fn condition_passed(cond: u32, cpsr: u64) -> bool {
+    let (n, z, c, v) = (
+        cpsr & (1 << 31) != 0,
+        cpsr & (1 << 30) != 0,
+        cpsr & (1 << 29) != 0,
+        cpsr & (1 << 28) != 0,
+    );
+    match cond {
+        0x0 => z,
+        0x1 => !z,
+        0x2 => c,
+        0x3 => !c,
+        0x4 => n,
+        0x5 => !n,
+        0x6 => v,
+        0x7 => !v,
+        0x8 => c && !z,
+        0x9 => !c || z,
+        0xa => n == v,
+        0xb => n != v,
+        0xc => !z && n == v,
+        0xd => z || n != v,
+        _ => true,
+    }
+}
+
+/// Whether a PPC `BC` with the options `bo` and the condition bit `bi` branches. The
+/// hook runs before the instruction decrements CTR.
+fn ppc_branch_taken<D>(uc: &Unicorn<D>, bo: u32, bi: u32) -> bool {
+    let ctr_ok = bo & 0x04 != 0 || {
+        let ctr = reg(uc, RegisterPPC::CTR).wrapping_sub(1);
+        let ctr = if is_64bit(uc) { ctr } else { ctr & 0xffff_ffff };
+        (ctr != 0) != (bo & 0x02 != 0)
+    };
+    let cond_ok = bo & 0x10 != 0 || {
+        let field = reg(uc, RegisterPPC::CR0 as i32 + (bi / 4) as i32);
+        (field >> (3 - bi % 4) & 1 != 0) == (bo & 0x08 != 0)
+    };
+    ctr_ok && cond_ok
+}
+
+fn classify<D>(uc: &Unicorn<D>, address: u64, size: u32) -> Option<Event> {
+    let mut buf = [0u8; 16];
+    let bytes = &mut buf[..size.min(16) as usize];
+    uc.mem_read(address, bytes).ok()?;
+    let bytes = &*bytes;
+    let big_endian = mode(uc).contains(Mode::BIG_ENDIAN);
+    let word = |offset: usize| -> Option<u32> {
+        let b: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(b)
+        } else {
+            u32::from_le_bytes(b)
+        })
+    };
+    let half = |offset: usize| -> Option<u16> {
+        let b: [u8; 2] = bytes.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if big_endian {
+            u16::from_be_bytes(b)
+        } else {
+            u16::from_le_bytes(b)
+        })
+    };
+    let next = address + u64::from(size);
+    let call = Some(Event::Call {
+        return_address: next,
+    });
+    let ret = |target: u64| Some(Event::Return { target });
+
+    match uc.get_arch() {
+        Arch::X86 => {
+            let long_mode = is_64bit(uc);
+            let is_prefix = |b: u8| {
+                matches!(
+                    b,
+                    0x26 | 0x2e | 0x36 | 0x3e | 0x64 | 0x65 | 0x66 | 0x67 | 0xf2 | 0xf3
+                ) || (long_mode && (0x40..=0x4f).contains(&b))
+            };
+            let i = bytes.iter().position(|&b| !is_prefix(b))?;
+            match bytes[i] {
+                0xe8 => call,
+                0xff if bytes.get(i + 1).map(|modrm| (modrm >> 3) & 7) == Some(2) => call,
+                0xc2 | 0xc3 => {
+                    let sp = if long_mode {
+                        reg(uc, RegisterX86::RSP)
+                    } else {
+                        reg(uc, RegisterX86::ESP)
+                    };
+                    ret(read_ptr(uc, sp)?)
+                }
+                _ => None,
+            }
+        }
+        // the translator skips the hook for instructions whose IT condition fails
+        Arch::ARM if reg(uc, RegisterARM::CPSR) & 0x20 != 0 => {
+            let hw1 = half(0)?;
+            let sp = reg(uc, RegisterARM::SP);
+            if size == 2 {
+                match hw1 {
+                    // BLX Rm
+                    _ if hw1 & 0xff87 == 0x4780 => call,
+                    // BX LR
+                    0x4770 => ret(reg(uc, RegisterARM::LR)),
+                    // POP {.., PC}
+                    _ if hw1 & 0xff00 == 0xbd00 => {
+                        ret(read_ptr(uc, sp + 4 * u64::from((hw1 & 0xff).count_ones()))?)
+                    }
+                    _ => None,
+                }
+            } else {
+                let hw2 = half(2)?;
+                match (hw1, hw2) {
+                    // BL, BLX
+                    _ if hw1 & 0xf800 == 0xf000 && hw2 & 0xc000 == 0xc000 => call,
+                    // POP.W {.., PC}
+                    (0xe8bd, _) if hw2 & 0x8000 != 0 => {
+                        ret(read_ptr(uc, sp + 4 * u64::from(hw2.count_ones() - 1))?)
+                    }
+                    // LDR PC, [SP], #4
+                    (0xf85d, 0xfb04) => ret(read_ptr(uc, sp)?),
+                    _ => None,
+                }
+            }
+        }
+        Arch::ARM => {
+            let w = word(0)?;
+            let sp = reg(uc, RegisterARM::SP);
+            if !condition_passed(w >> 28, reg(uc, RegisterARM::CPSR)) {
+                return None;
+            }
+            match w {
+                // BLX imm
+                _ if w & 0xfe00_0000 == 0xfa00_0000 => call,
+                // BL
+                _ if w & 0x0f00_0000 == 0x0b00_0000 && w >> 28 != 0xf => call,
+                // BLX Rm
+                _ if w & 0x0fff_fff0 == 0x012f_ff30 => call,
+                // BX LR, MOV PC, LR
+                _ if w & 0x0fff_ffff == 0x012f_ff1e || w & 0x0fff_ffff == 0x01a0_f00e => {
+                    ret(reg(uc, RegisterARM::LR))
+                }
+                // LDMIA SP!, {.., PC}
+                _ if w & 0x0fff_8000 == 0x08bd_8000 => ret(read_ptr(
+                    uc,
+                    sp + 4 * u64::from((w & 0xffff).count_ones() - 1),
+                )?),
+                // LDR PC, [SP], #4
+                _ if w & 0x0fff_ffff == 0x049d_f004 => ret(read_ptr(uc, sp)?),
+                _ => None,
+            }
+        }
+        Arch::ARM64 => {
+            // instructions are little endian even on big endian targets
+            let w = u32::from_le_bytes(bytes.get(0..4)?.try_into().ok()?);
+            match w {
+                // BL, BLR
+                _ if w & 0xfc00_0000 == 0x9400_0000 || w & 0xffff_fc1f == 0xd63f_0000 => call,
+                // RET Xn
+                _ if w & 0xffff_fc1f == 0xd65f_0000 => {
+                    let n = (w >> 5) & 0x1f;
+                    let target = match n {
+                        29 => reg(uc, RegisterARM64::X29),
+                        30 => reg(uc, RegisterARM64::X30),
+                        _ => reg(uc, RegisterARM64::X0 as i32 + n as i32),
+                    };
+                    ret(target)
+                }
+                _ => None,
+            }
+        }
+        Arch::MIPS => {
+            let w = word(0)?;
+            // the instruction in the delay slot runs before the callee
+            let call = Some(Event::Call {
+                return_address: address + 8,
+            });
+            match w >> 26 {
+                // JAL
+                3 => call,
+                // JALR
+                0 if w & 0x3f == 9 && (w >> 11) & 0x1f != 0 => call,
+                // JR RA, JALR ZERO, RA
+                0 if w == 0x03e0_0008 || w == 0x03e0_0009 => ret(reg(uc, RegisterMIPS::RA)),
+                // BLTZAL, BGEZAL (BAL)
+                1 if matches!((w >> 16) & 0x1f, 0x10 | 0x11) => {
+                    let rs = reg(uc, RegisterMIPS::R0 as i32 + ((w >> 21) & 0x1f) as i32);
+                    let negative = if mode(uc).contains(Mode::MIPS64) {
+                        (rs as i64) < 0
+                    } else {
+                        (rs as u32 as i32) < 0
+                    };
+                    // bit 16 selects BGEZAL
+                    if negative != (w & 0x1_0000 != 0) {
+                        call
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            }
+        }
+        Arch::RISCV => {
+            // x1 (ra) and x5 (t0) are the link registers of the calling convention
+            let is_link = |r: u32| r == 1 || r == 5;
+            let x = |r: u32| reg(uc, RegisterRISCV::X0 as i32 + r as i32);
+            if size == 2 {
+                let h = u32::from(half(0)?);
+                let rs1 = (h >> 7) & 0x1f;
+                match h {
+                    // C.JR
+                    _ if h & 0xf07f == 0x8002 && is_link(rs1) => ret(x(rs1)),
+                    // C.JALR
+                    _ if h & 0xf07f == 0x9002 && rs1 != 0 => call,
+                    // C.JAL, RV32 only
+                    _ if h & 0xe003 == 0x2001 && !is_64bit(uc) => call,
+                    _ => None,
+                }
+            } else {
+                let w = word(0)?;
+                let (opcode, rd, rs1) = (w & 0x7f, (w >> 7) & 0x1f, (w >> 15) & 0x1f);
+                match opcode {
+                    // JAL, JALR
+                    0x6f | 0x67 if is_link(rd) => call,
+                    // JALR x0, imm(ra)
+                    0x67 if rd == 0 && is_link(rs1) => {
+                        let imm = ((w as i32) >> 20) as i64;
+                        ret(x(rs1).wrapping_add(imm as u64))
+                    }
+                    _ => None,
+                }
+            }
+        }
+        Arch::PPC => {
+            let w = word(0)?;
+            match w {
+                // BL
+                _ if w >> 26 == 18 && w & 1 == 1 => call,
+                // BCL
+                _ if w >> 26 == 16 && w & 1 == 1 => {
+                    if ppc_branch_taken(uc, (w >> 21) & 0x1f, (w >> 16) & 0x1f) {
+                        call
+                    } else {
+                        None
+                    }
+                }
+                // BCTRL, BLRL
+                0x4e80_0421 | 0x4e80_0021 => call,
+                // BLR
+                0x4e80_0020 => ret(reg(uc, RegisterPPC::LR)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
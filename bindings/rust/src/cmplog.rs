@@ -0,0 +1,235 @@
+//! Comparison logging for input-to-state mutations.
+//!
+//! Once enabled with `Unicorn::cmplog_enable`, the operands of compare instructions are
+//! recorded through a `TcgOpCode::SUB` hook with `TcgOpFlag::CMP`. Comparison routines
+//! such as `memcmp` or `strcmp` are recorded as well once their entry points are
+//! registered with `Unicorn::cmplog_add_routine`.
+//!
+//! Only the x86 translator marks its compare instructions with `TcgOpFlag::CMP` so far,
+//! on every other architecture only the routines are recorded.
+//!
+//! The operands end up in a fixed-size table laid out like the cmp map of AFL++: every
+//! compare instruction and every call site of a routine owns the entry its address
+//! hashes to, and every entry keeps the operands of its last `CMPLOG_MAP_H` hits. A
+//! mutator looks for either operand in the input and replaces it with the other one.
+//! The table is cleared by `CmpLog::reset`, which `Harness::run` does before every
+//! input. Every entry takes a little over 2 KiB, so the width of the table is up to
+//! the caller.
+
+use crate::{ffi, Unicorn};
+use alloc::{boxed::Box, vec::Vec};
+
+/// Hits whose operands are kept per entry.
+pub const CMPLOG_MAP_H: usize = 32;
+
+/// Bytes of the operands of a routine that are kept.
+pub const CMPLOG_RTN_LEN: usize = 32;
+
+/// Default number of entries of the table, about 2 MiB in total.
+pub const DEFAULT_WIDTH: usize = 1 << 10;
+
+/// What an entry of the table records.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CmpKind {
+    /// A compare instruction.
+    Insn,
+    /// A call to a comparison routine.
+    Routine,
+}
+
+/// Comparison routines whose operands can be recorded.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Routine {
+    /// `memcmp(s1, s2, n)` and alike, e.g. `bcmp`.
+    Memcmp,
+    /// `strcmp(s1, s2)` and alike, e.g. `strcasecmp`.
+    Strcmp,
+    /// `strncmp(s1, s2, n)` and alike, e.g. `strncasecmp`.
+    Strncmp,
+}
+
+/// Operands of a single comparison.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct CmpOperands {
+    /// Operands of instructions are stored little endian, those of routines as they
+    /// were found in guest memory.
+    pub v0: [u8; CMPLOG_RTN_LEN],
+    pub v1: [u8; CMPLOG_RTN_LEN],
+    pub v0_len: u8,
+    pub v1_len: u8,
+}
+
+impl CmpOperands {
+    const EMPTY: CmpOperands = CmpOperands {
+        v0: [0; CMPLOG_RTN_LEN],
+        v1: [0; CMPLOG_RTN_LEN],
+        v0_len: 0,
+        v1_len: 0,
+    };
+
+    /// Return the bytes of the first operand.
+    #[must_use]
+    pub fn v0(&self) -> &[u8] {
+        &self.v0[..self.v0_len as usize]
+    }
+
+    /// Return the bytes of the second operand.
+    #[must_use]
+    pub fn v1(&self) -> &[u8] {
+        &self.v1[..self.v1_len as usize]
+    }
+}
+
+/// An entry of the table.
+#[derive(Debug, Clone)]
+pub struct CmpEntry {
+    pub kind: CmpKind,
+    /// Address of the compare instruction, or return address of the call to the routine.
+    pub address: u64,
+    /// Times the entry was hit since the last reset.
+    pub hits: u32,
+    /// Operands of hit `n` are kept in slot `n % CMPLOG_MAP_H`.
+    pub slots: [CmpOperands; CMPLOG_MAP_H],
+}
+
+impl CmpEntry {
+    const EMPTY: CmpEntry = CmpEntry {
+        kind: CmpKind::Insn,
+        address: 0,
+        hits: 0,
+        slots: [CmpOperands::EMPTY; CMPLOG_MAP_H],
+    };
+
+    /// Return the operands recorded for this entry.
+    #[must_use]
+    pub fn operands(&self) -> &[CmpOperands] {
+        &self.slots[..(self.hits as usize).min(CMPLOG_MAP_H)]
+    }
+}
+
+/// Table of the comparisons recorded since the last reset.
+#[derive(Debug, Clone)]
+pub struct CmpLog {
+    entries: Box<[CmpEntry]>,
+    pub(crate) hooks: Vec<ffi::uc_hook>,
+}
+
+impl CmpLog {
+    pub(crate) fn new(width: usize, hooks: Vec<ffi::uc_hook>) -> CmpLog {
+        CmpLog {
+            entries: vec![CmpEntry::EMPTY; width].into_boxed_slice(),
+            hooks,
+        }
+    }
+
+    /// Return the number of entries of the table.
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return the index of the entry owned by `address`.
+    #[must_use]
+    pub fn index(&self, address: u64) -> usize {
+        // same hash as the edge coverage, spreads aligned addresses over the table
+        (((address >> 4) ^ (address << 8)) as usize) & (self.entries.len() - 1)
+    }
+
+    /// Return the entry owned by `address`, if it was hit since the last reset.
+    #[must_use]
+    pub fn entry(&self, address: u64) -> Option<&CmpEntry> {
+        Some(&self.entries[self.index(address)]).filter(|e| e.hits > 0 && e.address == address)
+    }
+
+    /// Return the entries that were hit since the last reset.
+    pub fn entries(&self) -> impl Iterator<Item = &CmpEntry> {
+        self.entries.iter().filter(|e| e.hits > 0)
+    }
+
+    /// Forget all recorded comparisons, e.g. before running the next input.
+    pub fn reset(&mut self) {
+        for entry in self.entries.iter_mut().filter(|e| e.hits > 0) {
+            entry.hits = 0;
+        }
+    }
+
+    /// Record the operands of a compare instruction of `bits` bits at `address`.
+    pub(crate) fn log_insn(&mut self, address: u64, arg1: u64, arg2: u64, bits: usize) {
+        let size = (bits / 8).clamp(1, 8);
+        let v0 = arg1.to_le_bytes();
+        let v1 = arg2.to_le_bytes();
+        self.log(CmpKind::Insn, address, &v0[..size], &v1[..size]);
+    }
+
+    /// Record the operands of a call to a routine returning to `call_site`.
+    pub(crate) fn log_routine(&mut self, call_site: u64, v0: &[u8], v1: &[u8]) {
+        self.log(CmpKind::Routine, call_site, v0, v1);
+    }
+
+    fn log(&mut self, kind: CmpKind, address: u64, v0: &[u8], v1: &[u8]) {
+        let index = self.index(address);
+        let entry = &mut self.entries[index];
+        if entry.hits == 0 {
+            entry.kind = kind;
+            entry.address = address;
+        } else if entry.address != address {
+            // the first comparison hashing to an entry keeps it until the next reset
+            return;
+        }
+        let slot = &mut entry.slots[entry.hits as usize % CMPLOG_MAP_H];
+        let (n0, n1) = (v0.len().min(CMPLOG_RTN_LEN), v1.len().min(CMPLOG_RTN_LEN));
+        slot.v0[..n0].copy_from_slice(&v0[..n0]);
+        slot.v1[..n1].copy_from_slice(&v1[..n1]);
+        slot.v0_len = n0 as u8;
+        slot.v1_len = n1 as u8;
+        entry.hits = entry.hits.saturating_add(1);
+    }
+}
+
+/// Record the operands of the call to `routine` that is about to run.
+pub(crate) fn on_routine<D>(uc: &mut Unicorn<D>, routine: Routine) {
+    let (s1, s2) = match (uc.function_arg0_val(), uc.function_arg1_val()) {
+        (Ok(s1), Ok(s2)) => (s1, s2),
+        _ => return,
+    };
+    let limit = match routine {
+        Routine::Strcmp => CMPLOG_RTN_LEN,
+        Routine::Memcmp | Routine::Strncmp => match uc.function_arg2_val() {
+            Ok(n) => (n as usize).min(CMPLOG_RTN_LEN),
+            Err(_) => return,
+        },
+    };
+    let call_site = match uc.func_return_addr() {
+        Ok(call_site) => call_site,
+        Err(_) => return,
+    };
+    let mut v0 = read_operand(uc, s1, limit);
+    let mut v1 = read_operand(uc, s2, limit);
+    if routine != Routine::Memcmp {
+        // keep the terminator, replacing it lets a mutator shorten strings
+        for v in [&mut v0, &mut v1] {
+            if let Some(nul) = v.iter().position(|&b| b == 0) {
+                v.truncate(nul + 1);
+            }
+        }
+    }
+    if let Some(log) = uc.inner_mut().cmplog.as_mut() {
+        log.log_routine(call_site, &v0, &v1);
+    }
+}
+
+/// Read up to `len` bytes at `address`, stopping at the first unmapped one.
+fn read_operand<D>(uc: &Unicorn<D>, address: u64, len: usize) -> Vec<u8> {
+    if let Ok(data) = uc.mem_read_as_vec(address, len) {
+        return data;
+    }
+    let mut data = Vec::with_capacity(len);
+    let mut byte = [0u8];
+    for a in address..address + len as u64 {
+        if uc.mem_read(a, &mut byte).is_err() {
+            break;
+        }
+        data.push(byte[0]);
+    }
+    data
+}
@@ -0,0 +1,290 @@
+//! Crash triage.
+//!
+//! Once enabled with `Unicorn::crash_reports_enable`, whenever emulation crashes, be it
+//! a memory fault, an invalid instruction or a crash reported by a hook through
+//! `Unicorn::set_crash_pc`, a `CrashReport` is recorded and can be retrieved with
+//! `Unicorn::crash_report` until the next `emu_start`. Reports carry a bucket hash over
+//! the innermost frames of the backtrace, so crashes of a fuzzing campaign can be
+//! deduplicated.
+
+use crate::unicorn_const::{uc_error, Arch, MemRegion, MemType, Mode, Query};
+use crate::Unicorn;
+use crate::{RegisterARM, RegisterARM64, RegisterMIPS, RegisterPPC, RegisterRISCV, RegisterX86};
+use alloc::vec::Vec;
+
+/// Number of frames the bucket hash of a `CrashReport` covers.
+pub const BUCKET_FRAMES: usize = 5;
+
+/// Frames after which the frame-pointer walk gives up.
+const MAX_FRAMES: usize = 64;
+
+/// Why emulation crashed.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CrashKind {
+    /// Unmapped or protected memory was accessed.
+    Fault { access: MemType, address: u64 },
+    /// Emulation failed, e.g. with `uc_error::INSN_INVALID`.
+    Error(uc_error),
+    /// A hook reported the crash through `Unicorn::set_crash_pc`.
+    Reported,
+    /// A return went to `actual` instead of the return address `expected` of the
+    /// innermost frame of the shadow call stack.
+    StackSmash { expected: u64, actual: u64 },
+}
+
+/// State of the guest at the time of a crash.
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    /// PC of the crashing instruction.
+    pub pc: u64,
+    pub kind: CrashKind,
+    /// General purpose registers of the architecture.
+    pub registers: Vec<(&'static str, u64)>,
+    /// Return addresses, innermost first, starting with `pc`. Taken from the shadow
+    /// call stack if it is enabled, from the frame pointer chain otherwise; MIPS has no
+    /// frame pointer chain, so without the shadow call stack this is just `pc` there.
+    pub backtrace: Vec<u64>,
+    /// Region holding the faulting address, or the PC for other crashes.
+    pub region: Option<MemRegion>,
+    /// Hash over the kind and the first `BUCKET_FRAMES` frames of the backtrace.
+    pub bucket: u64,
+}
+
+impl CrashReport {
+    /// Take a report of the current state of `uc`, crashed at `pc`.
+    pub fn capture<D>(uc: &Unicorn<D>, pc: u64, kind: CrashKind) -> CrashReport {
+        let address = match kind {
+            CrashKind::Fault { address, .. } => address,
+            _ => pc,
+        };
+        let region = uc.mem_regions().ok().and_then(|regions| {
+            regions
+                .into_iter()
+                .find(|r| r.begin <= address && address <= r.end)
+        });
+        let mut report = CrashReport {
+            pc,
+            kind,
+            registers: registers(uc),
+            backtrace: backtrace(uc, pc),
+            region,
+            bucket: 0,
+        };
+        report.bucket = report.bucket_hash(BUCKET_FRAMES);
+        report
+    }
+
+    /// Hash the kind of the crash and the first `frames` frames of the backtrace.
+    ///
+    /// Fault addresses and register values are left out, they tend to differ between
+    /// inputs triggering the same bug.
+    #[must_use]
+    pub fn bucket_hash(&self, frames: usize) -> u64 {
+        let kind = match self.kind {
+            CrashKind::Fault { access, .. } => access as u64,
+            CrashKind::Error(err) => 0x100 | err as u64,
+            CrashKind::Reported => 0x200,
+            CrashKind::StackSmash { .. } => 0x300,
+        };
+        // FNV-1a, stable across runs and builds
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for value in core::iter::once(kind).chain(self.backtrace.iter().take(frames).copied()) {
+            for byte in value.to_le_bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash
+    }
+}
+
+fn current_mode<D>(uc: &Unicorn<D>) -> Mode {
+    uc.query(Query::MODE)
+        .map(|mode| Mode::from_bits_truncate(mode as i32))
+        .unwrap_or(Mode::LITTLE_ENDIAN)
+}
+
+pub(crate) fn is_64bit<D>(uc: &Unicorn<D>) -> bool {
+    let mode = current_mode(uc);
+    match uc.get_arch() {
+        Arch::ARM64 => true,
+        Arch::X86 => mode.contains(Mode::MODE_64),
+        Arch::RISCV => mode.contains(Mode::RISCV64),
+        Arch::PPC => mode.contains(Mode::PPC64),
+        Arch::MIPS => mode.contains(Mode::MIPS64),
+        _ => false,
+    }
+}
+
+/// Read a guest pointer, `None` if it is not mapped.
+pub(crate) fn read_ptr<D>(uc: &Unicorn<D>, address: u64) -> Option<u64> {
+    let size = if is_64bit(uc) { 8 } else { 4 };
+    let data = uc.mem_read_as_vec(address, size).ok()?;
+    let mut bytes = [0u8; 8];
+    if current_mode(uc).contains(Mode::BIG_ENDIAN) {
+        bytes[8 - size..].copy_from_slice(&data);
+        Some(u64::from_be_bytes(bytes))
+    } else {
+        bytes[..size].copy_from_slice(&data);
+        Some(u64::from_le_bytes(bytes))
+    }
+}
+
+const X86_32: [(&str, RegisterX86); 10] = [
+    ("eax", RegisterX86::EAX),
+    ("ebx", RegisterX86::EBX),
+    ("ecx", RegisterX86::ECX),
+    ("edx", RegisterX86::EDX),
+    ("esi", RegisterX86::ESI),
+    ("edi", RegisterX86::EDI),
+    ("ebp", RegisterX86::EBP),
+    ("esp", RegisterX86::ESP),
+    ("eip", RegisterX86::EIP),
+    ("eflags", RegisterX86::EFLAGS),
+];
+
+const X86_64: [(&str, RegisterX86); 18] = [
+    ("rax", RegisterX86::RAX),
+    ("rbx", RegisterX86::RBX),
+    ("rcx", RegisterX86::RCX),
+    ("rdx", RegisterX86::RDX),
+    ("rsi", RegisterX86::RSI),
+    ("rdi", RegisterX86::RDI),
+    ("rbp", RegisterX86::RBP),
+    ("rsp", RegisterX86::RSP),
+    ("r8", RegisterX86::R8),
+    ("r9", RegisterX86::R9),
+    ("r10", RegisterX86::R10),
+    ("r11", RegisterX86::R11),
+    ("r12", RegisterX86::R12),
+    ("r13", RegisterX86::R13),
+    ("r14", RegisterX86::R14),
+    ("r15", RegisterX86::R15),
+    ("rip", RegisterX86::RIP),
+    ("rflags", RegisterX86::RFLAGS),
+];
+
+const GPR_NAMES: [&str; 32] = [
+    "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "r11", "r12", "r13", "r14",
+    "r15", "r16", "r17", "r18", "r19", "r20", "r21", "r22", "r23", "r24", "r25", "r26", "r27",
+    "r28", "r29", "r30", "r31",
+];
+
+const X_NAMES: [&str; 32] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "x31",
+];
+
+/// Read the general purpose registers of the architecture.
+fn registers<D>(uc: &Unicorn<D>) -> Vec<(&'static str, u64)> {
+    // register ids of consecutive registers are consecutive as well
+    let numbered = |names: &[&'static str], first: i32, extra: &[(&'static str, i32)]| {
+        names
+            .iter()
+            .zip(first..)
+            .map(|(&name, reg)| (name, reg))
+            .chain(extra.iter().copied())
+            .filter_map(|(name, reg)| uc.reg_read(reg).ok().map(|value| (name, value)))
+            .collect()
+    };
+    match uc.get_arch() {
+        Arch::X86 => {
+            let regs: &[(&str, RegisterX86)] = if is_64bit(uc) { &X86_64 } else { &X86_32 };
+            regs.iter()
+                .filter_map(|&(name, reg)| uc.reg_read(reg).ok().map(|value| (name, value)))
+                .collect()
+        }
+        Arch::ARM => numbered(
+            &GPR_NAMES[..13],
+            RegisterARM::R0 as i32,
+            &[
+                ("sp", RegisterARM::SP as i32),
+                ("lr", RegisterARM::LR as i32),
+                ("pc", RegisterARM::PC as i32),
+                ("cpsr", RegisterARM::CPSR as i32),
+            ],
+        ),
+        Arch::ARM64 => numbered(
+            &X_NAMES[..29],
+            RegisterARM64::X0 as i32,
+            &[
+                ("x29", RegisterARM64::X29 as i32),
+                ("x30", RegisterARM64::X30 as i32),
+                ("sp", RegisterARM64::SP as i32),
+                ("pc", RegisterARM64::PC as i32),
+            ],
+        ),
+        Arch::MIPS => numbered(
+            &GPR_NAMES,
+            RegisterMIPS::R0 as i32,
+            &[("pc", RegisterMIPS::PC as i32)],
+        ),
+        Arch::PPC => numbered(
+            &GPR_NAMES,
+            RegisterPPC::R0 as i32,
+            &[
+                ("lr", RegisterPPC::LR as i32),
+                ("pc", RegisterPPC::PC as i32),
+            ],
+        ),
+        Arch::RISCV => numbered(
+            &X_NAMES,
+            RegisterRISCV::X0 as i32,
+            &[("pc", RegisterRISCV::PC as i32)],
+        ),
+        _ => uc.get_pc().map(|pc| vec![("pc", pc)]).unwrap_or_default(),
+    }
+}
+
+/// Walk the shadow call stack or the frame pointer chain, starting with `pc`.
+///
+/// Frame records are expected as laid out by GCC with frame pointers enabled: `[fp]` =
+/// caller's fp and `[fp + ptr]` = return address on x86 (`ebp`/`rbp`), ARM64 (`x29`)
+/// and Thumb (`r7`); `[fp]` = return address and `[fp - ptr]` = caller's fp on ARM
+/// (`r11`), where fp points at the saved `lr`; `[fp - ptr]` = return address and
+/// `[fp - 2 * ptr]` = caller's fp on RISC-V (`s0`); the back chain of `r1` on PPC. MIPS
+/// has no frame records, so only `pc` is reported there.
+fn backtrace<D>(uc: &Unicorn<D>, pc: u64) -> Vec<u64> {
+    let mut frames = vec![pc];
+    if let Some(stack) = uc.call_stack() {
+        frames.extend(stack.iter().rev().map(|frame| frame.return_address));
+        return frames;
+    }
+    let ptr = if is_64bit(uc) { 8 } else { 4 };
+    let (fp_reg, prev_at, ret_at): (i32, i64, i64) = match uc.get_arch() {
+        Arch::X86 if ptr == 8 => (RegisterX86::RBP as i32, 0, 8),
+        Arch::X86 => (RegisterX86::EBP as i32, 0, 4),
+        Arch::ARM if uc.reg_read(RegisterARM::CPSR).unwrap_or(0) & 0x20 != 0 => {
+            (RegisterARM::R7 as i32, 0, 4)
+        }
+        Arch::ARM => (RegisterARM::R11 as i32, -4, 0),
+        Arch::ARM64 => (RegisterARM64::X29 as i32, 0, 8),
+        Arch::RISCV => (RegisterRISCV::S0 as i32, -2 * ptr, -ptr),
+        // the saved LR lives in the caller's frame, one or two words above its back chain
+        Arch::PPC => (RegisterPPC::R1 as i32, 0, if ptr == 8 { 16 } else { 4 }),
+        _ => return frames,
+    };
+    let mut fp = match uc.reg_read(fp_reg) {
+        Ok(fp) => fp,
+        Err(_) => return frames,
+    };
+    while fp != 0 && frames.len() < MAX_FRAMES {
+        let prev = match read_ptr(uc, fp.wrapping_add(prev_at as u64)) {
+            Some(prev) => prev,
+            None => break,
+        };
+        let ret_base = if uc.get_arch() == Arch::PPC { prev } else { fp };
+        let ret = match read_ptr(uc, ret_base.wrapping_add(ret_at as u64)) {
+            Some(ret) if ret != 0 => ret,
+            _ => break,
+        };
+        frames.push(ret);
+        // frames of callers live further up the stack, anything else is garbage
+        if prev <= fp {
+            break;
+        }
+        fp = prev;
+    }
+    frames
+}
@@ -0,0 +1,371 @@
+//! Basic block coverage in the drcov format.
+//!
+//! drcov is the coverage format of DynamoRIO, understood by Lighthouse, bncov and most
+//! other coverage plugins of disassemblers. A file lists the modules of the target, then
+//! every basic block that was executed as an offset into its module:
+//!
+//! ```text
+//! DRCOV VERSION: 2
+//! DRCOV FLAVOR: unicorn
+//! Module Table: version 2, count 1
+//! Columns: id, base, end, entry, checksum, timestamp, path
+//!   0, 0x0000000000001000, 0x0000000000002000, 0x0000000000000000, 0x00000000, 0x00000000, firmware.bin
+//! BB Table: 2 bbs
+//! <u32 offset, u16 size, u16 module id, little endian, for every block>
+//! ```
+//!
+//! Disassemblers match modules by the file name in the `path` column, so name modules
+//! after the files loaded into the guest. `Coverage::from_regions` names every mapped
+//! region after its address instead.
+//!
+//! ```rust,ignore
+//! let coverage = Coverage::start(&mut emu, vec![Module::new("firmware.bin", 0x1000, 0x1fff)])?;
+//! emu.emu_start(0x1000, 0x2000, 0, 0)?;
+//! coverage.borrow_mut().finish(&mut emu)?;
+//! std::fs::write("firmware.drcov", coverage.borrow().to_bytes())?;
+//! ```
+
+use crate::unicorn_const::uc_error;
+use crate::{ffi, Unicorn};
+use alloc::{collections::BTreeSet, format, rc::Rc, string::String, vec::Vec};
+use core::cell::RefCell;
+
+/// Flavor written to the header, readers do not care about it.
+const FLAVOR: &str = "unicorn";
+
+/// A module of the target, blocks are recorded relative to its base.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Module {
+    /// Path, or at least the file name, of the module.
+    pub name: String,
+    pub base: u64,
+    /// Last address of the module, inclusive like `MemRegion::end`.
+    pub end: u64,
+}
+
+impl Module {
+    pub fn new(name: &str, base: u64, end: u64) -> Module {
+        Module {
+            name: String::from(name),
+            base,
+            end,
+        }
+    }
+
+    fn contains(&self, address: u64) -> bool {
+        self.base <= address && address <= self.end
+    }
+}
+
+/// An executed basic block.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub struct BasicBlock {
+    /// Index of the module in `Coverage::modules`.
+    pub module: u16,
+    /// Offset of the block from the base of its module.
+    pub offset: u32,
+    pub size: u16,
+}
+
+/// Malformed drcov file.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DrcovError {
+    /// The header or the module table is missing or malformed.
+    BadHeader,
+    /// Line `line` of the module table is malformed.
+    BadModule { line: usize },
+    /// The block table is missing, written as text, or shorter than announced.
+    BadBlocks,
+    /// A block refers to a module that is not in the module table.
+    UnknownModule { module: u16 },
+}
+
+/// Basic blocks executed in a set of modules.
+#[derive(Debug, Clone, Default)]
+pub struct Coverage {
+    modules: Vec<Module>,
+    blocks: BTreeSet<BasicBlock>,
+    /// Module of the last recorded block, the next one is likely in it as well.
+    last: usize,
+    hooks: Vec<ffi::uc_hook>,
+}
+
+impl Coverage {
+    /// Create an empty coverage of `modules`.
+    pub fn new(modules: Vec<Module>) -> Coverage {
+        Coverage {
+            modules,
+            ..Default::default()
+        }
+    }
+
+    /// Create an empty coverage with a module for every region mapped in `emu`, named
+    /// after its address, e.g. `region_0x1000`.
+    pub fn from_regions<D>(emu: &Unicorn<D>) -> Result<Coverage, uc_error> {
+        let modules = emu
+            .mem_regions()?
+            .into_iter()
+            .map(|region| Module {
+                name: format!("region_{:#x}", region.begin),
+                base: region.begin,
+                end: region.end,
+            })
+            .collect();
+        Ok(Coverage::new(modules))
+    }
+
+    /// Record the blocks `emu` executes in `modules`, or in its mapped regions if
+    /// `modules` is empty, until `Coverage::finish` is called.
+    pub fn start<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        modules: Vec<Module>,
+    ) -> Result<Rc<RefCell<Coverage>>, uc_error> {
+        let coverage = if modules.is_empty() {
+            Coverage::from_regions(emu)?
+        } else {
+            Coverage::new(modules)
+        };
+        let coverage = Rc::new(RefCell::new(coverage));
+        let c = coverage.clone();
+        let hook = emu.add_block_hook(move |_, address, size| {
+            c.borrow_mut().add_block(address, size);
+        })?;
+        coverage.borrow_mut().hooks.push(hook);
+        Ok(coverage)
+    }
+
+    /// Stop recording.
+    pub fn finish<D>(&mut self, emu: &mut Unicorn<D>) -> Result<(), uc_error> {
+        for hook in self.hooks.drain(..) {
+            emu.remove_hook(hook)?;
+        }
+        Ok(())
+    }
+
+    /// Return the modules blocks are recorded in.
+    #[must_use]
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    /// Return the executed blocks, ordered by module and offset.
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.iter()
+    }
+
+    /// Return the number of distinct blocks executed.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Record the block of `size` bytes at `address`. Returns whether it is new, blocks
+    /// outside of all modules or more than 4 GiB into their module, which drcov cannot
+    /// represent, are not recorded.
+    pub fn add_block(&mut self, address: u64, size: u32) -> bool {
+        let module = match self.modules.get(self.last) {
+            Some(m) if m.contains(address) => self.last,
+            _ => match self.modules.iter().position(|m| m.contains(address)) {
+                Some(module) => module,
+                None => return false,
+            },
+        };
+        self.last = module;
+        let offset = match u32::try_from(address - self.modules[module].base) {
+            Ok(offset) => offset,
+            Err(_) => return false,
+        };
+        self.blocks.insert(BasicBlock {
+            module: module as u16,
+            offset,
+            size: size.min(u32::from(u16::MAX)) as u16,
+        })
+    }
+
+    /// Add the blocks of `other`, e.g. the coverage of another run.
+    ///
+    /// Modules are matched by name. Modules only `other` knows are appended, so the same
+    /// target may be loaded at different addresses in every run.
+    pub fn merge(&mut self, other: &Coverage) {
+        let ids: Vec<u16> = other
+            .modules
+            .iter()
+            .map(
+                |module| match self.modules.iter().position(|m| m.name == module.name) {
+                    Some(id) => id as u16,
+                    None => {
+                        self.modules.push(module.clone());
+                        (self.modules.len() - 1) as u16
+                    }
+                },
+            )
+            .collect();
+        for block in &other.blocks {
+            self.blocks.insert(BasicBlock {
+                module: ids[block.module as usize],
+                ..*block
+            });
+        }
+    }
+
+    /// Encode the coverage as a drcov file.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut text = format!(
+            "DRCOV VERSION: 2\nDRCOV FLAVOR: {}\nModule Table: version 2, count {}\n\
+             Columns: id, base, end, entry, checksum, timestamp, path\n",
+            FLAVOR,
+            self.modules.len()
+        );
+        for (id, module) in self.modules.iter().enumerate() {
+            // the table holds the end exclusively
+            text += &format!(
+                "{:3}, {:#018x}, {:#018x}, {:#018x}, {:#010x}, {:#010x}, {}\n",
+                id,
+                module.base,
+                module.end.wrapping_add(1),
+                0,
+                0,
+                0,
+                module.name
+            );
+        }
+        text += &format!("BB Table: {} bbs\n", self.blocks.len());
+
+        let mut data = text.into_bytes();
+        data.reserve(self.blocks.len() * 8);
+        for block in &self.blocks {
+            data.extend_from_slice(&block.offset.to_le_bytes());
+            data.extend_from_slice(&block.size.to_le_bytes());
+            data.extend_from_slice(&block.module.to_le_bytes());
+        }
+        data
+    }
+
+    /// Decode a drcov file, as written by `Coverage::to_bytes` or by DynamoRIO.
+    pub fn parse(data: &[u8]) -> Result<Coverage, DrcovError> {
+        let mut lines = Lines {
+            data,
+            pos: 0,
+            line: 0,
+        };
+
+        if !lines
+            .next()
+            .ok_or(DrcovError::BadHeader)?
+            .starts_with("DRCOV VERSION: 2")
+        {
+            return Err(DrcovError::BadHeader);
+        }
+        let mut line = lines.next().ok_or(DrcovError::BadHeader)?;
+        if line.starts_with("DRCOV FLAVOR:") {
+            line = lines.next().ok_or(DrcovError::BadHeader)?;
+        }
+        // "Module Table: 3" in version 1 of the table, "Module Table: version 2, count 3" later
+        let count: usize = line
+            .strip_prefix("Module Table:")
+            .and_then(|rest| rest.rsplit([' ', ',']).next())
+            .and_then(|count| count.trim().parse().ok())
+            .ok_or(DrcovError::BadHeader)?;
+
+        // the columns differ between versions of DynamoRIO, the path always comes last
+        let mut line = lines.next().ok_or(DrcovError::BadHeader)?;
+        let columns: Vec<&str> = match line.strip_prefix("Columns:") {
+            Some(columns) => {
+                let columns = columns.split(',').map(str::trim).collect();
+                line = lines.next().ok_or(DrcovError::BadHeader)?;
+                columns
+            }
+            None => ["id", "base", "end", "entry", "path"].to_vec(),
+        };
+        let column = |names: &[&str]| columns.iter().position(|c| names.contains(c));
+        let (base_col, end_col, path_col) = match (
+            column(&["base", "start"]),
+            column(&["end"]),
+            column(&["path"]),
+        ) {
+            (Some(base), Some(end), Some(path)) if path == columns.len() - 1 => (base, end, path),
+            _ => return Err(DrcovError::BadHeader),
+        };
+
+        // the count is untrusted, the table has to prove it by its lines
+        let mut modules = Vec::new();
+        for i in 0..count {
+            if i > 0 {
+                line = lines.next().ok_or(DrcovError::BadHeader)?;
+            }
+            let err = DrcovError::BadModule { line: lines.line };
+            let fields: Vec<&str> = line.splitn(columns.len(), ',').map(str::trim).collect();
+            if fields.len() != columns.len() {
+                return Err(err);
+            }
+            let hex = |s: &str| u64::from_str_radix(s.trim_start_matches("0x"), 16).ok();
+            let (base, end) = match (hex(fields[base_col]), hex(fields[end_col])) {
+                (Some(base), Some(end)) if end > base => (base, end - 1),
+                _ => return Err(err),
+            };
+            modules.push(Module {
+                name: String::from(fields[path_col]),
+                base,
+                end,
+            });
+        }
+
+        // without modules the line after the table header is the block table header
+        if count > 0 {
+            line = lines.next().ok_or(DrcovError::BadBlocks)?;
+        }
+        let blocks: usize = line
+            .strip_prefix("BB Table: ")
+            .and_then(|rest| rest.strip_suffix(" bbs"))
+            .and_then(|count| count.parse().ok())
+            .ok_or(DrcovError::BadBlocks)?;
+        let table = data
+            .get(lines.pos..)
+            .and_then(|rest| rest.get(..blocks.checked_mul(8)?))
+            .ok_or(DrcovError::BadBlocks)?;
+
+        let mut coverage = Coverage::new(modules);
+        for entry in table.chunks_exact(8) {
+            let block = BasicBlock {
+                offset: u32::from_le_bytes(entry[0..4].try_into().unwrap()),
+                size: u16::from_le_bytes(entry[4..6].try_into().unwrap()),
+                module: u16::from_le_bytes(entry[6..8].try_into().unwrap()),
+            };
+            if block.module as usize >= coverage.modules.len() {
+                return Err(DrcovError::UnknownModule {
+                    module: block.module,
+                });
+            }
+            coverage.blocks.insert(block);
+        }
+        Ok(coverage)
+    }
+}
+
+/// Lines of the text part of a drcov file.
+struct Lines<'d> {
+    data: &'d [u8],
+    pos: usize,
+    /// Number of the line returned last, starting at 1.
+    line: usize,
+}
+
+impl<'d> Lines<'d> {
+    fn next(&mut self) -> Option<&'d str> {
+        let len = self
+            .data
+            .get(self.pos..)?
+            .iter()
+            .position(|&b| b == b'\n')?;
+        let line = core::str::from_utf8(&self.data[self.pos..self.pos + len]).ok()?;
+        self.pos += len + 1;
+        self.line += 1;
+        Some(line.trim_end_matches('\r'))
+    }
+}
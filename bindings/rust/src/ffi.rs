@@ -97,6 +97,7 @@ extern "C" {
     ) -> uc_error;
     pub fn uc_hook_del(engine: uc_handle, hook: uc_hook) -> uc_error;
     pub fn uc_query(engine: uc_handle, query_type: Query, result: *mut libc::size_t) -> uc_error;
+    pub fn uc_ctl(engine: uc_handle, control: u32, ...) -> uc_error;
     pub fn uc_context_alloc(engine: uc_handle, context: *mut uc_context) -> uc_error;
     pub fn uc_context_save(engine: uc_handle, context: uc_context) -> uc_error;
     pub fn uc_context_restore(engine: uc_handle, context: uc_context) -> uc_error;
@@ -226,6 +227,21 @@ pub extern "C" fn insn_out_hook_proxy<D, F>(
     (user_data.callback)(&mut user_data.uc, port, size, value);
 }
 
+pub extern "C" fn tcg_opcode_hook_proxy<D, F>(
+    uc: uc_handle,
+    address: u64,
+    arg1: u64,
+    arg2: u64,
+    size: u32,
+    user_data: *mut UcHook<D, F>,
+) where
+    F: FnMut(&mut crate::Unicorn<D>, u64, u64, u64, usize),
+{
+    let user_data = unsafe { &mut *user_data };
+    debug_assert_eq!(uc, user_data.uc.get_handle());
+    (user_data.callback)(&mut user_data.uc, address, arg1, arg2, size as usize);
+}
+
 pub extern "C" fn insn_sys_hook_proxy<D, F>(uc: uc_handle, user_data: *mut UcHook<D, F>)
 where
     F: FnMut(&mut crate::Unicorn<D>),
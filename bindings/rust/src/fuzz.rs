@@ -0,0 +1,312 @@
+//! Persistent-mode fuzzing on top of a prepared `Unicorn` instance.
+//!
+//! A `Harness` takes a snapshot of the registers and of all mapped memory when it is
+//! created. Every call to `Harness::run` places the input, emulates until one of the
+//! exits is reached and resets the instance to the snapshot again, copying back only
+//! the pages that were written to, by the guest or with `Unicorn::mem_write`,
+//! unmapping the memory mapped during the run and mapping again the memory unmapped
+//! during it, with the permissions of the snapshot. If the shadow memory is enabled, it is
+//! part of the snapshot as well. If comparison logging is enabled, the table holds the comparisons
+//! of the last input after every run. The virtual clock of deterministic emulation is
+//! rewound as well, so an input behaves the same no matter when it runs.
+//!
+//! ```rust,ignore
+//! let mut harness = Harness::new(
+//!     emu,
+//!     0x1000,
+//!     &[0x2000],
+//!     InputPlacement::Buffer { address: 0x8000, max_len: 0x1000, len_reg: RegisterX86::ECX.into() },
+//! )?;
+//! harness.set_timeout(SECOND_SCALE);
+//! match harness.run(b"input")? {
+//!     Verdict::Ok => {}
+//!     Verdict::Crash { pc, kind } => println!("crash at {:#x}: {:?}", pc, kind),
+//!     Verdict::Timeout => println!("hang"),
+//! }
+//! ```
+
+#[cfg(feature = "libfuzzer")]
+pub mod libfuzzer;
+
+pub use crate::crash::CrashKind;
+use crate::crash::CrashReport;
+use crate::shadow::ShadowMemory;
+use crate::unicorn_const::{uc_error, HookType, IsDirty, Permission};
+use crate::{ffi, Context, EmuExit, Unicorn};
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+/// Granularity of the dirty page tracking of the engine.
+const PAGE_SIZE: u64 = 0x1000;
+
+/// Callback placing an input in the guest, see `InputPlacement::Callback`.
+pub type PlaceFn<'a, D> = Box<dyn FnMut(&mut Unicorn<'a, D>, &[u8]) -> Result<(), uc_error> + 'a>;
+
+/// How an input gets into the guest before every run.
+pub enum InputPlacement<'a, D> {
+    /// Write the input to `address`, truncated to `max_len` bytes, and its length to
+    /// the register `len_reg`.
+    Buffer {
+        address: u64,
+        max_len: usize,
+        len_reg: i32,
+    },
+    /// Let a callback place the input, e.g. with `Unicorn::mem_write`.
+    Callback(PlaceFn<'a, D>),
+}
+
+/// Outcome of running a single input.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Verdict {
+    /// The target reached an exit, halted or was stopped by a hook.
+    Ok,
+    /// The target crashed at `pc`.
+    Crash { pc: u64, kind: CrashKind },
+    /// The target ran out of time or out of instructions.
+    Timeout,
+}
+
+/// Contents of a mapped region at the time the snapshot was taken.
+struct Region {
+    begin: u64,
+    perms: Permission,
+    data: Vec<u8>,
+}
+
+/// Persistent-mode fuzzing loop around a prepared `Unicorn`.
+pub struct Harness<'a, D: 'a> {
+    emu: Unicorn<'a, D>,
+    placement: InputPlacement<'a, D>,
+    begin: u64,
+    timeout: u64,
+    count: usize,
+    context: Context,
+    clock: u64,
+    regions: Vec<Region>,
+    shadow: Option<ShadowMemory>,
+    last_crash: Option<CrashReport>,
+    dirty: Rc<RefCell<Vec<u64>>>,
+    dirty_hook: ffi::uc_hook,
+}
+
+impl<'a, D> Harness<'a, D>
+where
+    D: 'a,
+{
+    /// Take a snapshot of `emu` and prepare it to run inputs from `begin` until one of
+    /// `exits` is reached.
+    pub fn new(
+        mut emu: Unicorn<'a, D>,
+        begin: u64,
+        exits: &[u64],
+        placement: InputPlacement<'a, D>,
+    ) -> Result<Harness<'a, D>, uc_error> {
+        emu.ctl_exits_enable()?;
+        emu.ctl_set_exits(exits)?;
+        emu.crash_reports_enable();
+
+        let mut regions = Vec::new();
+        for region in emu.mem_regions()? {
+            let size = (region.end - region.begin + 1) as usize;
+            regions.push(Region {
+                begin: region.begin,
+                perms: region.perms,
+                data: emu.mem_read_as_vec(region.begin, size)?,
+            });
+            // start from a clean slate, pages dirtied before the snapshot are part of it
+            for page in (region.begin..=region.end).step_by(PAGE_SIZE as usize) {
+                emu.reset_dirty(page);
+            }
+        }
+
+        let dirty = Rc::new(RefCell::new(Vec::new()));
+        // writes from the host, e.g. of the input or by hooks, need restoring as well
+        emu.inner_mut().dirty_log = Some(dirty.clone());
+        let pages = dirty.clone();
+        let dirty_hook = emu.add_mem_hook(
+            HookType::MEM_WRITE,
+            1,
+            0,
+            move |uc: &mut Unicorn<D>, _mem_type, address, size, _value| {
+                mark_dirty(uc, &pages, address, size);
+                true
+            },
+        )?;
+
+        Ok(Harness {
+            context: emu.context_init()?,
+            clock: emu.ctl_get_virtual_clock()?,
+            shadow: emu.shadow_mut().map(ShadowMemory::checkpoint),
+            last_crash: None,
+            emu,
+            placement,
+            begin,
+            timeout: 0,
+            count: 0,
+            regions,
+            dirty,
+            dirty_hook,
+        })
+    }
+
+    /// Limit every run to `timeout` microseconds, 0 means no limit.
+    ///
+    /// With deterministic emulation, these are microseconds of the virtual clock.
+    pub fn set_timeout(&mut self, timeout: u64) {
+        self.timeout = timeout;
+    }
+
+    /// Limit every run to `count` instructions, 0 means no limit.
+    pub fn set_count(&mut self, count: usize) {
+        self.count = count;
+    }
+
+    /// Return the instance the harness runs on.
+    pub fn emu(&self) -> &Unicorn<'a, D> {
+        &self.emu
+    }
+
+    /// Return the instance the harness runs on mutably.
+    ///
+    /// Changes made to memory or registers through it are undone by the next reset.
+    pub fn emu_mut(&mut self) -> &mut Unicorn<'a, D> {
+        &mut self.emu
+    }
+
+    /// Remove the harness hooks and return the instance as it was left by the last run.
+    pub fn into_inner(mut self) -> Result<Unicorn<'a, D>, uc_error> {
+        self.emu.remove_hook(self.dirty_hook)?;
+        self.emu.inner_mut().dirty_log = None;
+        self.emu.ctl_exits_disable()?;
+        Ok(self.emu)
+    }
+
+    /// Return the report of the last crashing input.
+    #[must_use]
+    pub fn last_crash(&self) -> Option<&CrashReport> {
+        self.last_crash.as_ref()
+    }
+
+    /// Run a single input and reset the instance to the snapshot afterwards.
+    pub fn run(&mut self, input: &[u8]) -> Result<Verdict, uc_error> {
+        // the comparisons of the last input stay around until the next one
+        if let Some(log) = self.emu.cmplog_mut() {
+            log.reset();
+        }
+        let verdict = self.place(input).and_then(|()| self.execute());
+        self.reset()?;
+        verdict
+    }
+
+    /// Restore the registers and every page dirtied since the last reset, unmap the
+    /// memory mapped since and restore the mappings of the snapshot.
+    pub fn reset(&mut self) -> Result<(), uc_error> {
+        for region in self.emu.mem_regions()? {
+            let snapshot = self
+                .regions
+                .iter()
+                .any(|r| region.begin >= r.begin && region.end - r.begin < r.data.len() as u64);
+            if !snapshot {
+                let size = (region.end - region.begin + 1) as usize;
+                self.emu.mem_unmap(region.begin, size)?;
+            }
+        }
+        // what is left lies within the snapshot regions, split by mem_protect or with
+        // holes from mem_unmap
+        let current = self.emu.mem_regions()?;
+        for region in &self.regions {
+            let size = region.data.len() as u64;
+            let parts: Vec<_> = current
+                .iter()
+                .filter(|c| c.begin >= region.begin && c.begin - region.begin < size)
+                .collect();
+            let mapped: u64 = parts.iter().map(|c| c.end - c.begin + 1).sum();
+            if mapped != size {
+                for part in parts {
+                    self.emu
+                        .mem_unmap(part.begin, (part.end - part.begin + 1) as usize)?;
+                }
+                // the fresh pages are marked dirty and rewritten below
+                self.emu
+                    .mem_map(region.begin, size as usize, region.perms)?;
+                self.emu.mem_write(region.begin, &region.data)?;
+            } else if parts.iter().any(|c| c.perms != region.perms) {
+                self.emu
+                    .mem_protect(region.begin, size as usize, region.perms)?;
+            }
+        }
+
+        let pages: Vec<u64> = self.dirty.borrow_mut().drain(..).collect();
+        for page in pages {
+            if let Some(region) = self
+                .regions
+                .iter()
+                .find(|r| page >= r.begin && page - r.begin < r.data.len() as u64)
+            {
+                let offset = (page - region.begin) as usize;
+                let end = (offset + PAGE_SIZE as usize).min(region.data.len());
+                self.emu.mem_write(page, &region.data[offset..end])?;
+            }
+            self.emu.reset_dirty(page);
+        }
+        // the pages written back above must not count as initialized by the guest
+        if let (Some(shadow), Some(snapshot)) = (self.emu.shadow_mut(), &self.shadow) {
+            shadow.restore(snapshot);
+        }
+        self.emu.call_stack_reset();
+        self.emu.set_crash_pc(0);
+        self.emu.ctl_set_virtual_clock(self.clock)?;
+        self.emu.context_restore(&self.context)
+    }
+
+    fn place(&mut self, input: &[u8]) -> Result<(), uc_error> {
+        match &mut self.placement {
+            InputPlacement::Buffer {
+                address,
+                max_len,
+                len_reg,
+            } => {
+                let input = &input[..input.len().min(*max_len)];
+                self.emu.mem_write(*address, input)?;
+                self.emu.reg_write(*len_reg, input.len() as u64)
+            }
+            InputPlacement::Callback(place) => place(&mut self.emu, input),
+        }
+    }
+
+    fn execute(&mut self) -> Result<Verdict, uc_error> {
+        let exit = self.emu.emu_start(self.begin, 0, self.timeout, self.count);
+        if let Some(report) = self.emu.take_crash_report() {
+            let verdict = Verdict::Crash {
+                pc: report.pc,
+                kind: report.kind,
+            };
+            self.last_crash = Some(report);
+            return Ok(verdict);
+        }
+        match exit {
+            Ok(EmuExit::Timeout) | Ok(EmuExit::InstructionLimit(_)) => Ok(Verdict::Timeout),
+            Ok(_) => Ok(Verdict::Ok),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Record the pages of `[address, address + size)` that were not dirty yet.
+pub(crate) fn mark_dirty<D>(
+    uc: &mut Unicorn<D>,
+    pages: &RefCell<Vec<u64>>,
+    address: u64,
+    size: usize,
+) {
+    if size == 0 {
+        return;
+    }
+    let first = address & !(PAGE_SIZE - 1);
+    let last = (address + size as u64 - 1) & !(PAGE_SIZE - 1);
+    for page in (first..=last).step_by(PAGE_SIZE as usize) {
+        if uc.test_and_set_dirty(page) == IsDirty::NDIRTY {
+            pages.borrow_mut().push(page);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Adapter for `cargo fuzz` and other in-process `LLVMFuzzerTestOneInput`-style engines.
+//!
+//! Guest edges are recorded into a map of 8-bit counters that is registered with the
+//! SanitizerCoverage runtime, so the host fuzzer treats new guest edges like new edges
+//! of the fuzz target itself. This requires the target to be built with
+//! `-fsanitize-coverage=inline-8bit-counters`, which `cargo fuzz` does by default.
+//!
+//! ```rust,ignore
+//! use libfuzzer_sys::{fuzz_target, Corpus};
+//! use std::cell::RefCell;
+//! use unicorn_engine::fuzz::{libfuzzer, Harness};
+//!
+//! thread_local! {
+//!     static TARGET: RefCell<Option<Box<dyn FnMut(&[u8]) -> libfuzzer::Corpus>>> =
+//!         RefCell::new(None);
+//! }
+//!
+//! fn harness() -> Harness<'static, ()> {
+//!     // map and load the target, then take the snapshot with `Harness::new`
+//!     unimplemented!()
+//! }
+//!
+//! fuzz_target!(init: {
+//!     let target = libfuzzer::fuzz_target(harness(), libfuzzer::DEFAULT_MAP_SIZE).unwrap();
+//!     TARGET.with(|t| *t.borrow_mut() = Some(Box::new(target)));
+//! }, |data: &[u8]| -> Corpus {
+//!     match TARGET.with(|t| (t.borrow_mut().as_mut().unwrap())(data)) {
+//!         libfuzzer::Corpus::Keep => Corpus::Keep,
+//!         libfuzzer::Corpus::Reject => Corpus::Reject,
+//!     }
+//! });
+//! ```
+
+use super::{CrashKind, Harness, Verdict};
+use crate::unicorn_const::{uc_error, CoverageFlags};
+use alloc::{format, vec::Vec};
+use core::fmt;
+
+/// Size of the counter map used by `fuzz_target`.
+pub const DEFAULT_MAP_SIZE: usize = 1 << 16;
+
+extern "C" {
+    fn __sanitizer_cov_8bit_counters_init(start: *mut u8, stop: *mut u8);
+}
+
+/// Whether an input should be added to the corpus, mirrors `libfuzzer_sys::Corpus`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Corpus {
+    Keep,
+    Reject,
+}
+
+/// An input the host fuzzer has to record as a crash.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Finding {
+    /// The guest crashed at `pc`, see `Harness::last_crash` for the whole report.
+    Crash { pc: u64, kind: CrashKind },
+    /// The harness failed to run the input.
+    Harness(uc_error),
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Finding::Crash { pc, kind } => write!(f, "guest crashed at {:#x}: {:?}", pc, kind),
+            Finding::Harness(err) => write!(f, "harness failed: {:?}", err),
+        }
+    }
+}
+
+/// Run `input` on `harness` and tell whether to keep it, the part of `fuzz_target`
+/// that does not need the SanitizerCoverage runtime.
+///
+/// Crashes of the guest (unmapped or protected accesses, invalid instructions and
+/// crashes reported through `Unicorn::set_crash_pc`) and failures of the harness are
+/// returned as `Finding`s. Inputs running into the timeout or instruction limit of
+/// the harness are rejected.
+pub fn test_one_input<D>(harness: &mut Harness<'_, D>, input: &[u8]) -> Result<Corpus, Finding> {
+    match harness.run(input) {
+        Ok(Verdict::Ok) => Ok(Corpus::Keep),
+        Ok(Verdict::Timeout) => Ok(Corpus::Reject),
+        Ok(Verdict::Crash { pc, kind }) => Err(Finding::Crash { pc, kind }),
+        Err(err) => Err(Finding::Harness(err)),
+    }
+}
+
+/// Wrap `harness` into a closure running one input per call, see `test_one_input`.
+///
+/// A `Finding` is printed to stderr, along with the backtrace of the guest, before
+/// the process aborts, which the host fuzzer records as a crash of the input.
+///
+/// The counters must be registered before the fuzzing loop starts, so call this from
+/// the `init` block of `fuzz_target!` (i.e. `LLVMFuzzerInitialize`). `map_size` has to
+/// be a power of two.
+pub fn fuzz_target<D: 'static>(
+    mut harness: Harness<'static, D>,
+    map_size: usize,
+) -> Result<impl FnMut(&[u8]) -> Corpus, uc_error> {
+    // the runtime keeps pointers into the map for the rest of the process
+    let map = Vec::leak(vec![0u8; map_size]);
+    let range = map.as_mut_ptr_range();
+    harness
+        .emu_mut()
+        .coverage_enable(map, 0, u64::MAX, CoverageFlags::NONE)?;
+    unsafe { __sanitizer_cov_8bit_counters_init(range.start, range.end) };
+
+    Ok(
+        move |input: &[u8]| match test_one_input(&mut harness, input) {
+            Ok(corpus) => corpus,
+            Err(finding) => abort(&harness, &finding),
+        },
+    )
+}
+
+/// Report `finding` on stderr and abort without unwinding into the fuzzer.
+fn abort<D>(harness: &Harness<'_, D>, finding: &Finding) -> ! {
+    let mut message = format!("==unicorn== {}\n", finding);
+    if let (Finding::Crash { .. }, Some(report)) = (finding, harness.last_crash()) {
+        for (i, frame) in report.backtrace.iter().enumerate() {
+            message += &format!("    #{} {:#x}\n", i, frame);
+        }
+    }
+    unsafe {
+        libc::write(2, message.as_ptr().cast(), message.len());
+        libc::abort()
+    }
+}
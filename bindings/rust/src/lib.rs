@@ -30,8 +30,22 @@
 
 #[macro_use]
 extern crate alloc;
-
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod callstack;
+pub mod cmplog;
+pub mod crash;
+pub mod drcov;
+pub mod fuzz;
+pub mod linux;
+pub mod loader;
+pub mod memory;
+pub mod shadow;
+pub mod tenet;
+pub mod trace;
 pub mod unicorn_const;
+pub mod utils;
 
 mod arm;
 mod arm64;
@@ -51,8 +65,15 @@ pub use crate::{
 };
 
 use alloc::{boxed::Box, rc::Rc, vec::Vec};
-use core::{cell::UnsafeCell, ptr};
+use core::{
+    cell::{RefCell, UnsafeCell},
+    ptr,
+};
+use callstack::{CallFrame, CallStack};
+use cmplog::{CmpLog, Routine};
+use crash::{CrashKind, CrashReport};
 use ffi::uc_handle;
+use shadow::ShadowMemory;
 use libc::c_void;
 
 #[derive(Debug)]
@@ -81,6 +102,29 @@ impl Drop for Context {
     }
 }
 
+/// Why `emu_start` returned.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum EmuExit {
+    /// The `until` address was reached.
+    ReachedUntil,
+    /// The given instruction `count` was exhausted.
+    InstructionLimit(usize),
+    /// The `timeout` elapsed.
+    Timeout,
+    /// `emu_stop` was called, usually from within a hook.
+    StoppedByHook,
+    /// One of the addresses set with `ctl_set_exits` was reached.
+    Exit(u64),
+    /// The guest halted on its own (e.g. x86 `hlt`) without reaching an exit.
+    Halted,
+    /// An invalid memory access at `address` by the instruction at `pc`.
+    Fault {
+        address: u64,
+        access: MemType,
+        pc: u64,
+    },
+}
+
 pub struct MmioCallbackScope<'a> {
     pub regions: Vec<(u64, usize)>,
     pub read_callback: Option<Box<dyn ffi::IsUcHook<'a> + 'a>>,
@@ -142,6 +186,22 @@ pub struct UnicornInner<'a, D> {
     pub data: D,
     pub mode: Option<Mode>,
     pub crash_pc: u64,
+    /// Report of the last crash, see `crash_report`
+    pub crash_report: Option<CrashReport>,
+    /// Whether crashes are captured into `crash_report`, see `crash_reports_enable`
+    pub crash_reports: bool,
+    /// Error `emu_start` returns after `emu_stop_with_error`
+    pub stop_error: Option<uc_error>,
+    /// Shadow call stack, see `call_stack_enable`
+    pub call_stack: Option<CallStack>,
+    /// Operands of recent comparisons, see `cmplog_enable`
+    pub cmplog: Option<CmpLog>,
+    /// Edge coverage map the generated code writes into, see `coverage_enable`
+    pub coverage: Option<&'a mut [u8]>,
+    /// Initialized bytes of guest memory, see `shadow_enable`
+    pub shadow: Option<ShadowMemory>,
+    /// Pages written from the host, tracked while a fuzzing `Harness` owns the instance
+    pub dirty_log: Option<Rc<RefCell<Vec<u64>>>>,
 }
 
 /// Drop UC
@@ -190,6 +250,14 @@ impl<'a> TryFrom<uc_handle> for Unicorn<'a, ()> {
                 mmio_callbacks: vec![],
                 mode: Option::None, 
                 crash_pc: 0,
+                crash_report: None,
+                crash_reports: false,
+                stop_error: None,
+                call_stack: None,
+                cmplog: None,
+                coverage: None,
+                shadow: None,
+                dirty_log: None,
             })),
         })
     }
@@ -215,6 +283,14 @@ where
                     mmio_callbacks: vec![],
                     mode: Some(mode),
                     crash_pc: 0x0,
+                    crash_report: None,
+                    crash_reports: false,
+                    stop_error: None,
+                    call_stack: None,
+                    cmplog: None,
+                    coverage: None,
+                    shadow: None,
+                    dirty_log: None,
                 })),
             })
         } else {
@@ -241,7 +317,7 @@ impl<'a, D> Unicorn<'a, D> {
     /// Return whatever data was passed during initialization.
     ///
     /// For an example, have a look at `utils::init_emu_with_heap` where
-    /// a struct is passed which is used for a custom allocator.
+    /// the guest heap is passed, so hooks can look up its chunks and errors.
     #[must_use]
     pub fn get_data(&self) -> &D {
         &self.inner().data
@@ -265,8 +341,27 @@ impl<'a, D> Unicorn<'a, D> {
         self.inner().mode.expect("Mode not set")
     }
 
+    /// Report a crash at `pc` detected by a hook, 0 clears the last crash.
+    ///
+    /// With `crash_reports_enable`, a `CrashReport` of the current state is recorded as
+    /// well, see `crash_report`.
     pub fn set_crash_pc(&mut self, pc: u64) {
+        if pc != 0 {
+            self.record_crash(pc, CrashKind::Reported);
+        } else {
+            self.inner_mut().crash_pc = 0;
+            self.inner_mut().crash_report = None;
+        }
+    }
+
+    /// Record a crash at `pc`, along with a report of the current state if reports are
+    /// enabled.
+    pub(crate) fn record_crash(&mut self, pc: u64, kind: CrashKind) {
         self.inner_mut().crash_pc = pc;
+        if self.inner().crash_reports {
+            let report = CrashReport::capture(self, pc, kind);
+            self.inner_mut().crash_report = Some(report);
+        }
     }
 
     #[must_use]
@@ -274,6 +369,36 @@ impl<'a, D> Unicorn<'a, D> {
         self.inner().crash_pc
     }
 
+    /// Return the report of the last crash of the current `emu_start`, if any.
+    ///
+    /// Once enabled with `crash_reports_enable`, reports are recorded for memory faults
+    /// and invalid instructions ending `emu_start` as well as crashes reported through
+    /// `set_crash_pc`.
+    #[must_use]
+    pub fn crash_report(&self) -> Option<&CrashReport> {
+        self.inner().crash_report.as_ref()
+    }
+
+    /// Take the report of the last crash, clearing it together with `crash_pc`.
+    pub fn take_crash_report(&mut self) -> Option<CrashReport> {
+        self.inner_mut().crash_pc = 0;
+        self.inner_mut().crash_report.take()
+    }
+
+    /// Start capturing a `CrashReport` for every crash, see the `crash` module.
+    ///
+    /// Capturing reads the registers, the memory regions and the backtrace, so it is off
+    /// by default.
+    pub fn crash_reports_enable(&mut self) {
+        self.inner_mut().crash_reports = true;
+    }
+
+    /// Stop capturing crash reports, dropping the last one.
+    pub fn crash_reports_disable(&mut self) {
+        self.inner_mut().crash_reports = false;
+        self.inner_mut().crash_report = None;
+    }
+
     /// Return the handle of the current emulator.
     #[must_use]
     pub fn get_handle(&self) -> uc_handle {
@@ -323,6 +448,12 @@ impl<'a, D> Unicorn<'a, D> {
         let err =
             unsafe { ffi::uc_mem_write(self.get_handle(), address, bytes.as_ptr(), bytes.len()) };
         if err == uc_error::OK {
+            if let Some(shadow) = self.inner_mut().shadow.as_mut() {
+                shadow.unpoison(address, bytes.len() as u64);
+            }
+            if let Some(log) = self.inner().dirty_log.clone() {
+                fuzz::mark_dirty(self, &log, address, bytes.len());
+            }
             Ok(())
         } else {
             Err(err)
@@ -342,6 +473,9 @@ impl<'a, D> Unicorn<'a, D> {
     /// `size` must be a multiple of 4kb or this will return `Error::ARG`.
     ///
     /// `ptr` is a pointer to the provided memory region that will be used by the emulator.
+    ///
+    /// With the shadow memory enabled the region starts out uninitialized, like with
+    /// `mem_map`; unpoison whatever the host filled in beforehand.
     pub unsafe fn mem_map_ptr(
         &mut self,
         address: u64,
@@ -351,6 +485,9 @@ impl<'a, D> Unicorn<'a, D> {
     ) -> Result<(), uc_error> {
         let err = ffi::uc_mem_map_ptr(self.get_handle(), address, size, perms.bits(), ptr);
         if err == uc_error::OK {
+            if let Some(shadow) = self.inner_mut().shadow.as_mut() {
+                shadow.poison(address, size as u64);
+            }
             Ok(())
         } else {
             Err(err)
@@ -369,6 +506,9 @@ impl<'a, D> Unicorn<'a, D> {
     ) -> Result<(), uc_error> {
         let err = unsafe { ffi::uc_mem_map(self.get_handle(), address, size, perms.bits()) };
         if err == uc_error::OK {
+            if let Some(shadow) = self.inner_mut().shadow.as_mut() {
+                shadow.poison(address, size as u64);
+            }
             Ok(())
         } else {
             Err(err)
@@ -492,6 +632,9 @@ impl<'a, D> Unicorn<'a, D> {
         self.mmio_unmap(address, size);
 
         if err == uc_error::OK {
+            if let Some(shadow) = self.inner_mut().shadow.as_mut() {
+                shadow.forget(address, size as u64);
+            }
             Ok(())
         } else {
             Err(err)
@@ -920,6 +1063,50 @@ impl<'a, D> Unicorn<'a, D> {
         }
     }
 
+    /// Add a hook tracing the TCG opcode `code` in the blocks in `begin..=end`.
+    ///
+    /// The callback gets the PC, both operands and the operand size in bits.
+    pub fn add_tcg_hook<F>(
+        &mut self,
+        code: TcgOpCode,
+        flags: TcgOpFlag,
+        begin: u64,
+        end: u64,
+        callback: F,
+    ) -> Result<ffi::uc_hook, uc_error>
+    where
+        F: FnMut(&mut Unicorn<D>, u64, u64, u64, usize) + 'a,
+    {
+        let mut hook_ptr = core::ptr::null_mut();
+        let mut user_data = Box::new(ffi::UcHook {
+            callback,
+            uc: Unicorn {
+                inner: self.inner.clone(),
+            },
+        });
+
+        let err = unsafe {
+            ffi::uc_hook_add(
+                self.get_handle(),
+                &mut hook_ptr,
+                HookType::TCG_OPCODE,
+                ffi::tcg_opcode_hook_proxy::<D, F> as _,
+                user_data.as_mut() as *mut _ as _,
+                begin,
+                end,
+                code as libc::c_int,
+                flags.bits() as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            self.inner_mut().hooks.push((hook_ptr, user_data));
+
+            Ok(hook_ptr)
+        } else {
+            Err(err)
+        }
+    }
+
     /// Remove a hook.
     ///
     /// `hook` is the value returned by `add_*_hook` functions.
@@ -1006,21 +1193,62 @@ impl<'a, D> Unicorn<'a, D> {
     /// is hit. `timeout` specifies a duration in microseconds after which the emulation is
     /// stopped (infinite execution if set to 0). `count` is the maximum number of instructions
     /// to emulate (emulate all the available instructions if set to 0).
+    ///
+    /// Returns why the emulation stopped. Invalid memory accesses are reported as
+    /// `EmuExit::Fault`, every other error is returned as `Err`.
     pub fn emu_start(
         &mut self,
         begin: u64,
         until: u64,
         timeout: u64,
         count: usize,
-    ) -> Result<(), uc_error> {
-        unsafe {
-            let err = ffi::uc_emu_start(self.get_handle(), begin, until, timeout, count as _);
-            if err == uc_error::OK {
-                Ok(())
-            } else {
-                Err(err)
+    ) -> Result<EmuExit, uc_error> {
+        // a report left over from an earlier run would be taken for a crash of this one
+        self.inner_mut().crash_report = None;
+        self.inner_mut().stop_error = None;
+        let err =
+            unsafe { ffi::uc_emu_start(self.get_handle(), begin, until, timeout, count as _) };
+        if let (uc_error::OK, Some(err)) = (err, self.inner_mut().stop_error.take()) {
+            return Err(err);
+        }
+        let reason: ExitReason = self.query(Query::EXIT_REASON)?.try_into()?;
+        let capture = self.inner().crash_reports;
+        if err != uc_error::OK {
+            let access = match err {
+                uc_error::READ_UNMAPPED => MemType::READ_UNMAPPED,
+                uc_error::WRITE_UNMAPPED => MemType::WRITE_UNMAPPED,
+                uc_error::FETCH_UNMAPPED => MemType::FETCH_UNMAPPED,
+                uc_error::READ_PROT => MemType::READ_PROT,
+                uc_error::WRITE_PROT => MemType::WRITE_PROT,
+                uc_error::FETCH_PROT => MemType::FETCH_PROT,
+                uc_error::INSN_INVALID | uc_error::EXCEPTION if capture => {
+                    let pc = self.get_pc()?;
+                    let report = CrashReport::capture(self, pc, CrashKind::Error(err));
+                    self.inner_mut().crash_report = Some(report);
+                    return Err(err);
+                }
+                _ => return Err(err),
+            };
+            let address = self.query(Query::FAULT_ADDR)? as u64;
+            let pc = self.get_pc()?;
+            if capture {
+                let report = CrashReport::capture(self, pc, CrashKind::Fault { access, address });
+                self.inner_mut().crash_report = Some(report);
             }
+            return Ok(EmuExit::Fault {
+                address,
+                access,
+                pc,
+            });
         }
+        Ok(match reason {
+            ExitReason::UNTIL => EmuExit::ReachedUntil,
+            ExitReason::EXITS => EmuExit::Exit(self.get_pc()?),
+            ExitReason::COUNT => EmuExit::InstructionLimit(count),
+            ExitReason::TIMEOUT => EmuExit::Timeout,
+            ExitReason::STOP => EmuExit::StoppedByHook,
+            ExitReason::NONE | ExitReason::FAULT => EmuExit::Halted,
+        })
     }
 
     /// Stop the emulation.
@@ -1036,9 +1264,17 @@ impl<'a, D> Unicorn<'a, D> {
         }
     }
 
+    /// Stop the emulation and have `emu_start` return `Err(err)`, e.g. when a hook
+    /// detects an error of the guest or fails itself.
+    pub fn emu_stop_with_error(&mut self, err: uc_error) -> Result<(), uc_error> {
+        self.inner_mut().stop_error = Some(err);
+        self.emu_stop()
+    }
+
     /// Query the internal status of the engine.
     ///
-    /// supported: `MODE`, `PAGE_SIZE`, `ARCH`
+    /// supported: `MODE`, `PAGE_SIZE`, `ARCH`, `TIMEOUT`, `EXIT_REASON`, `FAULT_ADDR`,
+    /// `INSN_LIMIT`, `INSN_COUNT`
     pub fn query(&self, query: Query) -> Result<usize, uc_error> {
         let mut result: libc::size_t = Default::default();
         let err = unsafe { ffi::uc_query(self.get_handle(), query, &mut result) };
@@ -1049,6 +1285,481 @@ impl<'a, D> Unicorn<'a, D> {
         }
     }
 
+    /// Enable the exits mechanism.
+    ///
+    /// Afterwards the `until` argument of `emu_start` is ignored and emulation stops at
+    /// the addresses set with `ctl_set_exits` instead.
+    pub fn ctl_exits_enable(&mut self) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_USE_EXITS, 1, CTL_IO_WRITE),
+                1 as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Disable the exits mechanism so `until` takes effect again.
+    pub fn ctl_exits_disable(&mut self) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_USE_EXITS, 1, CTL_IO_WRITE),
+                0 as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return the addresses emulation currently stops at.
+    ///
+    /// Requires `ctl_exits_enable`.
+    pub fn ctl_get_exits(&self) -> Result<Vec<u64>, uc_error> {
+        let mut count: libc::size_t = 0;
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_EXITS_CNT, 1, CTL_IO_READ),
+                &mut count as *mut libc::size_t,
+            )
+        };
+        if err != uc_error::OK {
+            return Err(err);
+        }
+        let mut exits: Vec<u64> = vec![0; count];
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_EXITS, 2, CTL_IO_READ),
+                exits.as_mut_ptr(),
+                count,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(exits)
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Replace the addresses emulation stops at.
+    ///
+    /// Requires `ctl_exits_enable`.
+    pub fn ctl_set_exits(&mut self, exits: &[u64]) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_EXITS, 2, CTL_IO_WRITE),
+                exits.as_ptr(),
+                exits.len() as libc::size_t,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Select how the `timeout` of `emu_start` is enforced.
+    ///
+    /// `TimeoutMode::INLINE` avoids spawning a timer thread per `emu_start` call, which
+    /// dominates the runtime of short, high-frequency emulations. Timeouts are still
+    /// reported through `check_timeout`.
+    pub fn ctl_set_timeout_mode(&mut self, mode: TimeoutMode) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_TIMEOUT_MODE, 1, CTL_IO_WRITE),
+                mode as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return how the `timeout` of `emu_start` is enforced.
+    pub fn ctl_get_timeout_mode(&self) -> Result<TimeoutMode, uc_error> {
+        let mut mode: libc::c_int = 0;
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_TIMEOUT_MODE, 1, CTL_IO_READ),
+                &mut mode as *mut libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            mode.try_into()
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Select how the instruction `count` of `emu_start` is enforced.
+    ///
+    /// `CountMode::BLOCK` charges whole blocks instead of hooking every instruction, so
+    /// emulation may run a few instructions past `count` before it stops.
+    pub fn ctl_set_count_mode(&mut self, mode: CountMode) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_COUNT_MODE, 1, CTL_IO_WRITE),
+                mode as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return how the instruction `count` of `emu_start` is enforced.
+    pub fn ctl_get_count_mode(&self) -> Result<CountMode, uc_error> {
+        let mut mode: libc::c_int = 0;
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_COUNT_MODE, 1, CTL_IO_READ),
+                &mut mode as *mut libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            mode.try_into()
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Make emulation deterministic, or go back to host time with `None`.
+    ///
+    /// Guest time sources such as the x86 TSC, the ARM generic timer, the PPC timebase
+    /// or the RISC-V cycle counters follow a virtual clock instead of the host's. The
+    /// clock advances with the instructions executed, charged a whole block at a time,
+    /// by one tick and `ns_per_insn` nanoseconds each. The `timeout` of `emu_start` is
+    /// measured on the virtual clock too, so no timer thread is involved and an input
+    /// times out after the same instruction on every run.
+    pub fn ctl_set_deterministic(&mut self, config: Option<Deterministic>) -> Result<(), uc_error> {
+        let (enable, ns_per_insn) = match config {
+            Some(config) => (1, config.ns_per_insn),
+            None => (0, 0),
+        };
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_DETERMINISTIC, 2, CTL_IO_WRITE),
+                enable as libc::c_int,
+                ns_per_insn,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return whether emulation is deterministic.
+    pub fn ctl_get_deterministic(&self) -> Result<bool, uc_error> {
+        let mut enable: libc::c_int = 0;
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_DETERMINISTIC, 1, CTL_IO_READ),
+                &mut enable as *mut libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(enable != 0)
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Set the instructions counted by the virtual clock, e.g. to rewind it along
+    /// with a snapshot.
+    pub fn ctl_set_virtual_clock(&mut self, insns: u64) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_VIRTUAL_CLOCK, 1, CTL_IO_WRITE),
+                insns,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return the instructions counted by the virtual clock.
+    pub fn ctl_get_virtual_clock(&self) -> Result<u64, uc_error> {
+        let mut insns: u64 = 0;
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_VIRTUAL_CLOCK, 1, CTL_IO_READ),
+                &mut insns as *mut u64,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(insns)
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Record AFL-style edge coverage of the blocks in `begin..=end` into `map`.
+    ///
+    /// The hit counters are bumped directly from the generated code, indexed by
+    /// `cur ^ prev` where `prev` is the location of the previously executed block
+    /// shifted right by one, so the map length must be a power of two. The previous
+    /// location is reset at the start of every `emu_start`, but the map itself is
+    /// not cleared unless `CoverageFlags::BUCKETS` is set; use `coverage_map_mut`
+    /// for that between runs.
+    pub fn coverage_enable(
+        &mut self,
+        map: &'a mut [u8],
+        begin: u64,
+        end: u64,
+        flags: CoverageFlags,
+    ) -> Result<(), uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_COVERAGE, 5, CTL_IO_WRITE),
+                map.as_mut_ptr(),
+                map.len(),
+                begin,
+                end,
+                flags.bits() as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            self.inner_mut().coverage = Some(map);
+            Ok(())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Stop recording edge coverage and hand the map back.
+    pub fn coverage_disable(&mut self) -> Result<Option<&'a mut [u8]>, uc_error> {
+        let err = unsafe {
+            ffi::uc_ctl(
+                self.get_handle(),
+                ctl(ControlType::UC_COVERAGE, 5, CTL_IO_WRITE),
+                ptr::null_mut::<u8>(),
+                0usize,
+                0u64,
+                0u64,
+                0 as libc::c_int,
+            )
+        };
+        if err == uc_error::OK {
+            Ok(self.inner_mut().coverage.take())
+        } else {
+            Err(err)
+        }
+    }
+
+    /// Return the edge coverage map, if coverage is enabled.
+    pub fn coverage_map(&self) -> Option<&[u8]> {
+        self.inner().coverage.as_deref()
+    }
+
+    /// Return the edge coverage map mutably, e.g. to clear it between runs.
+    pub fn coverage_map_mut(&mut self) -> Option<&mut [u8]> {
+        self.inner_mut().coverage.as_deref_mut()
+    }
+
+    /// Start tracking which bytes of guest memory are initialized, see the `shadow` module.
+    ///
+    /// Guest reads of uninitialized bytes are recorded in `ShadowMemory::reads`. With
+    /// `stop_on_read` the first one also stops emulation and is reported through
+    /// `set_crash_pc`.
+    pub fn shadow_enable(&mut self, stop_on_read: bool) -> Result<(), uc_error> {
+        if let Some(shadow) = self.inner_mut().shadow.as_mut() {
+            shadow.stop_on_read = stop_on_read;
+            return Ok(());
+        }
+
+        let read_hook = self.add_mem_hook(HookType::MEM_READ, 1, 0, |uc, _, address, size, _| {
+            let pc = match uc.get_pc() {
+                Ok(pc) => pc,
+                Err(err) => {
+                    let _ = uc.emu_stop_with_error(err);
+                    return true;
+                }
+            };
+            let stop = match uc.inner_mut().shadow.as_mut() {
+                Some(shadow) => shadow.check_read(pc, address, size) && shadow.stop_on_read,
+                None => false,
+            };
+            if stop {
+                uc.set_crash_pc(pc);
+                let _ = uc.emu_stop();
+            }
+            true
+        })?;
+        let write_hook =
+            match self.add_mem_hook(HookType::MEM_WRITE, 1, 0, |uc, _, address, size, _| {
+                if let Some(shadow) = uc.inner_mut().shadow.as_mut() {
+                    shadow.unpoison(address, size as u64);
+                }
+                true
+            }) {
+                Ok(hook) => hook,
+                Err(err) => {
+                    self.remove_hook(read_hook)?;
+                    return Err(err);
+                }
+            };
+
+        self.inner_mut().shadow = Some(ShadowMemory::new(stop_on_read, vec![read_hook, write_hook]));
+        Ok(())
+    }
+
+    /// Stop tracking initialized memory and hand back the shadow.
+    pub fn shadow_disable(&mut self) -> Result<Option<ShadowMemory>, uc_error> {
+        let shadow = self.inner_mut().shadow.take();
+        if let Some(shadow) = &shadow {
+            for &hook in &shadow.hooks {
+                self.remove_hook(hook)?;
+            }
+        }
+        Ok(shadow)
+    }
+
+    /// Return the shadow memory, if it is enabled.
+    pub fn shadow(&self) -> Option<&ShadowMemory> {
+        self.inner().shadow.as_ref()
+    }
+
+    /// Return the shadow memory mutably, e.g. to poison memory mapped before it was enabled.
+    pub fn shadow_mut(&mut self) -> Option<&mut ShadowMemory> {
+        self.inner_mut().shadow.as_mut()
+    }
+
+    /// Start tracking calls and returns in a shadow call stack, see the `callstack` module.
+    ///
+    /// With `detect_smashing`, a return to an address that is not on the shadow stack stops
+    /// emulation and is reported as `CrashKind::StackSmash`.
+    pub fn call_stack_enable(&mut self, detect_smashing: bool) -> Result<(), uc_error> {
+        if let Some(stack) = self.inner_mut().call_stack.as_mut() {
+            stack.detect_smashing = detect_smashing;
+            return Ok(());
+        }
+        let hook = self.add_code_hook(1, 0, callstack::on_insn)?;
+        self.inner_mut().call_stack = Some(CallStack {
+            frames: Vec::new(),
+            detect_smashing,
+            hook,
+        });
+        Ok(())
+    }
+
+    /// Stop tracking calls and returns.
+    pub fn call_stack_disable(&mut self) -> Result<(), uc_error> {
+        if let Some(stack) = self.inner_mut().call_stack.take() {
+            self.remove_hook(stack.hook)?;
+        }
+        Ok(())
+    }
+
+    /// Return the frames of the shadow call stack, outermost first, if it is enabled.
+    #[must_use]
+    pub fn call_stack(&self) -> Option<&[CallFrame]> {
+        self.inner().call_stack.as_ref().map(|stack| &stack.frames[..])
+    }
+
+    /// Drop all frames of the shadow call stack, e.g. before emulating from a new entry point.
+    pub fn call_stack_reset(&mut self) {
+        if let Some(stack) = self.inner_mut().call_stack.as_mut() {
+            stack.frames.clear();
+        }
+    }
+
+    /// Start logging the operands of compare instructions in `begin..=end` into a table
+    /// of `width` entries, see the `cmplog` module.
+    ///
+    /// `width` must be a power of two, `cmplog::DEFAULT_WIDTH` is a good start. Compare
+    /// instructions are only logged on x86, see the `cmplog` module.
+    pub fn cmplog_enable(&mut self, width: usize, begin: u64, end: u64) -> Result<(), uc_error> {
+        if !width.is_power_of_two() {
+            return Err(uc_error::ARG);
+        }
+        if self.inner().cmplog.is_some() {
+            self.cmplog_disable()?;
+        }
+        let hook = self.add_tcg_hook(
+            TcgOpCode::SUB,
+            TcgOpFlag::CMP,
+            begin,
+            end,
+            |uc, address, arg1, arg2, size| {
+                if let Some(log) = uc.inner_mut().cmplog.as_mut() {
+                    log.log_insn(address, arg1, arg2, size);
+                }
+            },
+        )?;
+        self.inner_mut().cmplog = Some(CmpLog::new(width, vec![hook]));
+        Ok(())
+    }
+
+    /// Log the operands of every call to the comparison routine at `address`.
+    ///
+    /// The operands are read from the arguments when the routine is entered and recorded
+    /// in the entry of the call site, so different callers of a routine get different
+    /// entries.
+    pub fn cmplog_add_routine(&mut self, address: u64, routine: Routine) -> Result<(), uc_error> {
+        if self.inner().cmplog.is_none() {
+            return Err(uc_error::ARG);
+        }
+        let hook = self.add_code_hook(address, address, move |uc, _, _| {
+            cmplog::on_routine(uc, routine);
+        })?;
+        if let Some(log) = self.inner_mut().cmplog.as_mut() {
+            log.hooks.push(hook);
+        }
+        Ok(())
+    }
+
+    /// Stop logging comparisons and hand back the table.
+    pub fn cmplog_disable(&mut self) -> Result<Option<CmpLog>, uc_error> {
+        let log = self.inner_mut().cmplog.take();
+        if let Some(log) = &log {
+            for &hook in &log.hooks {
+                self.remove_hook(hook)?;
+            }
+        }
+        Ok(log)
+    }
+
+    /// Return the comparisons logged since the last reset, if logging is enabled.
+    #[must_use]
+    pub fn cmplog(&self) -> Option<&CmpLog> {
+        self.inner().cmplog.as_ref()
+    }
+
+    /// Return the comparison log mutably, e.g. to reset it between runs.
+    pub fn cmplog_mut(&mut self) -> Option<&mut CmpLog> {
+        self.inner_mut().cmplog.as_mut()
+    }
+
     /// Sets dirty bit for the page of given address and returns an `IsDirty` option to indicate if
     /// the page had already been dirtied before
     pub fn test_and_set_dirty(&mut self, address: u64) -> IsDirty {
@@ -1066,10 +1777,25 @@ impl<'a, D> Unicorn<'a, D> {
     }
 
     /// Check if emulation timed out
+    ///
+    /// Only the wall-clock `timeout` of `emu_start` counts, see `check_insn_limit` for
+    /// the instruction `count`.
     pub fn check_timeout(&mut self) -> bool {
         unsafe { ffi::uc_check_timeout(self.get_handle()) }
     }
 
+    /// Check if emulation stopped because the instruction `count` ran out
+    pub fn check_insn_limit(&self) -> Result<bool, uc_error> {
+        Ok(self.query(Query::INSN_LIMIT)? != 0)
+    }
+
+    /// Number of instructions executed by the last `emu_start`
+    ///
+    /// Only kept while a `count` is passed to `emu_start`.
+    pub fn insn_count(&self) -> Result<usize, uc_error> {
+        self.query(Query::INSN_COUNT)
+    }
+
     /// Gets the current program counter for this `unicorn` instance.
     #[inline]
     pub fn get_pc(&self) -> Result<u64, uc_error> {
@@ -1122,6 +1848,44 @@ impl<'a, D> Unicorn<'a, D> {
         self.reg_write(reg, value)
     }
 
+    /// Stack pointer register for active architecture
+    #[inline]
+    pub fn stack_pointer_reg(&self) -> Result<i32, uc_error> {
+        let arch = self.get_arch();
+        Ok(
+            match arch {
+                Arch::X86 => {
+                    match self.get_mode() {
+                        Mode::MODE_32 => RegisterX86::ESP as i32,
+                        Mode::MODE_64 => RegisterX86::RSP as i32,
+                        _ => unreachable!(),
+                    }
+                }
+                Arch::ARM => RegisterARM::SP as i32,
+                Arch::ARM64 => RegisterARM64::SP as i32,
+                Arch::MIPS => RegisterMIPS::SP as i32,
+                Arch::SPARC => RegisterSPARC::SP as i32,
+                Arch::M68K => RegisterM68K::A7 as i32,
+                Arch::PPC => RegisterPPC::R1 as i32,
+                Arch::RISCV => RegisterRISCV::SP as i32,
+                Arch::S390X => RegisterS390X::R15 as i32,
+                Arch::TRICORE => RegisterTRICORE::SP as i32,
+                Arch::MAX => panic!("Illegal Arch specified"),
+        })
+    }
+
+    /// Gets the current stack pointer for this `unicorn` instance.
+    #[inline]
+    pub fn get_sp(&self) -> Result<u64, uc_error> {
+        self.reg_read(self.stack_pointer_reg()?)
+    }
+
+    /// Sets the stack pointer for this `unicorn` instance.
+    #[inline]
+    pub fn set_sp(&mut self, value: u64) -> Result<(), uc_error> {
+        self.reg_write(self.stack_pointer_reg()?, value)
+    }
+
     /// Linux function arg-0 for active architecture
     #[inline]
     pub fn function_arg0_val(&self) -> Result<u64, uc_error> {
@@ -1131,8 +1895,8 @@ impl<'a, D> Unicorn<'a, D> {
                 match self.get_mode() {
                     Mode::MODE_32 => {
                         let mut reader = vec![0u8; 4];
-                        let esp = self.reg_read(RegisterX86::ESP as i32).unwrap();
-                        self.mem_read(esp + 0x4, &mut reader).unwrap();
+                        let esp = self.reg_read(RegisterX86::ESP as i32)?;
+                        self.mem_read(esp + 0x4, &mut reader)?;
                         Ok(u32::from_le_bytes(reader[0..4].try_into().unwrap()) as u64)
                     },
                     Mode::MODE_64 => self.reg_read(RegisterX86::RDI as i32),
@@ -1159,14 +1923,21 @@ impl<'a, D> Unicorn<'a, D> {
                 match self.get_mode() {
                     Mode::MODE_32 => {
                         let mut reader = vec![0u8; 4];
-                        let esp = self.reg_read(RegisterX86::ESP as i32).unwrap();
-                        self.mem_read(esp + 0x8, &mut reader).unwrap();
+                        let esp = self.reg_read(RegisterX86::ESP as i32)?;
+                        self.mem_read(esp + 0x8, &mut reader)?;
                         Ok(u32::from_le_bytes(reader[0..4].try_into().unwrap()) as u64)
                     },
                     Mode::MODE_64 => self.reg_read(RegisterX86::RSI as i32),
                     _ => unreachable!(),
                 }
             }
+            Arch::ARM => self.reg_read(RegisterARM::R1 as i32),
+            Arch::ARM64 => self.reg_read(RegisterARM64::X1 as i32),
+            Arch::MIPS => self.reg_read(RegisterMIPS::A1 as i32),
+            Arch::SPARC => self.reg_read(RegisterSPARC::O1 as i32),
+            Arch::PPC => self.reg_read(RegisterPPC::R4 as i32),
+            Arch::RISCV => self.reg_read(RegisterRISCV::A1 as i32),
+            Arch::MAX => panic!("Illegal Arch specified"),
             _ => unreachable!(),
         }
     }
@@ -1180,14 +1951,21 @@ impl<'a, D> Unicorn<'a, D> {
                 match self.get_mode() {
                     Mode::MODE_32 => {
                         let mut reader = vec![0u8; 4];
-                        let esp = self.reg_read(RegisterX86::ESP as i32).unwrap();
-                        self.mem_read(esp + 0xc, &mut reader).unwrap();
+                        let esp = self.reg_read(RegisterX86::ESP as i32)?;
+                        self.mem_read(esp + 0xc, &mut reader)?;
                         Ok(u32::from_le_bytes(reader[0..4].try_into().unwrap()) as u64)
                     },
                     Mode::MODE_64 => self.reg_read(RegisterX86::RDX as i32),
                     _ => unreachable!(),
                 }
             }
+            Arch::ARM => self.reg_read(RegisterARM::R2 as i32),
+            Arch::ARM64 => self.reg_read(RegisterARM64::X2 as i32),
+            Arch::MIPS => self.reg_read(RegisterMIPS::A2 as i32),
+            Arch::SPARC => self.reg_read(RegisterSPARC::O2 as i32),
+            Arch::PPC => self.reg_read(RegisterPPC::R5 as i32),
+            Arch::RISCV => self.reg_read(RegisterRISCV::A2 as i32),
+            Arch::MAX => panic!("Illegal Arch specified"),
             _ => unreachable!(),
         }
     }
@@ -1358,13 +2136,41 @@ impl<'a, D> Unicorn<'a, D> {
         })
     }
 
-    /// Linux syscall return register for active architecture
+    /// Linux function return register for active architecture
+    #[inline]
+    pub fn function_return_reg(&self) -> Result<i32, uc_error> {
+        let arch = self.get_arch();
+        Ok(
+            match arch {
+                Arch::X86 => { 
+                    match self.get_mode() {
+                        Mode::MODE_32 => RegisterX86::EAX as i32,
+                        Mode::MODE_64 => RegisterX86::RAX as i32,
+                        _ => unreachable!(),
+                    }
+                }
+                Arch::ARM => RegisterARM::R0 as i32,
+                Arch::ARM64 => RegisterARM64::X0 as i32,
+                Arch::MIPS => RegisterMIPS::V0 as i32,
+                Arch::SPARC => RegisterSPARC::O0 as i32,
+                Arch::PPC => RegisterPPC::R3 as i32,
+                Arch::RISCV => RegisterRISCV::A0 as i32,
+                Arch::MAX => panic!("Illegal Arch specified"),
+                _ => unreachable!(),
+        })
+    }
+
+    /// Link register holding the return address for active architecture
     #[inline]
     pub fn link_register(&self) -> Result<i32, uc_error> {
         let arch = self.get_arch();
         Ok(
             match arch {
+                Arch::ARM => RegisterARM::LR as i32,
+                Arch::ARM64 => RegisterARM64::LR as i32,
+                Arch::MIPS => RegisterMIPS::RA as i32,
                 Arch::RISCV => RegisterRISCV::RA as i32,
+                Arch::PPC => RegisterPPC::LR as i32,
                 _ => unreachable!(),
         })
     }
@@ -1374,28 +2180,28 @@ impl<'a, D> Unicorn<'a, D> {
     pub fn simulate_return(&mut self) -> Result<(), uc_error> {
         let arch = self.get_arch();
         match arch {
-            Arch::RISCV => {
-                let link_register = self.link_register().unwrap();
-                let new_pc = self.reg_read(link_register).unwrap();
-                self.set_pc(new_pc).unwrap();
+            Arch::ARM | Arch::ARM64 | Arch::MIPS | Arch::RISCV | Arch::PPC => {
+                let link_register = self.link_register()?;
+                let new_pc = self.reg_read(link_register)?;
+                self.set_pc(new_pc)?;
                 Ok(())
             }
             Arch::X86 => {
                 match self.get_mode() {
                     Mode::MODE_32 => {
-                        let esp_val: u64 = self.reg_read(RegisterX86::ESP).unwrap();
-                        self.reg_write(RegisterX86::ESP, esp_val+4).unwrap();
-                        let data = self.mem_read_as_vec(esp_val, 4).unwrap();
+                        let esp_val: u64 = self.reg_read(RegisterX86::ESP)?;
+                        self.reg_write(RegisterX86::ESP, esp_val+4)?;
+                        let data = self.mem_read_as_vec(esp_val, 4)?;
                         let addr = u32::from_le_bytes(data[0..4].try_into().unwrap()) as u64;
-                        self.set_pc(addr).unwrap();
+                        self.set_pc(addr)?;
                         Ok(())
                     },
                     Mode::MODE_64 => {
-                        let rsp_val: u64 = self.reg_read(RegisterX86::RSP).unwrap();
-                        self.reg_write(RegisterX86::RSP, rsp_val+8).unwrap();
-                        let data = self.mem_read_as_vec(rsp_val, 8).unwrap();
+                        let rsp_val: u64 = self.reg_read(RegisterX86::RSP)?;
+                        self.reg_write(RegisterX86::RSP, rsp_val+8)?;
+                        let data = self.mem_read_as_vec(rsp_val, 8)?;
                         let addr = u64::from_le_bytes(data[0..8].try_into().unwrap());
-                        self.set_pc(addr).unwrap();
+                        self.set_pc(addr)?;
                         Ok(())
                     },
                     _ => unreachable!(),
@@ -1409,21 +2215,21 @@ impl<'a, D> Unicorn<'a, D> {
     pub fn func_return_addr(&self) -> Result<u64, uc_error> {
         let arch = self.get_arch();
         match arch {
-            Arch::RISCV => {
-                let link_register = self.link_register().unwrap();
+            Arch::ARM | Arch::ARM64 | Arch::MIPS | Arch::RISCV | Arch::PPC => {
+                let link_register = self.link_register()?;
                 self.reg_read(link_register)
             }
             Arch::X86 => {
                 match self.get_mode() {
                     Mode::MODE_32 => {
-                        let esp_val: u64 = self.reg_read(RegisterX86::ESP).unwrap();
-                        let data = self.mem_read_as_vec(esp_val, 4).unwrap();
+                        let esp_val: u64 = self.reg_read(RegisterX86::ESP)?;
+                        let data = self.mem_read_as_vec(esp_val, 4)?;
                         Ok(u32::from_le_bytes(data[0..4].try_into().unwrap()) as u64)
                     },
                     Mode::MODE_64 => {
-                        let rsp_val: u64 = self.reg_read(RegisterX86::RSP).unwrap();
-                        let data = self.mem_read_as_vec(rsp_val, 8).unwrap();
-                        Ok(u32::from_le_bytes(data[0..8].try_into().unwrap()) as u64)
+                        let rsp_val: u64 = self.reg_read(RegisterX86::RSP)?;
+                        let data = self.mem_read_as_vec(rsp_val, 8)?;
+                        Ok(u64::from_le_bytes(data[0..8].try_into().unwrap()))
                     },
                     _ => unreachable!(),
                 }
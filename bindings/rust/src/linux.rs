@@ -0,0 +1,9 @@
+//! Linux user mode emulation.
+
+pub mod stack;
+pub mod syscall;
+pub mod sysno;
+pub mod vfs;
+
+pub use syscall::{Kernel, Syscall, SyscallFn};
+pub use sysno::{Abi, Sysno};
@@ -0,0 +1,400 @@
+//! The initial stack of a Linux process.
+//!
+//! On entry the kernel leaves the stack pointer at `argc`, followed by the `argv` and
+//! `envp` pointer arrays and the auxiliary vector, all guest words. The strings they
+//! point to, the 16 random bytes of `AT_RANDOM` and the platform string sit above them,
+//! below the top of the stack:
+//!
+//! ```text
+//! sp -> argc
+//!       argv[0] .. argv[argc - 1], 0
+//!       envp[0] .. envp[n - 1], 0
+//!       auxv (type, value) pairs, (AT_NULL, 0)
+//!       padding
+//!       random bytes, platform string
+//!       argument and environment strings
+//!       file name of the executable
+//! top
+//! ```
+//!
+//! ```rust,ignore
+//! let image = elf::load(&mut emu, &data, 0)?;
+//! StackBuilder::new()
+//!     .image(&image)
+//!     .arg("/bin/true")
+//!     .env("PATH=/bin")
+//!     .map(0x7fff_0000, 0x10000)
+//!     .build(&mut emu)?;
+//! ```
+//!
+//! `StackBuilder::build_in` maps the stack through a `VirtualMemory` instead, where it
+//! shows up as `[stack]`.
+
+use crate::loader::elf::LoadedElf;
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission, Query};
+use crate::Unicorn;
+use alloc::{string::String, vec::Vec};
+
+pub const AT_NULL: u64 = 0;
+pub const AT_IGNORE: u64 = 1;
+pub const AT_EXECFD: u64 = 2;
+pub const AT_PHDR: u64 = 3;
+pub const AT_PHENT: u64 = 4;
+pub const AT_PHNUM: u64 = 5;
+pub const AT_PAGESZ: u64 = 6;
+pub const AT_BASE: u64 = 7;
+pub const AT_FLAGS: u64 = 8;
+pub const AT_ENTRY: u64 = 9;
+pub const AT_NOTELF: u64 = 10;
+pub const AT_UID: u64 = 11;
+pub const AT_EUID: u64 = 12;
+pub const AT_GID: u64 = 13;
+pub const AT_EGID: u64 = 14;
+pub const AT_PLATFORM: u64 = 15;
+pub const AT_HWCAP: u64 = 16;
+pub const AT_CLKTCK: u64 = 17;
+pub const AT_SECURE: u64 = 23;
+pub const AT_BASE_PLATFORM: u64 = 24;
+pub const AT_RANDOM: u64 = 25;
+pub const AT_HWCAP2: u64 = 26;
+pub const AT_EXECFN: u64 = 31;
+pub const AT_SYSINFO_EHDR: u64 = 33;
+
+/// Default size of the stack mapped by `StackBuilder::map`.
+pub const DEFAULT_STACK_SIZE: u64 = 0x10_0000;
+
+/// Where the initial stack was written to.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct StackLayout {
+    /// The stack pointer, pointing to `argc`.
+    pub sp: u64,
+    pub argv: u64,
+    pub envp: u64,
+    pub auxv: u64,
+    /// Address of the `AT_RANDOM` bytes.
+    pub random: u64,
+}
+
+/// Lays out `argc`, `argv`, `envp` and the auxiliary vector on the stack of an instance.
+#[derive(Debug, Clone)]
+pub struct StackBuilder {
+    args: Vec<String>,
+    env: Vec<String>,
+    /// Entries set explicitly, they replace the defaults of the same type.
+    auxv: Vec<(u64, u64)>,
+    execfn: Option<String>,
+    platform: Option<String>,
+    random: [u8; 16],
+    /// `(top, size)` of a stack to map before writing to it.
+    map: Option<(u64, u64)>,
+    top: Option<u64>,
+}
+
+impl Default for StackBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackBuilder {
+    #[must_use]
+    pub fn new() -> StackBuilder {
+        StackBuilder {
+            args: Vec::new(),
+            env: Vec::new(),
+            auxv: Vec::new(),
+            execfn: None,
+            platform: None,
+            // runs are reproducible unless random bytes are given
+            random: [0; 16],
+            map: None,
+            top: None,
+        }
+    }
+
+    /// Append an argument, the first one is the program name.
+    #[must_use]
+    pub fn arg(mut self, arg: &str) -> Self {
+        self.args.push(String::from(arg));
+        self
+    }
+
+    /// Append arguments.
+    #[must_use]
+    pub fn args<'s, I: IntoIterator<Item = &'s str>>(mut self, args: I) -> Self {
+        self.args.extend(args.into_iter().map(String::from));
+        self
+    }
+
+    /// Append an environment variable, given as `NAME=value`.
+    #[must_use]
+    pub fn env(mut self, var: &str) -> Self {
+        self.env.push(String::from(var));
+        self
+    }
+
+    /// Append environment variables, given as `NAME=value`.
+    #[must_use]
+    pub fn envs<'s, I: IntoIterator<Item = &'s str>>(mut self, vars: I) -> Self {
+        self.env.extend(vars.into_iter().map(String::from));
+        self
+    }
+
+    /// Set the auxiliary vector entry `kind`, replacing the value the builder would
+    /// pass otherwise. `AT_NULL`, `AT_RANDOM`, `AT_PLATFORM` and `AT_EXECFN` are
+    /// ignored, they point into the stack.
+    #[must_use]
+    pub fn aux(mut self, kind: u64, value: u64) -> Self {
+        if matches!(kind, AT_NULL | AT_RANDOM | AT_PLATFORM | AT_EXECFN) {
+            return self;
+        }
+        match self.auxv.iter_mut().find(|(k, _)| *k == kind) {
+            Some(entry) => entry.1 = value,
+            None => self.auxv.push((kind, value)),
+        }
+        self
+    }
+
+    /// Pass the program headers and the entry point of a loaded executable.
+    #[must_use]
+    pub fn image(self, image: &LoadedElf) -> Self {
+        let builder = self
+            .aux(AT_PHENT, u64::from(image.phentsize))
+            .aux(AT_PHNUM, u64::from(image.phnum))
+            .aux(AT_ENTRY, image.entry);
+        match image.phdr {
+            Some(phdr) => builder.aux(AT_PHDR, phdr),
+            None => builder,
+        }
+    }
+
+    /// Set the base address of the program interpreter, `AT_BASE`.
+    #[must_use]
+    pub fn interpreter(self, base: u64) -> Self {
+        self.aux(AT_BASE, base)
+    }
+
+    /// Set the file name of the executable, `AT_EXECFN`. Defaults to the first argument.
+    #[must_use]
+    pub fn execfn(mut self, name: &str) -> Self {
+        self.execfn = Some(String::from(name));
+        self
+    }
+
+    /// Set the platform string, `AT_PLATFORM`. Defaults to what Linux reports for x86,
+    /// ARM and ARM64, there is none for other architectures.
+    #[must_use]
+    pub fn platform(mut self, platform: &str) -> Self {
+        self.platform = Some(String::from(platform));
+        self
+    }
+
+    /// Set the 16 bytes `AT_RANDOM` points to, zero by default.
+    #[must_use]
+    pub fn random(mut self, random: [u8; 16]) -> Self {
+        self.random = random;
+        self
+    }
+
+    /// Map a read-write stack of `size` bytes below `top` when building, a `size` of 0
+    /// maps `DEFAULT_STACK_SIZE` bytes.
+    #[must_use]
+    pub fn map(mut self, top: u64, size: u64) -> Self {
+        self.map = Some((top, size));
+        self
+    }
+
+    /// Write below `top` of an already mapped stack instead of the current stack pointer.
+    #[must_use]
+    pub fn top(mut self, top: u64) -> Self {
+        self.top = Some(top);
+        self
+    }
+
+    /// Write the initial stack and point the stack pointer of `emu` to it.
+    ///
+    /// The stack grows down from the top given to `StackBuilder::map` or
+    /// `StackBuilder::top`, or from the current stack pointer. Fails with
+    /// `uc_error::ARG` if that is too close to 0 to hold the stack.
+    pub fn build<D>(&self, emu: &mut Unicorn<D>) -> Result<StackLayout, uc_error> {
+        self.build_with(emu, None)
+    }
+
+    /// Like `StackBuilder::build`, but map the stack through `memory`, or name the
+    /// mapping holding an already mapped stack `[stack]` in it.
+    pub fn build_in<D>(
+        &self,
+        emu: &mut Unicorn<D>,
+        memory: &mut VirtualMemory,
+    ) -> Result<StackLayout, uc_error> {
+        self.build_with(emu, Some(memory))
+    }
+
+    fn build_with<D>(
+        &self,
+        emu: &mut Unicorn<D>,
+        memory: Option<&mut VirtualMemory>,
+    ) -> Result<StackLayout, uc_error> {
+        let mode = Mode::from_bits_truncate(emu.query(Query::MODE)? as i32);
+        let arch = emu.get_arch();
+        let wide = crate::crash::is_64bit(emu);
+        let word = if wide { 8 } else { 4 };
+        let big_endian =
+            mode.contains(Mode::BIG_ENDIAN) || (arch == Arch::ARM && mode.contains(Mode::ARMBE8));
+
+        let top = match (self.map, self.top) {
+            (Some((top, size)), _) => {
+                let size = if size == 0 { DEFAULT_STACK_SIZE } else { size };
+                let base = top.checked_sub(size).ok_or(uc_error::ARG)?;
+                let perms = Permission::READ | Permission::WRITE;
+                match memory {
+                    Some(memory) => {
+                        memory.sync(emu)?;
+                        memory.map_fixed(emu, base, size, perms, Origin::Stack, false)?;
+                    }
+                    None => emu.mem_map(base, size as usize, perms)?,
+                }
+                top
+            }
+            (None, top) => {
+                let top = match top {
+                    Some(top) => top,
+                    None => emu.get_sp()?,
+                };
+                if let Some(memory) = memory {
+                    memory.sync(emu)?;
+                    let mapping = top.checked_sub(1).and_then(|below| memory.mapping(below));
+                    if let Some((begin, size)) = mapping.map(|m| (m.begin, m.size())) {
+                        memory.set_origin(emu, begin, size, Origin::Stack)?;
+                    }
+                }
+                top
+            }
+        };
+
+        // strings, from the top down
+        let mut strings: Vec<u8> = Vec::new();
+        let mut cursor = top;
+        let mut push = |strings: &mut Vec<u8>, data: &[u8], nul: bool| {
+            let len = data.len() as u64 + u64::from(nul);
+            cursor = cursor.checked_sub(len).ok_or(uc_error::ARG)?;
+            let mut chunk = data.to_vec();
+            if nul {
+                chunk.push(0);
+            }
+            chunk.extend_from_slice(strings);
+            *strings = chunk;
+            Ok(cursor)
+        };
+        // an empty word at the very top, like Linux leaves it
+        push(&mut strings, &[0; 8][..word], false)?;
+        let execfn = self
+            .execfn
+            .as_deref()
+            .or(self.args.first().map(String::as_str));
+        let execfn = execfn
+            .map(|name| push(&mut strings, name.as_bytes(), true))
+            .transpose()?;
+        let env: Vec<u64> = self
+            .env
+            .iter()
+            .rev()
+            .map(|var| push(&mut strings, var.as_bytes(), true))
+            .collect::<Result<_, _>>()?;
+        let args: Vec<u64> = self
+            .args
+            .iter()
+            .rev()
+            .map(|arg| push(&mut strings, arg.as_bytes(), true))
+            .collect::<Result<_, _>>()?;
+        let platform = self
+            .platform
+            .as_deref()
+            .or_else(|| default_platform(arch, mode))
+            .map(|platform| push(&mut strings, platform.as_bytes(), true))
+            .transpose()?;
+        let random = push(&mut strings, &self.random, false)?;
+        let strings_base = cursor;
+
+        let mut auxv: Vec<(u64, u64)> = vec![
+            (AT_PAGESZ, emu.query(Query::PAGE_SIZE)? as u64),
+            (AT_BASE, 0),
+            (AT_FLAGS, 0),
+            (AT_UID, 0),
+            (AT_EUID, 0),
+            (AT_GID, 0),
+            (AT_EGID, 0),
+            (AT_SECURE, 0),
+            (AT_CLKTCK, 100),
+            (AT_HWCAP, 0),
+            (AT_HWCAP2, 0),
+        ];
+        for &(kind, value) in &self.auxv {
+            match auxv.iter_mut().find(|(k, _)| *k == kind) {
+                Some(entry) => entry.1 = value,
+                None => auxv.push((kind, value)),
+            }
+        }
+        auxv.push((AT_RANDOM, random));
+        if let Some(platform) = platform {
+            auxv.push((AT_PLATFORM, platform));
+        }
+        if let Some(execfn) = execfn {
+            auxv.push((AT_EXECFN, execfn));
+        }
+        auxv.push((AT_NULL, 0));
+
+        // argc, argv, envp and auxv, the stack pointer aligned to 16 bytes
+        let words = 1 + (args.len() + 1) + (env.len() + 1) + auxv.len() * 2;
+        let sp = strings_base
+            .checked_sub((words * word) as u64)
+            .ok_or(uc_error::ARG)?
+            & !0xf;
+        let mut table: Vec<u8> = Vec::with_capacity((strings_base - sp) as usize);
+        let mut put = |value: u64| {
+            let bytes = if big_endian {
+                value.to_be_bytes()[8 - word..].to_vec()
+            } else {
+                value.to_le_bytes()[..word].to_vec()
+            };
+            table.extend_from_slice(&bytes);
+        };
+        put(args.len() as u64);
+        args.iter().rev().for_each(|&arg| put(arg));
+        put(0);
+        env.iter().rev().for_each(|&var| put(var));
+        put(0);
+        for &(kind, value) in &auxv {
+            put(kind);
+            put(value);
+        }
+        table.resize((strings_base - sp) as usize, 0);
+
+        emu.mem_write(sp, &table)?;
+        emu.mem_write(strings_base, &strings)?;
+        emu.set_sp(sp)?;
+
+        let argv = sp + word as u64;
+        let envp = argv + ((args.len() + 1) * word) as u64;
+        Ok(StackLayout {
+            sp,
+            argv,
+            envp,
+            auxv: envp + ((env.len() + 1) * word) as u64,
+            random,
+        })
+    }
+}
+
+/// Return the platform string Linux passes for `arch`, if any.
+fn default_platform(arch: Arch, mode: Mode) -> Option<&'static str> {
+    match arch {
+        Arch::X86 if mode.contains(Mode::MODE_64) => Some("x86_64"),
+        Arch::X86 => Some("i686"),
+        Arch::ARM if mode.contains(Mode::ARMBE8) || mode.contains(Mode::BIG_ENDIAN) => Some("v7b"),
+        Arch::ARM => Some("v7l"),
+        Arch::ARM64 => Some("aarch64"),
+        _ => None,
+    }
+}
@@ -0,0 +1,1257 @@
+//! System call emulation.
+//!
+//! A `Kernel` hooks the system call instruction of the guest, `int 0x80` on i386,
+//! `syscall` on x86-64, `svc` on ARM and ARM64 and `syscall`, `ecall` or `sc` on the
+//! other architectures, and answers the calls it knows. Everything else fails with
+//! `ENOSYS` and is listed by `Kernel::unknown`.
+//!
+//! Memory calls go through the `VirtualMemory` of the kernel: `mmap` maps memory above
+//! `Kernel::set_mmap_base`, `brk` grows the heap from the end of the image given to
+//! `Kernel::set_brk`, and `Kernel::memory` lists the mappings. Files are opened in the `Vfs` of the kernel, see the `vfs`
+//! module; standard input is read from a buffer and standard output and error are
+//! collected into buffers.
+//!
+//! ```rust,ignore
+//! let kernel = Kernel::start(&mut emu)?;
+//! kernel.borrow_mut().set_brk(image.bounds().unwrap().1 + 1);
+//! kernel.borrow_mut().hook(Sysno::Getpid, |_, _| Some(42))?;
+//! emu.emu_start(image.entry, 0, 0, 0)?;
+//! assert_eq!(kernel.borrow().exit_status(), Some(0));
+//! ```
+
+use super::sysno::{Abi, Sysno};
+use super::vfs::{DirEntry, FileType, OpenFlags, Stat, Vfs};
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission, Query};
+use crate::{
+    ffi, InsnSysX86, RegisterARM, RegisterARM64, RegisterMIPS, RegisterPPC, RegisterRISCV,
+    RegisterX86, Unicorn,
+};
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::cell::RefCell;
+
+pub const EPERM: i32 = 1;
+pub const ENOENT: i32 = 2;
+pub const EBADF: i32 = 9;
+pub const ENOMEM: i32 = 12;
+pub const EACCES: i32 = 13;
+pub const EFAULT: i32 = 14;
+pub const EEXIST: i32 = 17;
+pub const ENOTDIR: i32 = 20;
+pub const EISDIR: i32 = 21;
+pub const EINVAL: i32 = 22;
+pub const ENOTTY: i32 = 25;
+pub const EFBIG: i32 = 27;
+pub const ESPIPE: i32 = 29;
+pub const EROFS: i32 = 30;
+pub const ERANGE: i32 = 34;
+/// `ENAMETOOLONG` everywhere but on MIPS, where it is 78; the kernel translates it.
+pub const ENAMETOOLONG: i32 = 36;
+pub const ENOSYS: i32 = 38;
+
+const MAP_FIXED: u64 = 0x10;
+const MAP_FIXED_NOREPLACE: u64 = 0x10_0000;
+
+const AT_FDCWD: i32 = -100;
+const AT_EMPTY_PATH: u64 = 0x1000;
+
+/// Most bytes moved by a single read or write.
+const MAX_TRANSFER: u64 = 0x100_0000;
+/// Most buffers of a single `readv` or `writev`.
+const IOV_MAX: u64 = 1024;
+
+/// The GDT entries i386 `set_thread_area` hands out.
+const GDT_ENTRY_TLS_MIN: u64 = 6;
+const GDT_ENTRY_TLS_MAX: u64 = 8;
+
+const ARCH_SET_GS: u64 = 0x1001;
+const ARCH_SET_FS: u64 = 0x1002;
+const ARCH_GET_FS: u64 = 0x1003;
+const ARCH_GET_GS: u64 = 0x1004;
+
+/// Nanoseconds since the epoch the clock starts at, in November 2023.
+pub const DEFAULT_TIME: u64 = 1_700_000_000_000_000_000;
+
+/// Handler of a system call, see `Kernel::hook`.
+///
+/// Returns the result of the call, `-errno` on failure, or `None` to let the kernel
+/// handle the call after all.
+pub type SyscallFn<'a, D> = Box<dyn FnMut(&mut Unicorn<D>, &Syscall) -> Option<i64> + 'a>;
+
+/// A system call made by the guest.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Syscall {
+    pub number: u64,
+    /// The call `number` stands for in the ABI of the guest, if the kernel knows it.
+    pub sysno: Option<Sysno>,
+    pub args: [u64; 6],
+}
+
+/// Emulates the Linux system calls of a user mode process.
+pub struct Kernel<'a, D> {
+    abi: Abi,
+    big_endian: bool,
+    page_size: u64,
+    overrides: BTreeMap<u64, SyscallFn<'a, D>>,
+    memory: VirtualMemory,
+    pid: u64,
+    time: u64,
+    random: u64,
+    vfs: Vfs,
+    exit_status: Option<i32>,
+    unknown: Vec<u64>,
+    hooks: Vec<ffi::uc_hook>,
+    /// Page holding the GDT of i386 guests, once they call `set_thread_area`.
+    gdt: Option<u64>,
+}
+
+impl<'a, D: 'a> Kernel<'a, D> {
+    /// Hook the system calls of `emu` until `Kernel::finish` is called.
+    ///
+    /// Fails with `uc_error::ARCH` for architectures and modes without a Linux ABI.
+    pub fn start(emu: &mut Unicorn<'a, D>) -> Result<Rc<RefCell<Kernel<'a, D>>>, uc_error> {
+        let arch = emu.get_arch();
+        let mode = Mode::from_bits_truncate(emu.query(Query::MODE)? as i32);
+        let abi = Abi::new(arch, mode).ok_or(uc_error::ARCH)?;
+        let kernel = Rc::new(RefCell::new(Kernel {
+            abi,
+            big_endian: mode.contains(Mode::BIG_ENDIAN)
+                || (arch == Arch::ARM && mode.contains(Mode::ARMBE8)),
+            page_size: emu.query(Query::PAGE_SIZE)? as u64,
+            overrides: BTreeMap::new(),
+            memory: VirtualMemory::new(emu)?,
+            pid: 1000,
+            time: DEFAULT_TIME,
+            random: 0x2545_f491_4f6c_dd1d,
+            vfs: Vfs::new(),
+            exit_status: None,
+            unknown: Vec::new(),
+            hooks: Vec::new(),
+            gdt: None,
+        }));
+
+        let k = kernel.clone();
+        let hook = if abi == Abi::X86_64 {
+            emu.add_insn_sys_hook(InsnSysX86::SYSCALL, 1, 0, move |uc| {
+                k.borrow_mut().syscall(uc);
+            })?
+        } else {
+            emu.add_intr_hook(move |uc, intno| {
+                if is_syscall(abi, intno) {
+                    k.borrow_mut().syscall(uc);
+                }
+            })?
+        };
+        kernel.borrow_mut().hooks.push(hook);
+        Ok(kernel)
+    }
+
+    /// Stop answering system calls.
+    pub fn finish(&mut self, emu: &mut Unicorn<'a, D>) -> Result<(), uc_error> {
+        for hook in self.hooks.drain(..) {
+            emu.remove_hook(hook)?;
+        }
+        Ok(())
+    }
+
+    /// Handle `sysno` with `callback` before the kernel does, see `SyscallFn`.
+    ///
+    /// Fails with `uc_error::ARG` if the ABI of the guest does not have the call.
+    pub fn hook<F>(&mut self, sysno: Sysno, callback: F) -> Result<(), uc_error>
+    where
+        F: FnMut(&mut Unicorn<D>, &Syscall) -> Option<i64> + 'a,
+    {
+        let number = self.abi.number(sysno).ok_or(uc_error::ARG)?;
+        self.hook_number(number, callback);
+        Ok(())
+    }
+
+    /// Handle the system call `number` with `callback`, including calls the kernel
+    /// does not know.
+    pub fn hook_number<F>(&mut self, number: u64, callback: F)
+    where
+        F: FnMut(&mut Unicorn<D>, &Syscall) -> Option<i64> + 'a,
+    {
+        self.overrides.insert(number, Box::new(callback));
+    }
+
+    /// Remove the handler of the system call `number`.
+    pub fn unhook_number(&mut self, number: u64) {
+        self.overrides.remove(&number);
+    }
+
+    #[must_use]
+    pub fn abi(&self) -> Abi {
+        self.abi
+    }
+
+    /// Set the end of the loaded image, where the heap grown by `brk` starts. Without
+    /// it `brk` fails and C libraries fall back to `mmap`.
+    pub fn set_brk(&mut self, address: u64) {
+        self.memory.set_heap_start(address);
+    }
+
+    /// Return the current end of the heap.
+    #[must_use]
+    pub fn brk(&self) -> u64 {
+        self.memory.brk()
+    }
+
+    /// Set the address `mmap` starts looking for free memory at.
+    pub fn set_mmap_base(&mut self, address: u64) {
+        self.memory.set_mmap_base(address);
+    }
+
+    /// Return the memory of the process, e.g. to list its mappings.
+    #[must_use]
+    pub fn memory(&self) -> &VirtualMemory {
+        &self.memory
+    }
+
+    /// Return the memory of the process, e.g. to map a stack that shows up as `[stack]`.
+    pub fn memory_mut(&mut self) -> &mut VirtualMemory {
+        &mut self.memory
+    }
+
+    /// Set the process and thread id.
+    pub fn set_pid(&mut self, pid: u64) {
+        self.pid = pid;
+    }
+
+    /// Set the time in nanoseconds since the epoch.
+    ///
+    /// The clock advances a nanosecond for every instruction counted by the virtual
+    /// clock of deterministic emulation and stands still without it.
+    pub fn set_time(&mut self, time: u64) {
+        self.time = time;
+    }
+
+    /// Seed the bytes returned by `getrandom`.
+    pub fn set_random_seed(&mut self, seed: u64) {
+        // xorshift never leaves zero
+        self.random = seed.max(1);
+    }
+
+    /// Set what the guest reads from standard input.
+    pub fn set_stdin(&mut self, data: &[u8]) {
+        self.vfs.set_stdin(data);
+    }
+
+    /// Return what the guest wrote to standard output.
+    #[must_use]
+    pub fn stdout(&self) -> &[u8] {
+        self.vfs.stdout()
+    }
+
+    /// Return what the guest wrote to standard error.
+    #[must_use]
+    pub fn stderr(&self) -> &[u8] {
+        self.vfs.stderr()
+    }
+
+    /// Clear standard output and error.
+    pub fn clear_output(&mut self) {
+        self.vfs.clear_output();
+    }
+
+    /// Return the file namespace of the process.
+    #[must_use]
+    pub fn vfs(&self) -> &Vfs {
+        &self.vfs
+    }
+
+    /// Return the file namespace of the process mutably, e.g. to mount file systems.
+    pub fn vfs_mut(&mut self) -> &mut Vfs {
+        &mut self.vfs
+    }
+
+    /// Return the status passed to `exit` or `exit_group`, emulation stops there.
+    #[must_use]
+    pub fn exit_status(&self) -> Option<i32> {
+        self.exit_status
+    }
+
+    /// Return the numbers of the system calls that failed with `ENOSYS` because
+    /// neither the kernel nor a hook handled them.
+    #[must_use]
+    pub fn unknown(&self) -> &[u64] {
+        &self.unknown
+    }
+
+    fn syscall(&mut self, uc: &mut Unicorn<D>) {
+        let call = match self.arguments(uc) {
+            Ok(call) => call,
+            Err(_) => return,
+        };
+        let handled = self
+            .overrides
+            .get_mut(&call.number)
+            .and_then(|callback| callback(uc, &call));
+        let result = match handled {
+            Some(value) if (-4095..0).contains(&value) => Err(-value as i32),
+            Some(value) => Ok(value as u64),
+            None => self.dispatch(uc, &call),
+        };
+        let _ = self.set_result(uc, result);
+    }
+
+    /// Read the number and the arguments of the current system call.
+    fn arguments(&self, uc: &Unicorn<D>) -> Result<Syscall, uc_error> {
+        let (number, regs): (i32, [i32; 6]) = match self.abi {
+            Abi::X86 => (
+                RegisterX86::EAX.into(),
+                [
+                    RegisterX86::EBX.into(),
+                    RegisterX86::ECX.into(),
+                    RegisterX86::EDX.into(),
+                    RegisterX86::ESI.into(),
+                    RegisterX86::EDI.into(),
+                    RegisterX86::EBP.into(),
+                ],
+            ),
+            Abi::X86_64 => (
+                RegisterX86::RAX.into(),
+                [
+                    RegisterX86::RDI.into(),
+                    RegisterX86::RSI.into(),
+                    RegisterX86::RDX.into(),
+                    RegisterX86::R10.into(),
+                    RegisterX86::R8.into(),
+                    RegisterX86::R9.into(),
+                ],
+            ),
+            Abi::ArmEabi => (
+                RegisterARM::R7.into(),
+                [
+                    RegisterARM::R0.into(),
+                    RegisterARM::R1.into(),
+                    RegisterARM::R2.into(),
+                    RegisterARM::R3.into(),
+                    RegisterARM::R4.into(),
+                    RegisterARM::R5.into(),
+                ],
+            ),
+            Abi::Arm64 => (
+                RegisterARM64::X8.into(),
+                [
+                    RegisterARM64::X0.into(),
+                    RegisterARM64::X1.into(),
+                    RegisterARM64::X2.into(),
+                    RegisterARM64::X3.into(),
+                    RegisterARM64::X4.into(),
+                    RegisterARM64::X5.into(),
+                ],
+            ),
+            // the fifth and sixth o32 arguments are read from the stack below
+            Abi::MipsO32 | Abi::MipsN64 => (
+                RegisterMIPS::V0.into(),
+                [
+                    RegisterMIPS::A0.into(),
+                    RegisterMIPS::A1.into(),
+                    RegisterMIPS::A2.into(),
+                    RegisterMIPS::A3.into(),
+                    RegisterMIPS::T0.into(),
+                    RegisterMIPS::T1.into(),
+                ],
+            ),
+            Abi::Riscv32 | Abi::Riscv64 => (
+                RegisterRISCV::A7.into(),
+                [
+                    RegisterRISCV::A0.into(),
+                    RegisterRISCV::A1.into(),
+                    RegisterRISCV::A2.into(),
+                    RegisterRISCV::A3.into(),
+                    RegisterRISCV::A4.into(),
+                    RegisterRISCV::A5.into(),
+                ],
+            ),
+            Abi::Ppc | Abi::Ppc64 => (
+                RegisterPPC::R0.into(),
+                [
+                    RegisterPPC::R3.into(),
+                    RegisterPPC::R4.into(),
+                    RegisterPPC::R5.into(),
+                    RegisterPPC::R6.into(),
+                    RegisterPPC::R7.into(),
+                    RegisterPPC::R8.into(),
+                ],
+            ),
+        };
+        let mask = if self.abi.is_64bit() {
+            u64::MAX
+        } else {
+            0xffff_ffff
+        };
+        let number = uc.reg_read(number)? & mask;
+        let mut args = [0; 6];
+        for (arg, reg) in args.iter_mut().zip(regs) {
+            *arg = uc.reg_read(reg)? & mask;
+        }
+        if self.abi == Abi::MipsO32 {
+            let sp = uc.reg_read(RegisterMIPS::SP)?;
+            args[4] = self.read_word(uc, sp + 16).unwrap_or(0);
+            args[5] = self.read_word(uc, sp + 20).unwrap_or(0);
+        }
+        Ok(Syscall {
+            number,
+            sysno: self.abi.sysno(number),
+            args,
+        })
+    }
+
+    /// Write the result of a system call as the ABI of the guest expects it.
+    fn set_result(&self, uc: &mut Unicorn<D>, result: Result<u64, i32>) -> Result<(), uc_error> {
+        match self.abi {
+            // MIPS and PowerPC return the positive errno and flag the error
+            Abi::MipsO32 | Abi::MipsN64 => {
+                let (value, error) = match result {
+                    Ok(value) => (value, 0),
+                    Err(ENAMETOOLONG) => (78, 1),
+                    Err(ENOSYS) => (89, 1),
+                    Err(errno) => (errno as u64, 1),
+                };
+                uc.reg_write(RegisterMIPS::V0, value)?;
+                uc.reg_write(RegisterMIPS::A3, error)
+            }
+            Abi::Ppc | Abi::Ppc64 => {
+                // the summary overflow bit of cr0
+                let cr0 = uc.reg_read(RegisterPPC::CR0)?;
+                let (value, cr0) = match result {
+                    Ok(value) => (value, cr0 & !1),
+                    Err(errno) => (errno as u64, cr0 | 1),
+                };
+                uc.reg_write(RegisterPPC::R3, value)?;
+                uc.reg_write(RegisterPPC::CR0, cr0)
+            }
+            _ => {
+                let value = match result {
+                    Ok(value) => value,
+                    Err(errno) => -(errno as i64) as u64,
+                };
+                let reg: i32 = match self.abi {
+                    Abi::X86 => RegisterX86::EAX.into(),
+                    Abi::X86_64 => RegisterX86::RAX.into(),
+                    Abi::ArmEabi => RegisterARM::R0.into(),
+                    Abi::Arm64 => RegisterARM64::X0.into(),
+                    _ => RegisterRISCV::A0.into(),
+                };
+                uc.reg_write(reg, value)
+            }
+        }
+    }
+
+    fn dispatch(&mut self, uc: &mut Unicorn<D>, call: &Syscall) -> Result<u64, i32> {
+        let [a0, a1, a2, a3, a4, a5] = call.args;
+        let sysno = match call.sysno {
+            Some(sysno) => sysno,
+            None => {
+                if !self.unknown.contains(&call.number) {
+                    self.unknown.push(call.number);
+                }
+                return Err(ENOSYS);
+            }
+        };
+        match sysno {
+            Sysno::Read => self.read(uc, a0, a1, a2, None),
+            Sysno::Write => self.write(uc, a0, a1, a2, None),
+            Sysno::Pread64 => {
+                let offset = self.offset64(&call.args);
+                self.read(uc, a0, a1, a2, Some(offset))
+            }
+            Sysno::Pwrite64 => {
+                let offset = self.offset64(&call.args);
+                self.write(uc, a0, a1, a2, Some(offset))
+            }
+            Sysno::Readv | Sysno::Writev if a2 > IOV_MAX => Err(EINVAL),
+            Sysno::Readv | Sysno::Writev => {
+                let mut total = 0;
+                for i in 0..a2 {
+                    let iov = a1 + i * 2 * self.word();
+                    let base = self.read_word(uc, iov)?;
+                    let len = self.read_word(uc, iov + self.word())?;
+                    let done = if sysno == Sysno::Readv {
+                        self.read(uc, a0, base, len, None)?
+                    } else {
+                        self.write(uc, a0, base, len, None)?
+                    };
+                    total += done;
+                    if done < len {
+                        break;
+                    }
+                }
+                Ok(total)
+            }
+            Sysno::Open => self.open(uc, None, a0, a1),
+            Sysno::OpenAt => self.open(uc, dirfd(a0), a1, a2),
+            Sysno::Close => self.vfs.close(a0).map(|_| 0),
+            Sysno::Lseek => self.vfs.seek(a0, self.signed(a1), a2),
+            Sysno::Llseek => {
+                let offset = (a1 << 32 | a2 & 0xffff_ffff) as i64;
+                let position = self.vfs.seek(a0, offset, a4)?;
+                self.write_value(uc, a3, position, 8)?;
+                Ok(0)
+            }
+            // no descriptor is a terminal
+            Sysno::Ioctl if self.vfs.is_open(a0) => Err(ENOTTY),
+            Sysno::Ioctl => Err(EBADF),
+            Sysno::Stat | Sysno::Lstat => {
+                let path = self.path(uc, None, a0)?;
+                let stat = self.vfs.stat(&path)?;
+                self.write_stat(uc, a1, &stat)
+            }
+            Sysno::Fstat => {
+                let stat = self.vfs.fstat(a0)?;
+                self.write_stat(uc, a1, &stat)
+            }
+            Sysno::FstatAt => {
+                let stat = self.stat_at(uc, a0, a1, a3)?;
+                self.write_stat(uc, a2, &stat)
+            }
+            Sysno::Statx => {
+                let stat = self.stat_at(uc, a0, a1, a2)?;
+                self.write_statx(uc, a4, &stat)
+            }
+            Sysno::Getdents => self.getdents(uc, a0, a1, a2, false),
+            Sysno::Getdents64 => self.getdents(uc, a0, a1, a2, true),
+            // everything is accessible, as for root
+            Sysno::Access => {
+                let path = self.path(uc, None, a0)?;
+                self.vfs.stat(&path).map(|_| 0)
+            }
+            Sysno::FaccessAt => {
+                let path = self.path(uc, dirfd(a0), a1)?;
+                self.vfs.stat(&path).map(|_| 0)
+            }
+            Sysno::Getcwd => {
+                let mut cwd = self.vfs.cwd().as_bytes().to_vec();
+                cwd.push(0);
+                if cwd.len() as u64 > a1 {
+                    return Err(ERANGE);
+                }
+                self.write_bytes(uc, a0, &cwd)?;
+                Ok(cwd.len() as u64)
+            }
+            Sysno::Chdir => {
+                let path = self.path(uc, None, a0)?;
+                if self.vfs.stat(&path)?.kind != FileType::Directory {
+                    return Err(ENOTDIR);
+                }
+                self.vfs.set_cwd(&path);
+                Ok(0)
+            }
+            Sysno::Mmap => self.mmap(uc, a0, a1, a2, a3, a4, a5),
+            Sysno::Mmap2 => self.mmap(uc, a0, a1, a2, a3, a4, a5 << 12),
+            Sysno::OldMmap => {
+                let mut args = [0; 6];
+                for (i, arg) in args.iter_mut().enumerate() {
+                    *arg = self.read_word(uc, a0 + i as u64 * 4)?;
+                }
+                let [b0, b1, b2, b3, b4, b5] = args;
+                self.mmap(uc, b0, b1, b2, b3, b4, b5)
+            }
+            Sysno::Munmap => {
+                self.memory.sync(uc).map_err(errno)?;
+                self.memory.unmap(uc, a0, a1).map_err(errno)?;
+                Ok(0)
+            }
+            Sysno::Mprotect => {
+                let perms = Permission::from_bits_truncate(a2 as u32) & Permission::ALL;
+                self.memory.sync(uc).map_err(errno)?;
+                self.memory.protect(uc, a0, a1, perms).map_err(errno)?;
+                Ok(0)
+            }
+            Sysno::Brk => {
+                self.memory.sync(uc).map_err(errno)?;
+                Ok(self.memory.set_brk(uc, a0))
+            }
+            Sysno::Exit | Sysno::ExitGroup => {
+                self.exit_status = Some(a0 as i32);
+                let _ = uc.emu_stop();
+                Ok(0)
+            }
+            Sysno::Uname => self.uname(uc, a0),
+            Sysno::Getpid | Sysno::Gettid | Sysno::SetTidAddress => Ok(self.pid),
+            Sysno::Getppid => Ok(1),
+            Sysno::Getuid | Sysno::Geteuid | Sysno::Getgid | Sysno::Getegid => Ok(0),
+            Sysno::ClockGettime | Sysno::ClockGettime64 => {
+                let now = self.now(uc);
+                let (sec, nsec) = (now / 1_000_000_000, now % 1_000_000_000);
+                if sysno == Sysno::ClockGettime64 {
+                    self.write_value(uc, a1, sec, 8)?;
+                    self.write_value(uc, a1 + 8, nsec, 8)?;
+                } else {
+                    self.write_word(uc, a1, sec)?;
+                    self.write_word(uc, a1 + self.word(), nsec)?;
+                }
+                Ok(0)
+            }
+            Sysno::Gettimeofday => {
+                if a0 != 0 {
+                    let now = self.now(uc);
+                    self.write_word(uc, a0, now / 1_000_000_000)?;
+                    self.write_word(uc, a0 + self.word(), now % 1_000_000_000 / 1000)?;
+                }
+                Ok(0)
+            }
+            Sysno::ArchPrctl => self.arch_prctl(uc, a0, a1),
+            Sysno::SetRobustList => Ok(0),
+            Sysno::SetThreadArea if self.abi == Abi::X86 => self.set_thread_area(uc, a0),
+            Sysno::SetThreadArea => uc
+                .reg_write(RegisterMIPS::CP0_USERLOCAL, a0)
+                .map(|_| 0)
+                .map_err(|_| EINVAL),
+            Sysno::SetTls => uc
+                .reg_write(RegisterARM::C13_C0_3, a0)
+                .map(|_| 0)
+                .map_err(|_| EINVAL),
+            Sysno::RtSigaction => {
+                // no signals are ever delivered, the old action is the default one
+                if a2 != 0 {
+                    let size = match self.abi {
+                        Abi::MipsO32 | Abi::MipsN64 | Abi::Riscv32 | Abi::Riscv64 => 2,
+                        _ => 3,
+                    } * self.word()
+                        + a3.min(128);
+                    self.write_bytes(uc, a2, &vec![0; size as usize])?;
+                }
+                Ok(0)
+            }
+            Sysno::RtSigprocmask => {
+                if a2 != 0 {
+                    self.write_bytes(uc, a2, &vec![0; a3.min(128) as usize])?;
+                }
+                Ok(0)
+            }
+            Sysno::Getrandom => {
+                let data: Vec<u8> = (0..a1.min(0x10_0000)).map(|_| self.next_random()).collect();
+                self.write_bytes(uc, a0, &data)?;
+                Ok(data.len() as u64)
+            }
+        }
+    }
+
+    fn read(
+        &mut self,
+        uc: &mut Unicorn<D>,
+        fd: u64,
+        buf: u64,
+        count: u64,
+        offset: Option<u64>,
+    ) -> Result<u64, i32> {
+        let data = self
+            .vfs
+            .read(fd, count.min(MAX_TRANSFER) as usize, offset)?;
+        self.write_bytes(uc, buf, &data)?;
+        Ok(data.len() as u64)
+    }
+
+    fn write(
+        &mut self,
+        uc: &mut Unicorn<D>,
+        fd: u64,
+        buf: u64,
+        count: u64,
+        offset: Option<u64>,
+    ) -> Result<u64, i32> {
+        let data = uc
+            .mem_read_as_vec(buf, count.min(MAX_TRANSFER) as usize)
+            .map_err(|_| EFAULT)?;
+        self.vfs.write(fd, &data, offset).map(|done| done as u64)
+    }
+
+    fn open(
+        &mut self,
+        uc: &Unicorn<D>,
+        dirfd: Option<u64>,
+        path: u64,
+        flags: u64,
+    ) -> Result<u64, i32> {
+        let path = self.path(uc, dirfd, path)?;
+        let (create, exclusive, truncate, append, directory) = match self.abi {
+            Abi::MipsO32 | Abi::MipsN64 => (0x100, 0x400, 0x200, 0x8, 0x1_0000),
+            Abi::ArmEabi | Abi::Arm64 | Abi::Ppc | Abi::Ppc64 => (0x40, 0x80, 0x200, 0x400, 0x4000),
+            _ => (0x40, 0x80, 0x200, 0x400, 0x1_0000),
+        };
+        let flags = OpenFlags {
+            read: flags & 3 != 1,
+            write: flags & 3 != 0,
+            create: flags & create != 0,
+            exclusive: flags & exclusive != 0,
+            truncate: flags & truncate != 0,
+            append: flags & append != 0,
+            directory: flags & directory != 0,
+        };
+        self.vfs.open(&path, flags)
+    }
+
+    /// Read the path at `address` and resolve it against `dirfd`.
+    fn path(&self, uc: &Unicorn<D>, dirfd: Option<u64>, address: u64) -> Result<String, i32> {
+        let path = self.read_string(uc, address)?;
+        if path.is_empty() {
+            return Err(ENOENT);
+        }
+        self.vfs.resolve(dirfd, &path)
+    }
+
+    /// Return the status of the path at `address`, or of `dirfd` itself for an empty
+    /// path with `AT_EMPTY_PATH`.
+    fn stat_at(
+        &mut self,
+        uc: &Unicorn<D>,
+        dirfd: u64,
+        address: u64,
+        flags: u64,
+    ) -> Result<Stat, i32> {
+        if flags & AT_EMPTY_PATH != 0 && self.read_string(uc, address)?.is_empty() {
+            return self.vfs.fstat(dirfd);
+        }
+        let path = self.path(uc, self::dirfd(dirfd), address)?;
+        self.vfs.stat(&path)
+    }
+
+    /// Write `stat` as the `struct stat` of the ABI, `stat64` on the 32-bit ones.
+    fn write_stat(&self, uc: &mut Unicorn<D>, address: u64, stat: &Stat) -> Result<u64, i32> {
+        let mode = u64::from(stat.kind.mode() | stat.perms);
+        let nlink = if stat.kind == FileType::Directory {
+            2
+        } else {
+            1
+        };
+        let blocks = stat.size.div_ceil(512);
+        let now = self.now(uc);
+        let (sec, nsec) = (now / 1_000_000_000, now % 1_000_000_000);
+        // (offset, size, value) of the fields that are not zero
+        let (size, fields) = match self.abi {
+            Abi::X86_64 => (
+                144,
+                vec![
+                    (0, 8, 1),
+                    (8, 8, stat.ino),
+                    (16, 8, nlink),
+                    (24, 4, mode),
+                    (48, 8, stat.size),
+                    (56, 8, 4096),
+                    (64, 8, blocks),
+                    (72, 8, sec),
+                    (80, 8, nsec),
+                    (88, 8, sec),
+                    (96, 8, nsec),
+                    (104, 8, sec),
+                    (112, 8, nsec),
+                ],
+            ),
+            Abi::Arm64 | Abi::Riscv64 => (
+                128,
+                vec![
+                    (0, 8, 1),
+                    (8, 8, stat.ino),
+                    (16, 4, mode),
+                    (20, 4, nlink),
+                    (48, 8, stat.size),
+                    (56, 4, 4096),
+                    (64, 8, blocks),
+                    (72, 8, sec),
+                    (80, 8, nsec),
+                    (88, 8, sec),
+                    (96, 8, nsec),
+                    (104, 8, sec),
+                    (112, 8, nsec),
+                ],
+            ),
+            // stat64 is packed on i386, ARM aligns the 64-bit fields
+            Abi::X86 => (
+                96,
+                vec![
+                    (0, 8, 1),
+                    (12, 4, stat.ino & 0xffff_ffff),
+                    (16, 4, mode),
+                    (20, 4, nlink),
+                    (44, 8, stat.size),
+                    (52, 4, 4096),
+                    (56, 8, blocks),
+                    (64, 4, sec),
+                    (68, 4, nsec),
+                    (72, 4, sec),
+                    (76, 4, nsec),
+                    (80, 4, sec),
+                    (84, 4, nsec),
+                    (88, 8, stat.ino),
+                ],
+            ),
+            Abi::ArmEabi => (
+                104,
+                vec![
+                    (0, 8, 1),
+                    (12, 4, stat.ino & 0xffff_ffff),
+                    (16, 4, mode),
+                    (20, 4, nlink),
+                    (48, 8, stat.size),
+                    (56, 4, 4096),
+                    (64, 8, blocks),
+                    (72, 4, sec),
+                    (76, 4, nsec),
+                    (80, 4, sec),
+                    (84, 4, nsec),
+                    (88, 4, sec),
+                    (92, 4, nsec),
+                    (96, 8, stat.ino),
+                ],
+            ),
+            // stat64 on o32, stat on n64: the same offsets with 32-bit times
+            Abi::MipsO32 | Abi::MipsN64 => (
+                104,
+                vec![
+                    (0, 4, 1),
+                    (16, 8, stat.ino),
+                    (24, 4, mode),
+                    (28, 4, nlink),
+                    (56, 8, stat.size),
+                    (64, 4, sec),
+                    (68, 4, nsec),
+                    (72, 4, sec),
+                    (76, 4, nsec),
+                    (80, 4, sec),
+                    (84, 4, nsec),
+                    (88, 4, 4096),
+                    (96, 8, blocks),
+                ],
+            ),
+            // the generic stat64
+            Abi::Ppc | Abi::Riscv32 => (
+                104,
+                vec![
+                    (0, 8, 1),
+                    (8, 8, stat.ino),
+                    (16, 4, mode),
+                    (20, 4, nlink),
+                    (48, 8, stat.size),
+                    (56, 4, 4096),
+                    (64, 8, blocks),
+                    (72, 4, sec),
+                    (76, 4, nsec),
+                    (80, 4, sec),
+                    (84, 4, nsec),
+                    (88, 4, sec),
+                    (92, 4, nsec),
+                ],
+            ),
+            // st_nlink comes before st_mode
+            Abi::Ppc64 => (
+                144,
+                vec![
+                    (0, 8, 1),
+                    (8, 8, stat.ino),
+                    (16, 8, nlink),
+                    (24, 4, mode),
+                    (48, 8, stat.size),
+                    (56, 8, 4096),
+                    (64, 8, blocks),
+                    (72, 8, sec),
+                    (80, 8, nsec),
+                    (88, 8, sec),
+                    (96, 8, nsec),
+                    (104, 8, sec),
+                    (112, 8, nsec),
+                ],
+            ),
+        };
+        let mut data = vec![0; size];
+        for (offset, size, value) in fields {
+            put(&mut data, offset, size, value, self.big_endian);
+        }
+        self.write_bytes(uc, address, &data)?;
+        Ok(0)
+    }
+
+    /// Write `stat` as a `struct statx`, which is the same on every architecture.
+    fn write_statx(&self, uc: &mut Unicorn<D>, address: u64, stat: &Stat) -> Result<u64, i32> {
+        let now = self.now(uc);
+        let (sec, nsec) = (now / 1_000_000_000, now % 1_000_000_000);
+        let nlink = if stat.kind == FileType::Directory {
+            2
+        } else {
+            1
+        };
+        let mut data = vec![0; 256];
+        for (offset, size, value) in [
+            // STATX_BASIC_STATS
+            (0, 4, 0x7ff),
+            (4, 4, 4096),
+            (16, 4, nlink),
+            (28, 2, u64::from(stat.kind.mode() | stat.perms)),
+            (32, 8, stat.ino),
+            (40, 8, stat.size),
+            (48, 8, stat.size.div_ceil(512)),
+            (64, 8, sec),
+            (72, 4, nsec),
+            (80, 8, sec),
+            (88, 4, nsec),
+            (96, 8, sec),
+            (104, 4, nsec),
+            (112, 8, sec),
+            (120, 4, nsec),
+            (140, 4, 1),
+        ] {
+            put(&mut data, offset, size, value, self.big_endian);
+        }
+        self.write_bytes(uc, address, &data)?;
+        Ok(0)
+    }
+
+    /// Write as many entries of the directory `fd` as fit into `count` bytes, as
+    /// `linux_dirent64` or as the older `linux_dirent`.
+    fn getdents(
+        &mut self,
+        uc: &mut Unicorn<D>,
+        fd: u64,
+        address: u64,
+        count: u64,
+        is64: bool,
+    ) -> Result<u64, i32> {
+        let word = self.word() as usize;
+        let big_endian = self.big_endian;
+        let mut data = Vec::new();
+        let mut full = false;
+        self.vfs.read_dir(fd, |entry: &DirEntry, next| {
+            let name = entry.name.as_bytes();
+            let (record, name_at) = if is64 {
+                ((19 + name.len() + 1 + 7) & !7, 19)
+            } else {
+                // the type follows the name, in the last byte of the record
+                (
+                    (2 * word + 2 + name.len() + 2 + word - 1) & !(word - 1),
+                    2 * word + 2,
+                )
+            };
+            if (data.len() + record) as u64 > count {
+                full = true;
+                return false;
+            }
+            let mut rec = vec![0; record];
+            if is64 {
+                put(&mut rec, 0, 8, entry.ino, big_endian);
+                put(&mut rec, 8, 8, next, big_endian);
+                put(&mut rec, 16, 2, record as u64, big_endian);
+                rec[18] = entry.kind.dirent_type();
+            } else {
+                put(&mut rec, 0, word, entry.ino, big_endian);
+                put(&mut rec, word, word, next, big_endian);
+                put(&mut rec, 2 * word, 2, record as u64, big_endian);
+                rec[record - 1] = entry.kind.dirent_type();
+            }
+            rec[name_at..name_at + name.len()].copy_from_slice(name);
+            data.extend_from_slice(&rec);
+            true
+        })?;
+        if data.is_empty() && full {
+            return Err(EINVAL);
+        }
+        self.write_bytes(uc, address, &data)?;
+        Ok(data.len() as u64)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn mmap(
+        &mut self,
+        uc: &mut Unicorn<D>,
+        address: u64,
+        len: u64,
+        prot: u64,
+        flags: u64,
+        fd: u64,
+        offset: u64,
+    ) -> Result<u64, i32> {
+        if len == 0 {
+            return Err(EINVAL);
+        }
+        let anonymous = match self.abi {
+            Abi::MipsO32 | Abi::MipsN64 => 0x800,
+            _ => 0x20,
+        };
+        // the contents of a file mapping, it is a private copy
+        let (data, origin) = if flags & anonymous == 0 {
+            let data = self.vfs.read(fd, len as usize, Some(offset))?;
+            let path = String::from(self.vfs.path(fd).unwrap_or_default());
+            (Some(data), Origin::File { path, offset })
+        } else {
+            (None, Origin::Anonymous)
+        };
+        let perms = Permission::from_bits_truncate(prot as u32) & Permission::ALL;
+        self.memory.sync(uc).map_err(errno)?;
+        let address = if flags & (MAP_FIXED | MAP_FIXED_NOREPLACE) != 0 {
+            let replace = flags & MAP_FIXED_NOREPLACE == 0;
+            self.memory
+                .map_fixed(uc, address, len, perms, origin, replace)
+                .map_err(|err| {
+                    if err == uc_error::MAP {
+                        EEXIST
+                    } else {
+                        errno(err)
+                    }
+                })?;
+            address
+        } else {
+            let hint = address & !(self.page_size - 1);
+            self.memory
+                .map(uc, hint, len, perms, origin)
+                .map_err(errno)?
+        };
+        if let Some(data) = data {
+            self.write_bytes(uc, address, &data)?;
+        }
+        Ok(address)
+    }
+
+    fn uname(&self, uc: &mut Unicorn<D>, address: u64) -> Result<u64, i32> {
+        let machine = match self.abi {
+            Abi::X86 => "i686",
+            Abi::X86_64 => "x86_64",
+            Abi::ArmEabi => "armv7l",
+            Abi::Arm64 => "aarch64",
+            Abi::MipsO32 => "mips",
+            Abi::MipsN64 => "mips64",
+            Abi::Riscv32 => "riscv32",
+            Abi::Riscv64 => "riscv64",
+            Abi::Ppc => "ppc",
+            Abi::Ppc64 => "ppc64",
+        };
+        // sysname, nodename, release, version, machine and domainname
+        let fields = ["Linux", "unicorn", "6.1.0", "#1 SMP", machine, "(none)"];
+        let mut data = vec![0u8; fields.len() * 65];
+        for (field, chunk) in fields.iter().zip(data.chunks_mut(65)) {
+            chunk[..field.len()].copy_from_slice(field.as_bytes());
+        }
+        self.write_bytes(uc, address, &data)?;
+        Ok(0)
+    }
+
+    fn arch_prctl(&self, uc: &mut Unicorn<D>, code: u64, address: u64) -> Result<u64, i32> {
+        if !matches!(self.abi, Abi::X86 | Abi::X86_64) {
+            return Err(EINVAL);
+        }
+        let reg = match code {
+            ARCH_SET_FS | ARCH_GET_FS => RegisterX86::FS_BASE,
+            ARCH_SET_GS | ARCH_GET_GS => RegisterX86::GS_BASE,
+            _ => return Err(EINVAL),
+        };
+        if matches!(code, ARCH_SET_FS | ARCH_SET_GS) {
+            uc.reg_write(reg, address).map_err(|_| EINVAL)?;
+        } else {
+            let value = uc.reg_read(reg).map_err(|_| EINVAL)?;
+            self.write_value(uc, address, value, 8)?;
+        }
+        Ok(0)
+    }
+
+    /// Install the i386 TLS segment described by the `user_desc` at `address` in the
+    /// GDT, picking a free entry if asked to. The guest loads the segment into `gs`.
+    fn set_thread_area(&mut self, uc: &mut Unicorn<D>, address: u64) -> Result<u64, i32> {
+        let desc = uc.mem_read_as_vec(address, 16).map_err(|_| EFAULT)?;
+        let field = |i: usize| u32::from_le_bytes(desc[i * 4..i * 4 + 4].try_into().unwrap());
+        let (entry, base, limit, flags) = (field(0), field(1), field(2), field(3));
+        let entry = if entry == u32::MAX {
+            let entry = GDT_ENTRY_TLS_MIN;
+            self.write_value(uc, address, entry, 4)?;
+            entry
+        } else {
+            u64::from(entry)
+        };
+        if !(GDT_ENTRY_TLS_MIN..=GDT_ENTRY_TLS_MAX).contains(&entry) {
+            return Err(EINVAL);
+        }
+        let gdt = match self.gdt {
+            Some(gdt) => gdt,
+            None => {
+                let perms = Permission::READ | Permission::WRITE;
+                let gdt = self
+                    .memory
+                    .map(uc, 0, self.page_size, perms, Origin::Anonymous)
+                    .map_err(errno)?;
+                // uc_x86_mmr: selector, base, limit and flags
+                let mut gdtr = [0u8; 24];
+                gdtr[8..16].copy_from_slice(&gdt.to_le_bytes());
+                gdtr[16..20]
+                    .copy_from_slice(&(8 * (GDT_ENTRY_TLS_MAX as u32 + 1) - 1).to_le_bytes());
+                uc.reg_write_long(RegisterX86::GDTR, &gdtr)
+                    .map_err(|_| EINVAL)?;
+                self.gdt = Some(gdt);
+                gdt
+            }
+        };
+        // the flags are the bit fields seg_32bit, contents (2 bits), read_exec_only,
+        // limit_in_pages, seg_not_present and useable
+        let bit = |n: u32| (flags >> n) & 1;
+        let low = (base & 0xffff) << 16 | (limit & 0xffff);
+        let high = (base & 0xff00_0000)
+            | (base >> 16 & 0xff)
+            | (limit & 0xf_0000)
+            | (bit(3) ^ 1) << 9
+            | (flags >> 1 & 3) << 10
+            // DPL 3, code or data
+            | 0x7000
+            | (bit(5) ^ 1) << 15
+            | bit(6) << 20
+            | bit(0) << 22
+            | bit(4) << 23;
+        let descriptor = u64::from(high) << 32 | u64::from(low);
+        self.write_value(uc, gdt + entry * 8, descriptor, 8)?;
+        Ok(0)
+    }
+
+    /// Return the current time in nanoseconds since the epoch.
+    fn now(&self, uc: &Unicorn<D>) -> u64 {
+        self.time + uc.ctl_get_virtual_clock().unwrap_or(0)
+    }
+
+    fn next_random(&mut self) -> u8 {
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 7;
+        self.random ^= self.random << 17;
+        (self.random >> 32) as u8
+    }
+
+    /// Return the 64-bit file offset passed in two registers from the fourth on, 32-bit
+    /// ABIs that align register pairs skip the fourth.
+    fn offset64(&self, args: &[u64; 6]) -> u64 {
+        if self.abi.is_64bit() {
+            return args[3];
+        }
+        let i = match self.abi {
+            Abi::ArmEabi | Abi::MipsO32 | Abi::Ppc => 4,
+            _ => 3,
+        };
+        let (low, high) = if self.big_endian {
+            (args[i + 1], args[i])
+        } else {
+            (args[i], args[i + 1])
+        };
+        high << 32 | low
+    }
+
+    /// Return an argument as the signed `long` of the guest.
+    fn signed(&self, value: u64) -> i64 {
+        if self.abi.is_64bit() {
+            value as i64
+        } else {
+            i64::from(value as u32 as i32)
+        }
+    }
+
+    /// Read the NUL terminated string at `address`.
+    fn read_string(&self, uc: &Unicorn<D>, address: u64) -> Result<String, i32> {
+        let mut data = Vec::new();
+        let mut address = address;
+        // read up to the end of the page, the next one may not be mapped
+        while data.len() < 4096 {
+            let len = self.page_size - (address & (self.page_size - 1));
+            let chunk = uc
+                .mem_read_as_vec(address, len as usize)
+                .map_err(|_| EFAULT)?;
+            if let Some(end) = chunk.iter().position(|&b| b == 0) {
+                data.extend_from_slice(&chunk[..end]);
+                return String::from_utf8(data).map_err(|_| ENOENT);
+            }
+            data.extend_from_slice(&chunk);
+            address += len;
+        }
+        Err(ENAMETOOLONG)
+    }
+
+    /// Return the size of a guest word.
+    pub(super) fn word(&self) -> u64 {
+        if self.abi.is_64bit() {
+            8
+        } else {
+            4
+        }
+    }
+
+    pub(super) fn read_word(&self, uc: &Unicorn<D>, address: u64) -> Result<u64, i32> {
+        let size = self.word() as usize;
+        let data = uc.mem_read_as_vec(address, size).map_err(|_| EFAULT)?;
+        let mut bytes = [0u8; 8];
+        if self.big_endian {
+            bytes[8 - size..].copy_from_slice(&data);
+            Ok(u64::from_be_bytes(bytes))
+        } else {
+            bytes[..size].copy_from_slice(&data);
+            Ok(u64::from_le_bytes(bytes))
+        }
+    }
+
+    pub(super) fn write_word(
+        &self,
+        uc: &mut Unicorn<D>,
+        address: u64,
+        value: u64,
+    ) -> Result<(), i32> {
+        self.write_value(uc, address, value, self.word() as usize)
+    }
+
+    /// Write the `size` low bytes of `value` in the byte order of the guest.
+    pub(super) fn write_value(
+        &self,
+        uc: &mut Unicorn<D>,
+        address: u64,
+        value: u64,
+        size: usize,
+    ) -> Result<(), i32> {
+        if self.big_endian {
+            self.write_bytes(uc, address, &value.to_be_bytes()[8 - size..])
+        } else {
+            self.write_bytes(uc, address, &value.to_le_bytes()[..size])
+        }
+    }
+
+    pub(super) fn write_bytes(
+        &self,
+        uc: &mut Unicorn<D>,
+        address: u64,
+        data: &[u8],
+    ) -> Result<(), i32> {
+        uc.mem_write(address, data).map_err(|_| EFAULT)
+    }
+}
+
+/// Translate a failure of `VirtualMemory` into the errno of the memory calls.
+fn errno(err: uc_error) -> i32 {
+    match err {
+        uc_error::ARG => EINVAL,
+        _ => ENOMEM,
+    }
+}
+
+/// Return whether the interrupt `intno` is a system call in `abi`.
+fn is_syscall(abi: Abi, intno: u32) -> bool {
+    match abi {
+        Abi::X86 => intno == 0x80,
+        Abi::X86_64 => false,
+        // EXCP_SWI
+        Abi::ArmEabi | Abi::Arm64 => intno == 2,
+        // EXCP_SYSCALL
+        Abi::MipsO32 | Abi::MipsN64 => intno == 17,
+        // ecall from user, supervisor or machine mode
+        Abi::Riscv32 | Abi::Riscv64 => matches!(intno, 8 | 9 | 11),
+        // POWERPC_EXCP_SYSCALL
+        Abi::Ppc | Abi::Ppc64 => intno == 8,
+    }
+}
+
+/// Return the directory of an `*at` call, `None` for `AT_FDCWD`.
+fn dirfd(fd: u64) -> Option<u64> {
+    if fd as i32 == AT_FDCWD {
+        None
+    } else {
+        Some(fd)
+    }
+}
+
+/// Store the `size` low bytes of `value` at `offset` of `buf`.
+fn put(buf: &mut [u8], offset: usize, size: usize, value: u64, big_endian: bool) {
+    let bytes = if big_endian {
+        value.to_be_bytes()[8 - size..].to_vec()
+    } else {
+        value.to_le_bytes()[..size].to_vec()
+    };
+    buf[offset..offset + size].copy_from_slice(&bytes);
+}
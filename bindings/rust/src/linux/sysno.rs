@@ -0,0 +1,583 @@
+//! System call numbers of the Linux ABIs.
+
+use crate::unicorn_const::{Arch, Mode};
+
+/// An emulated system call, independent of its number in an ABI.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum Sysno {
+    Read,
+    Write,
+    Open,
+    OpenAt,
+    Close,
+    Lseek,
+    /// `lseek` with a 64-bit offset on 32-bit architectures.
+    Llseek,
+    Readv,
+    Writev,
+    Pread64,
+    Pwrite64,
+    /// `stat`, or `stat64` on 32-bit x86, ARM, MIPS and PowerPC.
+    Stat,
+    Lstat,
+    Fstat,
+    /// `newfstatat`, or `fstatat64` on 32-bit x86, ARM, MIPS and PowerPC.
+    FstatAt,
+    Statx,
+    Getdents,
+    Getdents64,
+    Access,
+    FaccessAt,
+    Getcwd,
+    Chdir,
+    Ioctl,
+    Mmap,
+    /// `mmap` with the offset counted in pages of 4096 bytes.
+    Mmap2,
+    /// The i386 `mmap` taking a pointer to its arguments.
+    OldMmap,
+    Munmap,
+    Mprotect,
+    Brk,
+    Exit,
+    ExitGroup,
+    Uname,
+    Getpid,
+    Getppid,
+    Gettid,
+    Getuid,
+    Geteuid,
+    Getgid,
+    Getegid,
+    ClockGettime,
+    /// `clock_gettime` with a 64-bit `time_t` on 32-bit architectures.
+    ClockGettime64,
+    Gettimeofday,
+    ArchPrctl,
+    SetTidAddress,
+    SetRobustList,
+    /// Sets the thread pointer on MIPS, and a TLS segment descriptor on i386.
+    SetThreadArea,
+    /// Sets the thread pointer on ARM, a private system call of the architecture.
+    SetTls,
+    RtSigaction,
+    RtSigprocmask,
+    Getrandom,
+}
+
+/// System call convention of an architecture and mode.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Abi {
+    /// i386, `int 0x80`.
+    X86,
+    /// x86-64, `syscall`.
+    X86_64,
+    /// 32-bit ARM EABI, `svc 0` with the number in `r7`.
+    ArmEabi,
+    Arm64,
+    /// 32-bit MIPS, arguments beyond the fourth are passed on the stack.
+    MipsO32,
+    MipsN64,
+    Riscv32,
+    Riscv64,
+    Ppc,
+    Ppc64,
+}
+
+impl Abi {
+    /// Return the ABI of an instance with `arch` and `mode`, `None` for architectures
+    /// Linux user mode is not emulated for.
+    #[must_use]
+    pub fn new(arch: Arch, mode: Mode) -> Option<Abi> {
+        let wide = mode.contains(Mode::MODE_64);
+        match arch {
+            Arch::X86 if wide => Some(Abi::X86_64),
+            Arch::X86 if mode.contains(Mode::MODE_32) => Some(Abi::X86),
+            Arch::ARM => Some(Abi::ArmEabi),
+            Arch::ARM64 => Some(Abi::Arm64),
+            Arch::MIPS if wide => Some(Abi::MipsN64),
+            Arch::MIPS => Some(Abi::MipsO32),
+            Arch::RISCV if wide => Some(Abi::Riscv64),
+            Arch::RISCV => Some(Abi::Riscv32),
+            Arch::PPC if wide => Some(Abi::Ppc64),
+            Arch::PPC => Some(Abi::Ppc),
+            _ => None,
+        }
+    }
+
+    /// Return whether pointers and `long` are 64 bits wide.
+    #[must_use]
+    pub fn is_64bit(self) -> bool {
+        matches!(
+            self,
+            Abi::X86_64 | Abi::Arm64 | Abi::MipsN64 | Abi::Riscv64 | Abi::Ppc64
+        )
+    }
+
+    /// Return the system call `number` stands for.
+    #[must_use]
+    pub fn sysno(self, number: u64) -> Option<Sysno> {
+        self.table()
+            .iter()
+            .find(|&&(n, _)| n == number)
+            .map(|&(_, sysno)| sysno)
+    }
+
+    /// Return the number of `sysno`, `None` if the ABI does not have it.
+    #[must_use]
+    pub fn number(self, sysno: Sysno) -> Option<u64> {
+        self.table()
+            .iter()
+            .find(|&&(_, s)| s == sysno)
+            .map(|&(number, _)| number)
+    }
+
+    fn table(self) -> &'static [(u64, Sysno)] {
+        match self {
+            Abi::X86 => X86,
+            Abi::X86_64 => X86_64,
+            Abi::ArmEabi => ARM,
+            Abi::Arm64 | Abi::Riscv64 => GENERIC_64,
+            Abi::Riscv32 => GENERIC_32,
+            Abi::MipsO32 => MIPS_O32,
+            Abi::MipsN64 => MIPS_N64,
+            Abi::Ppc => PPC,
+            Abi::Ppc64 => PPC64,
+        }
+    }
+}
+
+// Where a system call has several numbers, e.g. the 16 and 32-bit uid variants, the
+// first one is what `Abi::number` returns.
+
+const X86: &[(u64, Sysno)] = &[
+    (1, Sysno::Exit),
+    (3, Sysno::Read),
+    (4, Sysno::Write),
+    (5, Sysno::Open),
+    (6, Sysno::Close),
+    (12, Sysno::Chdir),
+    (19, Sysno::Lseek),
+    (20, Sysno::Getpid),
+    (33, Sysno::Access),
+    (45, Sysno::Brk),
+    (54, Sysno::Ioctl),
+    (64, Sysno::Getppid),
+    (78, Sysno::Gettimeofday),
+    (90, Sysno::OldMmap),
+    (91, Sysno::Munmap),
+    (122, Sysno::Uname),
+    (125, Sysno::Mprotect),
+    (140, Sysno::Llseek),
+    (141, Sysno::Getdents),
+    (145, Sysno::Readv),
+    (146, Sysno::Writev),
+    (174, Sysno::RtSigaction),
+    (175, Sysno::RtSigprocmask),
+    (180, Sysno::Pread64),
+    (181, Sysno::Pwrite64),
+    (183, Sysno::Getcwd),
+    (192, Sysno::Mmap2),
+    (195, Sysno::Stat),
+    (196, Sysno::Lstat),
+    (197, Sysno::Fstat),
+    (199, Sysno::Getuid),
+    (200, Sysno::Getgid),
+    (201, Sysno::Geteuid),
+    (202, Sysno::Getegid),
+    (220, Sysno::Getdents64),
+    (224, Sysno::Gettid),
+    (243, Sysno::SetThreadArea),
+    (252, Sysno::ExitGroup),
+    (258, Sysno::SetTidAddress),
+    (265, Sysno::ClockGettime),
+    (295, Sysno::OpenAt),
+    (300, Sysno::FstatAt),
+    (307, Sysno::FaccessAt),
+    (311, Sysno::SetRobustList),
+    (355, Sysno::Getrandom),
+    (383, Sysno::Statx),
+    (384, Sysno::ArchPrctl),
+    (403, Sysno::ClockGettime64),
+    // the 16-bit uid calls
+    (24, Sysno::Getuid),
+    (47, Sysno::Getgid),
+    (49, Sysno::Geteuid),
+    (50, Sysno::Getegid),
+];
+
+const X86_64: &[(u64, Sysno)] = &[
+    (0, Sysno::Read),
+    (1, Sysno::Write),
+    (2, Sysno::Open),
+    (3, Sysno::Close),
+    (4, Sysno::Stat),
+    (5, Sysno::Fstat),
+    (6, Sysno::Lstat),
+    (8, Sysno::Lseek),
+    (9, Sysno::Mmap),
+    (10, Sysno::Mprotect),
+    (11, Sysno::Munmap),
+    (12, Sysno::Brk),
+    (13, Sysno::RtSigaction),
+    (14, Sysno::RtSigprocmask),
+    (16, Sysno::Ioctl),
+    (17, Sysno::Pread64),
+    (18, Sysno::Pwrite64),
+    (19, Sysno::Readv),
+    (20, Sysno::Writev),
+    (21, Sysno::Access),
+    (39, Sysno::Getpid),
+    (60, Sysno::Exit),
+    (63, Sysno::Uname),
+    (78, Sysno::Getdents),
+    (79, Sysno::Getcwd),
+    (80, Sysno::Chdir),
+    (96, Sysno::Gettimeofday),
+    (102, Sysno::Getuid),
+    (104, Sysno::Getgid),
+    (107, Sysno::Geteuid),
+    (108, Sysno::Getegid),
+    (110, Sysno::Getppid),
+    (158, Sysno::ArchPrctl),
+    (186, Sysno::Gettid),
+    (217, Sysno::Getdents64),
+    (218, Sysno::SetTidAddress),
+    (228, Sysno::ClockGettime),
+    (231, Sysno::ExitGroup),
+    (257, Sysno::OpenAt),
+    (262, Sysno::FstatAt),
+    (269, Sysno::FaccessAt),
+    (273, Sysno::SetRobustList),
+    (318, Sysno::Getrandom),
+    (332, Sysno::Statx),
+];
+
+const ARM: &[(u64, Sysno)] = &[
+    (1, Sysno::Exit),
+    (3, Sysno::Read),
+    (4, Sysno::Write),
+    (5, Sysno::Open),
+    (6, Sysno::Close),
+    (12, Sysno::Chdir),
+    (19, Sysno::Lseek),
+    (20, Sysno::Getpid),
+    (33, Sysno::Access),
+    (45, Sysno::Brk),
+    (54, Sysno::Ioctl),
+    (64, Sysno::Getppid),
+    (78, Sysno::Gettimeofday),
+    (91, Sysno::Munmap),
+    (122, Sysno::Uname),
+    (125, Sysno::Mprotect),
+    (140, Sysno::Llseek),
+    (141, Sysno::Getdents),
+    (145, Sysno::Readv),
+    (146, Sysno::Writev),
+    (174, Sysno::RtSigaction),
+    (175, Sysno::RtSigprocmask),
+    (180, Sysno::Pread64),
+    (181, Sysno::Pwrite64),
+    (183, Sysno::Getcwd),
+    (192, Sysno::Mmap2),
+    (195, Sysno::Stat),
+    (196, Sysno::Lstat),
+    (197, Sysno::Fstat),
+    (199, Sysno::Getuid),
+    (200, Sysno::Getgid),
+    (201, Sysno::Geteuid),
+    (202, Sysno::Getegid),
+    (217, Sysno::Getdents64),
+    (224, Sysno::Gettid),
+    (248, Sysno::ExitGroup),
+    (256, Sysno::SetTidAddress),
+    (263, Sysno::ClockGettime),
+    (322, Sysno::OpenAt),
+    (327, Sysno::FstatAt),
+    (334, Sysno::FaccessAt),
+    (338, Sysno::SetRobustList),
+    (384, Sysno::Getrandom),
+    (397, Sysno::Statx),
+    (403, Sysno::ClockGettime64),
+    (0xf_0005, Sysno::SetTls),
+    // the 16-bit uid calls
+    (24, Sysno::Getuid),
+    (47, Sysno::Getgid),
+    (49, Sysno::Geteuid),
+    (50, Sysno::Getegid),
+];
+
+/// The generic table of `asm-generic/unistd.h` shared by AArch64 and RISC-V.
+const GENERIC_64: &[(u64, Sysno)] = &[
+    (17, Sysno::Getcwd),
+    (29, Sysno::Ioctl),
+    (48, Sysno::FaccessAt),
+    (49, Sysno::Chdir),
+    (56, Sysno::OpenAt),
+    (57, Sysno::Close),
+    (61, Sysno::Getdents64),
+    (62, Sysno::Lseek),
+    (63, Sysno::Read),
+    (64, Sysno::Write),
+    (65, Sysno::Readv),
+    (66, Sysno::Writev),
+    (67, Sysno::Pread64),
+    (68, Sysno::Pwrite64),
+    (79, Sysno::FstatAt),
+    (80, Sysno::Fstat),
+    (93, Sysno::Exit),
+    (94, Sysno::ExitGroup),
+    (96, Sysno::SetTidAddress),
+    (99, Sysno::SetRobustList),
+    (113, Sysno::ClockGettime),
+    (134, Sysno::RtSigaction),
+    (135, Sysno::RtSigprocmask),
+    (160, Sysno::Uname),
+    (169, Sysno::Gettimeofday),
+    (172, Sysno::Getpid),
+    (173, Sysno::Getppid),
+    (174, Sysno::Getuid),
+    (175, Sysno::Geteuid),
+    (176, Sysno::Getgid),
+    (177, Sysno::Getegid),
+    (178, Sysno::Gettid),
+    (214, Sysno::Brk),
+    (215, Sysno::Munmap),
+    (222, Sysno::Mmap),
+    (226, Sysno::Mprotect),
+    (278, Sysno::Getrandom),
+    (291, Sysno::Statx),
+];
+
+/// The generic table for 32-bit RISC-V, which only has the 64-bit time, offset and
+/// `statx` calls and counts the `mmap` offset in pages.
+const GENERIC_32: &[(u64, Sysno)] = &[
+    (17, Sysno::Getcwd),
+    (29, Sysno::Ioctl),
+    (48, Sysno::FaccessAt),
+    (49, Sysno::Chdir),
+    (56, Sysno::OpenAt),
+    (57, Sysno::Close),
+    (61, Sysno::Getdents64),
+    (62, Sysno::Llseek),
+    (63, Sysno::Read),
+    (64, Sysno::Write),
+    (65, Sysno::Readv),
+    (66, Sysno::Writev),
+    (67, Sysno::Pread64),
+    (68, Sysno::Pwrite64),
+    (93, Sysno::Exit),
+    (94, Sysno::ExitGroup),
+    (96, Sysno::SetTidAddress),
+    (99, Sysno::SetRobustList),
+    (134, Sysno::RtSigaction),
+    (135, Sysno::RtSigprocmask),
+    (160, Sysno::Uname),
+    (172, Sysno::Getpid),
+    (173, Sysno::Getppid),
+    (174, Sysno::Getuid),
+    (175, Sysno::Geteuid),
+    (176, Sysno::Getgid),
+    (177, Sysno::Getegid),
+    (178, Sysno::Gettid),
+    (214, Sysno::Brk),
+    (215, Sysno::Munmap),
+    (222, Sysno::Mmap2),
+    (226, Sysno::Mprotect),
+    (278, Sysno::Getrandom),
+    (291, Sysno::Statx),
+    (403, Sysno::ClockGettime64),
+];
+
+const MIPS_O32: &[(u64, Sysno)] = &[
+    (4001, Sysno::Exit),
+    (4003, Sysno::Read),
+    (4004, Sysno::Write),
+    (4005, Sysno::Open),
+    (4006, Sysno::Close),
+    (4012, Sysno::Chdir),
+    (4019, Sysno::Lseek),
+    (4020, Sysno::Getpid),
+    (4024, Sysno::Getuid),
+    (4033, Sysno::Access),
+    (4045, Sysno::Brk),
+    (4047, Sysno::Getgid),
+    (4049, Sysno::Geteuid),
+    (4050, Sysno::Getegid),
+    (4054, Sysno::Ioctl),
+    (4064, Sysno::Getppid),
+    (4078, Sysno::Gettimeofday),
+    (4090, Sysno::Mmap),
+    (4091, Sysno::Munmap),
+    (4122, Sysno::Uname),
+    (4125, Sysno::Mprotect),
+    (4140, Sysno::Llseek),
+    (4141, Sysno::Getdents),
+    (4145, Sysno::Readv),
+    (4146, Sysno::Writev),
+    (4194, Sysno::RtSigaction),
+    (4195, Sysno::RtSigprocmask),
+    (4200, Sysno::Pread64),
+    (4201, Sysno::Pwrite64),
+    (4203, Sysno::Getcwd),
+    (4210, Sysno::Mmap2),
+    (4213, Sysno::Stat),
+    (4214, Sysno::Lstat),
+    (4215, Sysno::Fstat),
+    (4219, Sysno::Getdents64),
+    (4222, Sysno::Gettid),
+    (4246, Sysno::ExitGroup),
+    (4252, Sysno::SetTidAddress),
+    (4263, Sysno::ClockGettime),
+    (4283, Sysno::SetThreadArea),
+    (4288, Sysno::OpenAt),
+    (4293, Sysno::FstatAt),
+    (4300, Sysno::FaccessAt),
+    (4309, Sysno::SetRobustList),
+    (4353, Sysno::Getrandom),
+    (4366, Sysno::Statx),
+    (4403, Sysno::ClockGettime64),
+];
+
+const MIPS_N64: &[(u64, Sysno)] = &[
+    (5000, Sysno::Read),
+    (5001, Sysno::Write),
+    (5002, Sysno::Open),
+    (5003, Sysno::Close),
+    (5004, Sysno::Stat),
+    (5005, Sysno::Fstat),
+    (5006, Sysno::Lstat),
+    (5008, Sysno::Lseek),
+    (5009, Sysno::Mmap),
+    (5010, Sysno::Mprotect),
+    (5011, Sysno::Munmap),
+    (5012, Sysno::Brk),
+    (5013, Sysno::RtSigaction),
+    (5014, Sysno::RtSigprocmask),
+    (5015, Sysno::Ioctl),
+    (5016, Sysno::Pread64),
+    (5017, Sysno::Pwrite64),
+    (5018, Sysno::Readv),
+    (5019, Sysno::Writev),
+    (5020, Sysno::Access),
+    (5038, Sysno::Getpid),
+    (5058, Sysno::Exit),
+    (5061, Sysno::Uname),
+    (5076, Sysno::Getdents),
+    (5077, Sysno::Getcwd),
+    (5078, Sysno::Chdir),
+    (5094, Sysno::Gettimeofday),
+    (5100, Sysno::Getuid),
+    (5102, Sysno::Getgid),
+    (5105, Sysno::Geteuid),
+    (5106, Sysno::Getegid),
+    (5108, Sysno::Getppid),
+    (5178, Sysno::Gettid),
+    (5205, Sysno::ExitGroup),
+    (5212, Sysno::SetTidAddress),
+    (5222, Sysno::ClockGettime),
+    (5242, Sysno::SetThreadArea),
+    (5247, Sysno::OpenAt),
+    (5252, Sysno::FstatAt),
+    (5259, Sysno::FaccessAt),
+    (5268, Sysno::SetRobustList),
+    (5308, Sysno::Getdents64),
+    (5313, Sysno::Getrandom),
+    (5326, Sysno::Statx),
+];
+
+const PPC: &[(u64, Sysno)] = &[
+    (1, Sysno::Exit),
+    (3, Sysno::Read),
+    (4, Sysno::Write),
+    (5, Sysno::Open),
+    (6, Sysno::Close),
+    (12, Sysno::Chdir),
+    (19, Sysno::Lseek),
+    (20, Sysno::Getpid),
+    (24, Sysno::Getuid),
+    (33, Sysno::Access),
+    (45, Sysno::Brk),
+    (47, Sysno::Getgid),
+    (49, Sysno::Geteuid),
+    (50, Sysno::Getegid),
+    (54, Sysno::Ioctl),
+    (64, Sysno::Getppid),
+    (78, Sysno::Gettimeofday),
+    (90, Sysno::Mmap),
+    (91, Sysno::Munmap),
+    (122, Sysno::Uname),
+    (125, Sysno::Mprotect),
+    (140, Sysno::Llseek),
+    (141, Sysno::Getdents),
+    (145, Sysno::Readv),
+    (146, Sysno::Writev),
+    (173, Sysno::RtSigaction),
+    (174, Sysno::RtSigprocmask),
+    (179, Sysno::Pread64),
+    (180, Sysno::Pwrite64),
+    (182, Sysno::Getcwd),
+    (192, Sysno::Mmap2),
+    (195, Sysno::Stat),
+    (196, Sysno::Lstat),
+    (197, Sysno::Fstat),
+    (202, Sysno::Getdents64),
+    (207, Sysno::Gettid),
+    (232, Sysno::SetTidAddress),
+    (234, Sysno::ExitGroup),
+    (246, Sysno::ClockGettime),
+    (286, Sysno::OpenAt),
+    (291, Sysno::FstatAt),
+    (298, Sysno::FaccessAt),
+    (300, Sysno::SetRobustList),
+    (359, Sysno::Getrandom),
+    (383, Sysno::Statx),
+    (403, Sysno::ClockGettime64),
+];
+
+/// 64-bit PowerPC shares the numbers of the 32-bit one, without the 32-bit only calls.
+const PPC64: &[(u64, Sysno)] = &[
+    (1, Sysno::Exit),
+    (3, Sysno::Read),
+    (4, Sysno::Write),
+    (5, Sysno::Open),
+    (6, Sysno::Close),
+    (12, Sysno::Chdir),
+    (19, Sysno::Lseek),
+    (20, Sysno::Getpid),
+    (24, Sysno::Getuid),
+    (33, Sysno::Access),
+    (45, Sysno::Brk),
+    (47, Sysno::Getgid),
+    (49, Sysno::Geteuid),
+    (50, Sysno::Getegid),
+    (54, Sysno::Ioctl),
+    (64, Sysno::Getppid),
+    (78, Sysno::Gettimeofday),
+    (90, Sysno::Mmap),
+    (91, Sysno::Munmap),
+    (106, Sysno::Stat),
+    (107, Sysno::Lstat),
+    (108, Sysno::Fstat),
+    (122, Sysno::Uname),
+    (125, Sysno::Mprotect),
+    (141, Sysno::Getdents),
+    (145, Sysno::Readv),
+    (146, Sysno::Writev),
+    (173, Sysno::RtSigaction),
+    (174, Sysno::RtSigprocmask),
+    (179, Sysno::Pread64),
+    (180, Sysno::Pwrite64),
+    (182, Sysno::Getcwd),
+    (202, Sysno::Getdents64),
+    (207, Sysno::Gettid),
+    (232, Sysno::SetTidAddress),
+    (234, Sysno::ExitGroup),
+    (246, Sysno::ClockGettime),
+    (286, Sysno::OpenAt),
+    (291, Sysno::FstatAt),
+    (298, Sysno::FaccessAt),
+    (300, Sysno::SetRobustList),
+    (359, Sysno::Getrandom),
+    (383, Sysno::Statx),
+];
@@ -0,0 +1,812 @@
+//! The file namespace of an emulated process.
+//!
+//! A `Vfs` mounts file systems at absolute paths and keeps the table of open file
+//! descriptors. Descriptors 0, 1 and 2 are special: standard input reads from a buffer
+//! set with `Vfs::set_stdin`, standard output and error collect what the guest writes.
+//! Nothing is mounted at first, so every path is missing until a file system is.
+//!
+//! `MemFs` keeps files in memory and shares them between its clones, so a clone kept
+//! around reads back what the guest wrote; a file grows to at most `MEM_FILE_MAX`
+//! bytes. `HostFs`, with the `std` feature, exposes a
+//! host directory read-only.
+//!
+//! ```rust,ignore
+//! let fs = MemFs::new();
+//! fs.add_file("/input", &data);
+//! kernel.borrow_mut().vfs_mut().mount("/", fs.clone());
+//! kernel.borrow_mut().vfs_mut().mount("/usr", HostFs::new("/usr")?);
+//! ```
+
+#[cfg(feature = "std")]
+use super::syscall::{EACCES, EROFS};
+use super::syscall::{EBADF, EEXIST, EFBIG, EINVAL, EISDIR, ENOENT, ENOTDIR, ESPIPE};
+use alloc::{boxed::Box, collections::BTreeMap, format, rc::Rc, string::String, vec::Vec};
+use core::cell::{Cell, RefCell};
+
+/// Largest size of a `MemFs` file, writes beyond fail with `EFBIG`.
+pub const MEM_FILE_MAX: u64 = 1 << 30;
+
+/// Kind of a file.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum FileType {
+    Regular,
+    Directory,
+    /// Standard input, output and error.
+    CharDevice,
+}
+
+impl FileType {
+    /// Return the file type bits of `st_mode`.
+    #[must_use]
+    pub fn mode(self) -> u32 {
+        match self {
+            FileType::Regular => 0o100_000,
+            FileType::Directory => 0o040_000,
+            FileType::CharDevice => 0o020_000,
+        }
+    }
+
+    /// Return the `d_type` of a directory entry.
+    #[must_use]
+    pub fn dirent_type(self) -> u8 {
+        match self {
+            FileType::Regular => 8,
+            FileType::Directory => 4,
+            FileType::CharDevice => 2,
+        }
+    }
+}
+
+/// Status of a file.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Stat {
+    pub kind: FileType,
+    /// Permission bits of `st_mode`.
+    pub perms: u32,
+    pub size: u64,
+    pub ino: u64,
+}
+
+/// An entry of a directory.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DirEntry {
+    pub name: String,
+    pub kind: FileType,
+    pub ino: u64,
+}
+
+/// How a file is opened, decoded from the `O_*` flags of the guest.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct OpenFlags {
+    pub read: bool,
+    pub write: bool,
+    pub create: bool,
+    pub exclusive: bool,
+    pub truncate: bool,
+    pub append: bool,
+    pub directory: bool,
+}
+
+/// An open regular file.
+pub trait File {
+    /// Read into `buf` from `offset`, returning the number of bytes read.
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, i32>;
+
+    /// Write `data` at `offset`, returning the number of bytes written.
+    fn write_at(&mut self, offset: u64, data: &[u8]) -> Result<usize, i32>;
+
+    fn size(&self) -> u64;
+}
+
+/// A file system mounted into a `Vfs`.
+///
+/// Paths are relative to the root of the file system, without a leading slash and
+/// without `.` or `..` components, the root itself is the empty path.
+pub trait FileSystem {
+    fn stat(&mut self, path: &str) -> Result<Stat, i32>;
+
+    /// Open the regular file `path`.
+    fn open(&mut self, path: &str, flags: OpenFlags) -> Result<Box<dyn File>, i32>;
+
+    /// Return the entries of the directory `path`, without `.` and `..`.
+    fn read_dir(&mut self, path: &str) -> Result<Vec<DirEntry>, i32>;
+}
+
+enum Handle {
+    Stdin,
+    Stdout,
+    Stderr,
+    File {
+        path: String,
+        file: Box<dyn File>,
+        stat: Stat,
+        flags: OpenFlags,
+        offset: u64,
+    },
+    Dir {
+        path: String,
+        stat: Stat,
+        entries: Vec<DirEntry>,
+        /// Index of the next entry to return.
+        pos: usize,
+    },
+}
+
+/// Mounted file systems and open files of a process.
+pub struct Vfs {
+    /// Mount points without trailing slash, the root is the empty string.
+    mounts: Vec<(String, Box<dyn FileSystem>)>,
+    cwd: String,
+    handles: BTreeMap<u64, Handle>,
+    stdin: Vec<u8>,
+    stdin_pos: usize,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+impl Default for Vfs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Vfs {
+    /// Create a namespace with nothing mounted and the standard descriptors open.
+    #[must_use]
+    pub fn new() -> Vfs {
+        let mut handles = BTreeMap::new();
+        handles.insert(0, Handle::Stdin);
+        handles.insert(1, Handle::Stdout);
+        handles.insert(2, Handle::Stderr);
+        Vfs {
+            mounts: Vec::new(),
+            cwd: String::from("/"),
+            handles,
+            stdin: Vec::new(),
+            stdin_pos: 0,
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        }
+    }
+
+    /// Mount `fs` at the absolute path `path`, hiding whatever was there.
+    pub fn mount<F: FileSystem + 'static>(&mut self, path: &str, fs: F) {
+        let path = normalize("/", path);
+        let point = String::from(path.trim_end_matches('/'));
+        self.mounts.retain(|(p, _)| *p != point);
+        self.mounts.push((point, Box::new(fs)));
+    }
+
+    /// Return the working directory paths are resolved against.
+    #[must_use]
+    pub fn cwd(&self) -> &str {
+        &self.cwd
+    }
+
+    /// Set the working directory, without checking that it exists.
+    pub fn set_cwd(&mut self, path: &str) {
+        self.cwd = normalize(&self.cwd, path);
+    }
+
+    /// Set what the guest reads from standard input.
+    pub fn set_stdin(&mut self, data: &[u8]) {
+        self.stdin = data.to_vec();
+        self.stdin_pos = 0;
+    }
+
+    /// Return what the guest wrote to standard output.
+    #[must_use]
+    pub fn stdout(&self) -> &[u8] {
+        &self.stdout
+    }
+
+    /// Return what the guest wrote to standard error.
+    #[must_use]
+    pub fn stderr(&self) -> &[u8] {
+        &self.stderr
+    }
+
+    /// Clear standard output and error.
+    pub fn clear_output(&mut self) {
+        self.stdout.clear();
+        self.stderr.clear();
+    }
+
+    /// Return whether `fd` is open.
+    #[must_use]
+    pub fn is_open(&self, fd: u64) -> bool {
+        self.handles.contains_key(&fd)
+    }
+
+    /// Resolve `path` against the directory `dirfd`, or against the working directory
+    /// if `dirfd` is `None`.
+    pub(super) fn resolve(&self, dirfd: Option<u64>, path: &str) -> Result<String, i32> {
+        if path.starts_with('/') {
+            return Ok(normalize("/", path));
+        }
+        match dirfd {
+            None => Ok(normalize(&self.cwd, path)),
+            Some(fd) => match self.handles.get(&fd) {
+                Some(Handle::Dir { path: dir, .. }) => Ok(normalize(dir, path)),
+                Some(_) => Err(ENOTDIR),
+                None => Err(EBADF),
+            },
+        }
+    }
+
+    /// Return the file system mounted at the longest prefix of the absolute `path`,
+    /// along with the rest of the path.
+    fn lookup(&mut self, path: &str) -> Result<(&mut dyn FileSystem, String), i32> {
+        let (fs, rest) = self
+            .mounts
+            .iter_mut()
+            .filter_map(|(point, fs)| {
+                let rest = path.strip_prefix(point.as_str())?;
+                if rest.is_empty() || rest.starts_with('/') {
+                    Some((point.len(), fs, rest))
+                } else {
+                    None
+                }
+            })
+            .max_by_key(|(len, _, _)| *len)
+            .map(|(_, fs, rest)| (fs, String::from(rest.trim_start_matches('/'))))
+            .ok_or(ENOENT)?;
+        Ok((fs.as_mut(), rest))
+    }
+
+    pub(super) fn stat(&mut self, path: &str) -> Result<Stat, i32> {
+        let (fs, rest) = self.lookup(path)?;
+        fs.stat(&rest)
+    }
+
+    pub(super) fn fstat(&self, fd: u64) -> Result<Stat, i32> {
+        let (kind, ino) = match self.handles.get(&fd).ok_or(EBADF)? {
+            Handle::Stdin => (FileType::CharDevice, 1),
+            Handle::Stdout => (FileType::CharDevice, 2),
+            Handle::Stderr => (FileType::CharDevice, 3),
+            Handle::File { file, stat, .. } => {
+                return Ok(Stat {
+                    size: file.size(),
+                    ..*stat
+                })
+            }
+            Handle::Dir { stat, .. } => return Ok(*stat),
+        };
+        Ok(Stat {
+            kind,
+            perms: 0o620,
+            size: 0,
+            ino,
+        })
+    }
+
+    /// Open the absolute `path` on the lowest free descriptor.
+    pub(super) fn open(&mut self, path: &str, flags: OpenFlags) -> Result<u64, i32> {
+        let (fs, rest) = self.lookup(path)?;
+        let handle = match fs.stat(&rest) {
+            Ok(_) if flags.create && flags.exclusive => return Err(EEXIST),
+            Ok(stat) if stat.kind == FileType::Directory => {
+                if flags.write {
+                    return Err(EISDIR);
+                }
+                Handle::Dir {
+                    path: String::from(path),
+                    stat,
+                    entries: fs.read_dir(&rest)?,
+                    pos: 0,
+                }
+            }
+            Ok(_) if flags.directory => return Err(ENOTDIR),
+            Err(ENOENT) if flags.create => open_file(fs, path, &rest, flags)?,
+            Ok(_) => open_file(fs, path, &rest, flags)?,
+            Err(err) => return Err(err),
+        };
+        let fd = (0..).find(|fd| !self.handles.contains_key(fd)).unwrap_or(0);
+        self.handles.insert(fd, handle);
+        Ok(fd)
+    }
+
+    /// Return the absolute path `fd` was opened with.
+    pub(super) fn path(&self, fd: u64) -> Option<&str> {
+        match self.handles.get(&fd)? {
+            Handle::File { path, .. } | Handle::Dir { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    pub(super) fn close(&mut self, fd: u64) -> Result<(), i32> {
+        self.handles.remove(&fd).map(|_| ()).ok_or(EBADF)
+    }
+
+    /// Read up to `len` bytes at the offset of `fd`, or at `offset` if given.
+    pub(super) fn read(
+        &mut self,
+        fd: u64,
+        len: usize,
+        offset: Option<u64>,
+    ) -> Result<Vec<u8>, i32> {
+        match self.handles.get_mut(&fd).ok_or(EBADF)? {
+            Handle::Stdin if offset.is_none() => {
+                let rest = &self.stdin[self.stdin_pos..];
+                let data = rest[..rest.len().min(len)].to_vec();
+                self.stdin_pos += data.len();
+                Ok(data)
+            }
+            Handle::Stdin => Err(ESPIPE),
+            Handle::File {
+                file,
+                flags,
+                offset: position,
+                ..
+            } => {
+                if !flags.read {
+                    return Err(EBADF);
+                }
+                // guests ask for huge reads into small files
+                let len = len.min(file.size().saturating_sub(offset.unwrap_or(*position)) as usize);
+                let mut data = vec![0; len];
+                let done = file.read_at(offset.unwrap_or(*position), &mut data)?;
+                data.truncate(done);
+                if offset.is_none() {
+                    *position += done as u64;
+                }
+                Ok(data)
+            }
+            Handle::Dir { .. } => Err(EISDIR),
+            _ => Err(EBADF),
+        }
+    }
+
+    /// Write `data` at the offset of `fd`, or at `offset` if given.
+    pub(super) fn write(
+        &mut self,
+        fd: u64,
+        data: &[u8],
+        offset: Option<u64>,
+    ) -> Result<usize, i32> {
+        match self.handles.get_mut(&fd).ok_or(EBADF)? {
+            Handle::Stdout | Handle::Stderr if offset.is_some() => Err(ESPIPE),
+            Handle::Stdout => {
+                self.stdout.extend_from_slice(data);
+                Ok(data.len())
+            }
+            Handle::Stderr => {
+                self.stderr.extend_from_slice(data);
+                Ok(data.len())
+            }
+            Handle::File {
+                file,
+                flags,
+                offset: position,
+                ..
+            } => {
+                if !flags.write {
+                    return Err(EBADF);
+                }
+                let at = match offset {
+                    Some(offset) => offset,
+                    None if flags.append => file.size(),
+                    None => *position,
+                };
+                let done = file.write_at(at, data)?;
+                if offset.is_none() {
+                    *position = at + done as u64;
+                }
+                Ok(done)
+            }
+            Handle::Dir { .. } => Err(EISDIR),
+            Handle::Stdin => Err(EBADF),
+        }
+    }
+
+    /// Move the offset of `fd` like `lseek`, returning the new offset.
+    pub(super) fn seek(&mut self, fd: u64, offset: i64, whence: u64) -> Result<u64, i32> {
+        let (position, size) = match self.handles.get_mut(&fd).ok_or(EBADF)? {
+            Handle::File { file, offset, .. } => (offset, file.size()),
+            Handle::Dir { pos, .. } => {
+                // only rewinding is meaningful for directories
+                if offset == 0 && whence == 0 {
+                    *pos = 0;
+                    return Ok(0);
+                }
+                return Err(EINVAL);
+            }
+            _ => return Err(ESPIPE),
+        };
+        let base = match whence {
+            0 => 0,
+            1 => *position,
+            2 => size,
+            _ => return Err(EINVAL),
+        };
+        let target = base.checked_add_signed(offset).ok_or(EINVAL)?;
+        *position = target;
+        Ok(target)
+    }
+
+    /// Return the next entries of the directory `fd` as long as `fits` accepts them.
+    pub(super) fn read_dir<F>(&mut self, fd: u64, mut fits: F) -> Result<(), i32>
+    where
+        F: FnMut(&DirEntry, u64) -> bool,
+    {
+        match self.handles.get_mut(&fd).ok_or(EBADF)? {
+            Handle::Dir { entries, pos, .. } => {
+                while let Some(entry) = entries.get(*pos) {
+                    if !fits(entry, *pos as u64 + 1) {
+                        break;
+                    }
+                    *pos += 1;
+                }
+                Ok(())
+            }
+            _ => Err(ENOTDIR),
+        }
+    }
+}
+
+fn open_file(
+    fs: &mut dyn FileSystem,
+    path: &str,
+    rest: &str,
+    flags: OpenFlags,
+) -> Result<Handle, i32> {
+    let file = fs.open(rest, flags)?;
+    let stat = fs.stat(rest)?;
+    Ok(Handle::File {
+        path: String::from(path),
+        file,
+        stat,
+        flags,
+        offset: 0,
+    })
+}
+
+/// Resolve `path` against the absolute directory `base` into an absolute path without
+/// `.` and `..` components.
+fn normalize(base: &str, path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    let joined = if path.starts_with('/') {
+        String::from(path)
+    } else {
+        format!("{}/{}", base, path)
+    };
+    for part in joined.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            part => parts.push(part),
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+enum Node {
+    File {
+        data: Rc<RefCell<Vec<u8>>>,
+        ino: u64,
+    },
+    Dir {
+        ino: u64,
+    },
+}
+
+/// A file system held in memory, clones share the files.
+#[derive(Clone)]
+pub struct MemFs {
+    nodes: Rc<RefCell<BTreeMap<String, Node>>>,
+    /// Inode number of the next node, never reused so removed nodes leave gaps.
+    next_ino: Rc<Cell<u64>>,
+}
+
+impl Default for MemFs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemFs {
+    /// Create a file system holding only its root directory.
+    #[must_use]
+    pub fn new() -> MemFs {
+        let mut nodes = BTreeMap::new();
+        nodes.insert(String::new(), Node::Dir { ino: 1 });
+        MemFs {
+            nodes: Rc::new(RefCell::new(nodes)),
+            next_ino: Rc::new(Cell::new(2)),
+        }
+    }
+
+    fn next_ino(&self) -> u64 {
+        let ino = self.next_ino.get();
+        self.next_ino.set(ino + 1);
+        ino
+    }
+
+    /// Create the directory `path` and its parents.
+    pub fn add_dir(&self, path: &str) {
+        let path = relative(path);
+        let mut nodes = self.nodes.borrow_mut();
+        let mut end = 0;
+        while end < path.len() {
+            end = path[end + 1..]
+                .find('/')
+                .map_or(path.len(), |i| end + 1 + i);
+            let dir = &path[..end];
+            if !nodes.contains_key(dir) {
+                let ino = self.next_ino();
+                nodes.insert(String::from(dir), Node::Dir { ino });
+            }
+        }
+    }
+
+    /// Create or replace the file `path` holding `data`, creating its parents.
+    pub fn add_file(&self, path: &str, data: &[u8]) {
+        let path = relative(path);
+        if let Some((parent, _)) = path.rsplit_once('/') {
+            self.add_dir(parent);
+        }
+        let ino = self.next_ino();
+        self.nodes.borrow_mut().insert(
+            path,
+            Node::File {
+                data: Rc::new(RefCell::new(data.to_vec())),
+                ino,
+            },
+        );
+    }
+
+    /// Return the contents of the file `path`.
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<Vec<u8>> {
+        match self.nodes.borrow().get(&relative(path))? {
+            Node::File { data, .. } => Some(data.borrow().clone()),
+            Node::Dir { .. } => None,
+        }
+    }
+
+    /// Remove the file or directory `path` along with everything below it.
+    pub fn remove(&self, path: &str) {
+        let path = relative(path);
+        let prefix = format!("{}/", path);
+        self.nodes
+            .borrow_mut()
+            .retain(|p, _| p.is_empty() || (*p != path && !p.starts_with(&prefix)));
+    }
+}
+
+/// Return `path` relative to the root, as `FileSystem` expects it.
+fn relative(path: &str) -> String {
+    String::from(normalize("/", path).trim_start_matches('/'))
+}
+
+impl FileSystem for MemFs {
+    fn stat(&mut self, path: &str) -> Result<Stat, i32> {
+        Ok(match self.nodes.borrow().get(path).ok_or(ENOENT)? {
+            Node::File { data, ino } => Stat {
+                kind: FileType::Regular,
+                perms: 0o644,
+                size: data.borrow().len() as u64,
+                ino: *ino,
+            },
+            Node::Dir { ino } => Stat {
+                kind: FileType::Directory,
+                perms: 0o755,
+                size: 0,
+                ino: *ino,
+            },
+        })
+    }
+
+    fn open(&mut self, path: &str, flags: OpenFlags) -> Result<Box<dyn File>, i32> {
+        let mut nodes = self.nodes.borrow_mut();
+        if !nodes.contains_key(path) {
+            let parent = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+            match nodes.get(parent) {
+                Some(Node::Dir { .. }) if flags.create => {}
+                Some(Node::File { .. }) => return Err(ENOTDIR),
+                _ => return Err(ENOENT),
+            }
+            let ino = self.next_ino();
+            nodes.insert(
+                String::from(path),
+                Node::File {
+                    data: Rc::new(RefCell::new(Vec::new())),
+                    ino,
+                },
+            );
+        }
+        match nodes.get(path) {
+            Some(Node::File { data, .. }) => {
+                if flags.truncate && flags.write {
+                    data.borrow_mut().clear();
+                }
+                Ok(Box::new(MemFile(data.clone())))
+            }
+            _ => Err(EISDIR),
+        }
+    }
+
+    fn read_dir(&mut self, path: &str) -> Result<Vec<DirEntry>, i32> {
+        let nodes = self.nodes.borrow();
+        match nodes.get(path) {
+            Some(Node::Dir { .. }) => {}
+            Some(Node::File { .. }) => return Err(ENOTDIR),
+            None => return Err(ENOENT),
+        }
+        let prefix = if path.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", path)
+        };
+        Ok(nodes
+            .iter()
+            .filter_map(|(p, node)| {
+                let name = p.strip_prefix(prefix.as_str())?;
+                if name.is_empty() || name.contains('/') {
+                    return None;
+                }
+                let (kind, ino) = match node {
+                    Node::File { ino, .. } => (FileType::Regular, *ino),
+                    Node::Dir { ino } => (FileType::Directory, *ino),
+                };
+                Some(DirEntry {
+                    name: String::from(name),
+                    kind,
+                    ino,
+                })
+            })
+            .collect())
+    }
+}
+
+struct MemFile(Rc<RefCell<Vec<u8>>>);
+
+impl File for MemFile {
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, i32> {
+        let data = self.0.borrow();
+        let rest = data.get(offset as usize..).unwrap_or_default();
+        let len = rest.len().min(buf.len());
+        buf[..len].copy_from_slice(&rest[..len]);
+        Ok(len)
+    }
+
+    fn write_at(&mut self, offset: u64, data: &[u8]) -> Result<usize, i32> {
+        let mut file = self.0.borrow_mut();
+        let end = offset
+            .checked_add(data.len() as u64)
+            .filter(|&end| end <= MEM_FILE_MAX)
+            .ok_or(EFBIG)? as usize;
+        if file.len() < end {
+            file.resize(end, 0);
+        }
+        file[offset as usize..end].copy_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn size(&self) -> u64 {
+        self.0.borrow().len() as u64
+    }
+}
+
+/// A host directory exposed read-only.
+///
+/// Paths are checked to stay below the directory after resolving symbolic links.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct HostFs {
+    root: std::path::PathBuf,
+}
+
+#[cfg(feature = "std")]
+impl HostFs {
+    /// Expose the directory `root`.
+    pub fn new<P: AsRef<std::path::Path>>(root: P) -> std::io::Result<HostFs> {
+        Ok(HostFs {
+            root: root.as_ref().canonicalize()?,
+        })
+    }
+
+    fn host_path(&self, path: &str) -> Result<std::path::PathBuf, i32> {
+        let host = self.root.join(path).canonicalize().map_err(errno)?;
+        if host.starts_with(&self.root) {
+            Ok(host)
+        } else {
+            Err(EACCES)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+fn errno(err: std::io::Error) -> i32 {
+    err.raw_os_error().unwrap_or(ENOENT)
+}
+
+/// Return an inode number for a host path, stable across runs.
+#[cfg(feature = "std")]
+fn host_ino(path: &std::path::Path) -> u64 {
+    // FNV-1a
+    path.to_string_lossy()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3)
+        })
+}
+
+#[cfg(feature = "std")]
+impl FileSystem for HostFs {
+    fn stat(&mut self, path: &str) -> Result<Stat, i32> {
+        let host = self.host_path(path)?;
+        let metadata = std::fs::metadata(&host).map_err(errno)?;
+        let (kind, perms) = if metadata.is_dir() {
+            (FileType::Directory, 0o555)
+        } else {
+            (FileType::Regular, 0o444)
+        };
+        Ok(Stat {
+            kind,
+            perms,
+            size: if metadata.is_dir() { 0 } else { metadata.len() },
+            ino: host_ino(&host),
+        })
+    }
+
+    fn open(&mut self, path: &str, flags: OpenFlags) -> Result<Box<dyn File>, i32> {
+        if flags.write || flags.create || flags.truncate {
+            return Err(EROFS);
+        }
+        let file = std::fs::File::open(self.host_path(path)?).map_err(errno)?;
+        let size = file.metadata().map_err(errno)?.len();
+        Ok(Box::new(HostFile { file, size }))
+    }
+
+    fn read_dir(&mut self, path: &str) -> Result<Vec<DirEntry>, i32> {
+        let host = self.host_path(path)?;
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(&host).map_err(errno)? {
+            let entry = entry.map_err(errno)?;
+            let kind = match entry.file_type() {
+                Ok(kind) if kind.is_dir() => FileType::Directory,
+                _ => FileType::Regular,
+            };
+            entries.push(DirEntry {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                kind,
+                ino: host_ino(&entry.path()),
+            });
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(entries)
+    }
+}
+
+#[cfg(feature = "std")]
+struct HostFile {
+    file: std::fs::File,
+    size: u64,
+}
+
+#[cfg(feature = "std")]
+impl File for HostFile {
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<usize, i32> {
+        use std::io::{Read, Seek, SeekFrom};
+        self.file.seek(SeekFrom::Start(offset)).map_err(errno)?;
+        let mut done = 0;
+        while done < buf.len() {
+            match self.file.read(&mut buf[done..]).map_err(errno)? {
+                0 => break,
+                n => done += n,
+            }
+        }
+        Ok(done)
+    }
+
+    fn write_at(&mut self, _offset: u64, _data: &[u8]) -> Result<usize, i32> {
+        Err(EROFS)
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+}
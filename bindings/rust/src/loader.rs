@@ -0,0 +1,89 @@
+//! Loaders placing executable images into a `Unicorn` instance.
+
+pub mod elf;
+pub mod firmware;
+pub mod hex;
+pub mod pe;
+
+use crate::unicorn_const::{uc_error, Permission, Query};
+use crate::Unicorn;
+use alloc::vec::Vec;
+
+/// Map the pages covering the areas `(address, size, perms)`.
+///
+/// Areas sharing a page get the union of their permissions, consecutive pages with the
+/// same permissions are mapped at once. The pages are not written to, so fresh pages
+/// are zero.
+pub(crate) fn map_areas<D>(
+    emu: &mut Unicorn<D>,
+    areas: &[(u64, u64, Permission)],
+) -> Result<(), uc_error> {
+    let page_size = emu.query(Query::PAGE_SIZE)? as u64;
+    // where page rounded areas begin (+1) and end (-1), 128-bit so an area may end at
+    // the top of the address space
+    let mut events: Vec<(u128, i64, Permission)> = Vec::new();
+    for &(address, size, perms) in areas {
+        if size == 0 {
+            continue;
+        }
+        let first = address & !(page_size - 1);
+        let last = address.checked_add(size - 1).ok_or(uc_error::ARG)? & !(page_size - 1);
+        events.push((u128::from(first), 1, perms));
+        events.push((u128::from(last) + u128::from(page_size), -1, perms));
+    }
+    events.sort_unstable_by_key(|&(at, _, _)| at);
+
+    // sweep over the events counting the areas covering each permission bit, the
+    // permissions only change where an area begins or ends
+    let bits = [Permission::READ, Permission::WRITE, Permission::EXEC];
+    let mut counts = [0i64; 3];
+    let mut active = 0i64;
+    let mut run: Option<(u128, u128, Permission)> = None;
+    let mut i = 0;
+    while i < events.len() {
+        let at = events[i].0;
+        while i < events.len() && events[i].0 == at {
+            let (_, delta, perms) = events[i];
+            active += delta;
+            for (count, &bit) in counts.iter_mut().zip(&bits) {
+                if perms.contains(bit) {
+                    *count += delta;
+                }
+            }
+            i += 1;
+        }
+        let next = match events.get(i) {
+            Some(&(next, _, _)) => next,
+            None => break,
+        };
+        if active == 0 {
+            continue;
+        }
+        let perms = counts
+            .iter()
+            .zip(&bits)
+            .filter(|(&count, _)| count > 0)
+            .fold(Permission::NONE, |perms, (_, &bit)| perms | bit);
+        match &mut run {
+            Some((_, end, p)) if *end == at && *p == perms => *end = next,
+            _ => {
+                if let Some(run) = run {
+                    map_run(emu, run)?;
+                }
+                run = Some((at, next, perms));
+            }
+        }
+    }
+    if let Some(run) = run {
+        map_run(emu, run)?;
+    }
+    Ok(())
+}
+
+fn map_run<D>(
+    emu: &mut Unicorn<D>,
+    (begin, end, perms): (u128, u128, Permission),
+) -> Result<(), uc_error> {
+    let size = usize::try_from(end - begin).map_err(|_| uc_error::ARG)?;
+    emu.mem_map(begin as u64, size, perms)
+}
@@ -0,0 +1,708 @@
+//! ELF loader.
+//!
+//! Parses ELF32 and ELF64 images of either endianness, maps their `PT_LOAD` segments
+//! with the permissions of the segment, copies the file contents and zero-fills the
+//! rest of every segment, i.e. `.bss`. The architecture and mode of the image follow
+//! from `e_machine`, `EI_CLASS` and `EI_DATA`, see `Elf::arch_mode`.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("firmware.elf")?;
+//! let (arch, mode) = Elf::parse(&data)?.arch_mode()?;
+//! let mut emu = Unicorn::new(arch, mode)?;
+//! let image = elf::load(&mut emu, &data, 0)?;
+//! let main = image.symbol("main").unwrap().address;
+//! ```
+
+pub mod coredump;
+pub mod link;
+
+#[cfg(feature = "std")]
+pub use link::Sysroot;
+pub use link::{LinkError, LinkedImage, Linker, Resolver};
+
+use super::map_areas;
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission};
+use crate::Unicorn;
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
+
+pub const ET_REL: u16 = 1;
+pub const ET_EXEC: u16 = 2;
+pub const ET_DYN: u16 = 3;
+pub const ET_CORE: u16 = 4;
+
+pub const PT_LOAD: u32 = 1;
+pub const PT_DYNAMIC: u32 = 2;
+pub const PT_INTERP: u32 = 3;
+pub const PT_NOTE: u32 = 4;
+pub const PT_PHDR: u32 = 6;
+pub const PT_TLS: u32 = 7;
+
+pub const PF_X: u32 = 1;
+pub const PF_W: u32 = 2;
+pub const PF_R: u32 = 4;
+
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_STRTAB: u32 = 3;
+pub const SHT_DYNSYM: u32 = 11;
+
+const SHN_UNDEF: u16 = 0;
+const SHN_ABS: u16 = 0xfff1;
+
+const EM_SPARC: u16 = 2;
+const EM_386: u16 = 3;
+const EM_68K: u16 = 4;
+const EM_MIPS: u16 = 8;
+const EM_SPARC32PLUS: u16 = 18;
+const EM_PPC: u16 = 20;
+const EM_PPC64: u16 = 21;
+const EM_S390: u16 = 22;
+const EM_ARM: u16 = 40;
+const EM_SPARCV9: u16 = 43;
+const EM_TRICORE: u16 = 44;
+const EM_X86_64: u16 = 62;
+const EM_AARCH64: u16 = 183;
+const EM_RISCV: u16 = 243;
+
+const EF_ARM_BE8: u32 = 0x0080_0000;
+
+/// Malformed or unsupported image.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ElfError {
+    /// Not an ELF image, or of an unknown class or data encoding.
+    BadHeader,
+    /// A header, segment or table lies outside of the image.
+    Truncated,
+    /// `e_machine` names an architecture the engine does not emulate.
+    UnsupportedMachine(u16),
+    /// The image is for another architecture or mode than the instance.
+    ArchMismatch { arch: Arch, mode: Mode },
+    /// Mapping or writing the image failed.
+    Emu(uc_error),
+}
+
+impl From<uc_error> for ElfError {
+    fn from(err: uc_error) -> Self {
+        ElfError::Emu(err)
+    }
+}
+
+/// An entry of the program header table.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct ProgramHeader {
+    /// `p_type`, e.g. `PT_LOAD`.
+    pub kind: u32,
+    /// `p_flags`, made of `PF_R`, `PF_W` and `PF_X`.
+    pub flags: u32,
+    pub offset: u64,
+    pub vaddr: u64,
+    pub filesz: u64,
+    pub memsz: u64,
+    pub align: u64,
+}
+
+impl ProgramHeader {
+    /// Return the permissions of the segment.
+    #[must_use]
+    pub fn permissions(&self) -> Permission {
+        let mut perms = Permission::NONE;
+        if self.flags & PF_R != 0 {
+            perms |= Permission::READ;
+        }
+        if self.flags & PF_W != 0 {
+            perms |= Permission::WRITE;
+        }
+        if self.flags & PF_X != 0 {
+            perms |= Permission::EXEC;
+        }
+        perms
+    }
+}
+
+/// An entry of the section header table.
+#[derive(PartialEq, Debug, Clone)]
+pub struct SectionHeader {
+    pub name: String,
+    /// `sh_type`, e.g. `SHT_SYMTAB`.
+    pub kind: u32,
+    pub flags: u64,
+    pub addr: u64,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+    pub info: u32,
+    pub entsize: u64,
+}
+
+/// Type of a symbol, `STT_*`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SymbolKind {
+    NoType,
+    Object,
+    Func,
+    Tls,
+    Other(u8),
+}
+
+/// Binding of a symbol, `STB_*`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SymbolBinding {
+    Local,
+    Global,
+    Weak,
+    Other(u8),
+}
+
+/// A symbol of `.symtab` or `.dynsym`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    /// Address in the guest, the load bias applied. Thumb functions have bit 0 set.
+    pub address: u64,
+    pub size: u64,
+    pub kind: SymbolKind,
+    pub binding: SymbolBinding,
+    /// Whether the image defines the symbol, rather than importing it.
+    pub defined: bool,
+}
+
+/// A parsed ELF image.
+#[derive(Debug, Clone)]
+pub struct Elf<'d> {
+    data: &'d [u8],
+    pub is_64bit: bool,
+    pub big_endian: bool,
+    /// `e_type`, e.g. `ET_EXEC`.
+    pub kind: u16,
+    pub machine: u16,
+    pub flags: u32,
+    pub entry: u64,
+    /// File offset of the program header table.
+    pub phoff: u64,
+    pub phentsize: u16,
+    pub program_headers: Vec<ProgramHeader>,
+    pub section_headers: Vec<SectionHeader>,
+}
+
+impl<'d> Elf<'d> {
+    /// Parse the headers of the image in `data`.
+    pub fn parse(data: &'d [u8]) -> Result<Elf<'d>, ElfError> {
+        if data.len() < 16 || &data[..4] != b"\x7fELF" {
+            return Err(ElfError::BadHeader);
+        }
+        let is_64bit = match data[4] {
+            1 => false,
+            2 => true,
+            _ => return Err(ElfError::BadHeader),
+        };
+        let big_endian = match data[5] {
+            1 => false,
+            2 => true,
+            _ => return Err(ElfError::BadHeader),
+        };
+        let mut elf = Elf {
+            data,
+            is_64bit,
+            big_endian,
+            kind: 0,
+            machine: 0,
+            flags: 0,
+            entry: 0,
+            phoff: 0,
+            phentsize: 0,
+            program_headers: Vec::new(),
+            section_headers: Vec::new(),
+        };
+        elf.kind = elf.u16(16)?;
+        elf.machine = elf.u16(18)?;
+        elf.entry = elf.word(24)?;
+        // everything after e_entry is shifted by the size of the two words before
+        let w = if is_64bit { 8 } else { 4 };
+        elf.phoff = elf.word(24 + w)?;
+        let shoff = elf.word(24 + 2 * w)?;
+        let h = 24 + 3 * w;
+        elf.flags = elf.u32(h)?;
+        elf.phentsize = elf.u16(h + 6)?;
+        let phnum = elf.u16(h + 8)?;
+        let shentsize = elf.u16(h + 10)?;
+        let shnum = elf.u16(h + 12)?;
+        let shstrndx = elf.u16(h + 14)?;
+
+        for i in 0..u64::from(phnum) {
+            let at = table_entry(elf.phoff, i, u64::from(elf.phentsize))?;
+            let ph = elf.program_header(at)?;
+            elf.program_headers.push(ph);
+        }
+        if shoff != 0 {
+            let mut names = Vec::new();
+            for i in 0..u64::from(shnum) {
+                let at = table_entry(shoff, i, u64::from(shentsize))?;
+                let (name, sh) = elf.section_header(at)?;
+                names.push(name);
+                elf.section_headers.push(sh);
+            }
+            if let Some(strtab) = elf.section_headers.get(shstrndx as usize).cloned() {
+                let names: Vec<String> = names
+                    .into_iter()
+                    .map(|name| String::from(elf.data_str(&strtab, name).unwrap_or("")))
+                    .collect();
+                for (sh, name) in elf.section_headers.iter_mut().zip(names) {
+                    sh.name = name;
+                }
+            }
+        }
+        Ok(elf)
+    }
+
+    /// Return the architecture and mode to emulate the image with.
+    ///
+    /// ARM images with an odd entry point start in Thumb mode.
+    pub fn arch_mode(&self) -> Result<(Arch, Mode), ElfError> {
+        let endian = if self.big_endian {
+            Mode::BIG_ENDIAN
+        } else {
+            Mode::LITTLE_ENDIAN
+        };
+        let width = if self.is_64bit {
+            Mode::MODE_64
+        } else {
+            Mode::MODE_32
+        };
+        let arch_mode = match self.machine {
+            EM_386 => (Arch::X86, Mode::MODE_32),
+            EM_X86_64 => (Arch::X86, Mode::MODE_64),
+            EM_ARM => {
+                let mut mode = if self.entry & 1 == 1 {
+                    Mode::THUMB
+                } else {
+                    Mode::ARM
+                };
+                if self.flags & EF_ARM_BE8 != 0 {
+                    // code stays little endian, only data is big endian
+                    mode |= Mode::ARMBE8;
+                } else {
+                    mode |= endian;
+                }
+                (Arch::ARM, mode)
+            }
+            EM_AARCH64 => (Arch::ARM64, Mode::ARM | endian),
+            EM_MIPS => (Arch::MIPS, width | endian),
+            EM_PPC | EM_PPC64 => (Arch::PPC, width | endian),
+            EM_RISCV => (Arch::RISCV, width),
+            EM_SPARC | EM_SPARC32PLUS => (Arch::SPARC, Mode::SPARC32 | endian),
+            EM_SPARCV9 => (Arch::SPARC, Mode::SPARC64 | endian),
+            EM_68K => (Arch::M68K, endian),
+            EM_S390 => (Arch::S390X, endian),
+            EM_TRICORE => (Arch::TRICORE, endian),
+            machine => return Err(ElfError::UnsupportedMachine(machine)),
+        };
+        Ok(arch_mode)
+    }
+
+    /// Return the bias to add to the addresses of the image when loading it at `base`.
+    ///
+    /// Only position independent images, i.e. `ET_DYN`, can be moved; their lowest
+    /// segment is placed at `base`.
+    #[must_use]
+    pub fn load_bias(&self, base: u64) -> u64 {
+        if self.kind != ET_DYN {
+            return 0;
+        }
+        let lowest = self
+            .loads()
+            .map(|ph| ph.vaddr - ph.vaddr % ph.align.max(1))
+            .min()
+            .unwrap_or(0);
+        base.wrapping_sub(lowest)
+    }
+
+    /// Return the `PT_LOAD` segments.
+    pub fn loads(&self) -> impl Iterator<Item = &ProgramHeader> {
+        self.program_headers.iter().filter(|ph| ph.kind == PT_LOAD)
+    }
+
+    /// Return the file contents of a segment.
+    pub fn segment_data(&self, ph: &ProgramHeader) -> Result<&'d [u8], ElfError> {
+        self.range(ph.offset, ph.filesz)
+    }
+
+    /// Return the file contents of a section.
+    pub fn section_data(&self, sh: &SectionHeader) -> Result<&'d [u8], ElfError> {
+        self.range(sh.offset, sh.size)
+    }
+
+    /// Return the symbols of `.symtab` and `.dynsym`, their addresses moved by `bias`.
+    pub fn symbols(&self, bias: u64) -> Result<Vec<Symbol>, ElfError> {
+        let mut symbols = Vec::new();
+        let mut seen = BTreeSet::new();
+        // .symtab comes first, .dynsym only adds what a stripped image still knows
+        let tables = self
+            .section_headers
+            .iter()
+            .filter(|sh| sh.kind == SHT_SYMTAB)
+            .chain(
+                self.section_headers
+                    .iter()
+                    .filter(|sh| sh.kind == SHT_DYNSYM),
+            );
+        for table in tables {
+            let strtab = self
+                .section_headers
+                .get(table.link as usize)
+                .ok_or(ElfError::Truncated)?;
+            let entsize = if self.is_64bit { 24 } else { 16 };
+            // entry 0 is reserved
+            for i in 1..table.size / entsize {
+                let at = table_entry(table.offset, i, entsize)?;
+                let sym = self.symbol(at, strtab, bias)?;
+                if sym.name.is_empty() || !seen.insert((sym.name.clone(), sym.address)) {
+                    continue;
+                }
+                symbols.push(sym);
+            }
+        }
+        if !self.section_headers.iter().any(|sh| sh.kind == SHT_DYNSYM) {
+            // without section headers, .dynsym is still found through the dynamic
+            // section; a broken one only costs the symbols, not the image
+            let dynamic = self.dynamic(bias).map(|d| d.symbols).unwrap_or_default();
+            for sym in dynamic.into_iter().skip(1) {
+                if !sym.name.is_empty() && seen.insert((sym.name.clone(), sym.address)) {
+                    symbols.push(sym);
+                }
+            }
+        }
+        Ok(symbols)
+    }
+
+    /// Return the guest address of the program header table, moved by `bias`. Segments
+    /// whose file range or address overflows are not considered.
+    #[must_use]
+    pub fn phdr_address(&self, bias: u64) -> Option<u64> {
+        if let Some(ph) = self.program_headers.iter().find(|ph| ph.kind == PT_PHDR) {
+            return Some(ph.vaddr.wrapping_add(bias));
+        }
+        self.loads()
+            .find(|ph| {
+                ph.offset <= self.phoff
+                    && ph
+                        .offset
+                        .checked_add(ph.filesz)
+                        .is_some_and(|end| self.phoff < end)
+            })
+            .and_then(|ph| ph.vaddr.checked_add(self.phoff - ph.offset))
+            .map(|address| address.wrapping_add(bias))
+    }
+
+    fn symbol(&self, at: usize, strtab: &SectionHeader, bias: u64) -> Result<Symbol, ElfError> {
+        let (name, info, shndx, value, size) = if self.is_64bit {
+            (
+                self.u32(at)?,
+                self.u8(at + 4)?,
+                self.u16(at + 6)?,
+                self.u64(at + 8)?,
+                self.u64(at + 16)?,
+            )
+        } else {
+            let (value, size) = (self.u32(at + 4)?, self.u32(at + 8)?);
+            (
+                self.u32(at)?,
+                self.u8(at + 12)?,
+                self.u16(at + 14)?,
+                u64::from(value),
+                u64::from(size),
+            )
+        };
+        let kind = match info & 0xf {
+            0 => SymbolKind::NoType,
+            1 => SymbolKind::Object,
+            2 => SymbolKind::Func,
+            6 => SymbolKind::Tls,
+            other => SymbolKind::Other(other),
+        };
+        let binding = match info >> 4 {
+            0 => SymbolBinding::Local,
+            1 => SymbolBinding::Global,
+            2 => SymbolBinding::Weak,
+            other => SymbolBinding::Other(other),
+        };
+        let defined = shndx != SHN_UNDEF;
+        // TLS symbols hold offsets into the TLS block rather than addresses
+        let moves = defined && shndx != SHN_ABS && kind != SymbolKind::Tls;
+        Ok(Symbol {
+            name: String::from(self.data_str(strtab, name).unwrap_or("")),
+            address: if moves {
+                value.wrapping_add(bias)
+            } else {
+                value
+            },
+            size,
+            kind,
+            binding,
+            defined,
+        })
+    }
+
+    fn program_header(&self, at: usize) -> Result<ProgramHeader, ElfError> {
+        Ok(if self.is_64bit {
+            ProgramHeader {
+                kind: self.u32(at)?,
+                flags: self.u32(at + 4)?,
+                offset: self.u64(at + 8)?,
+                vaddr: self.u64(at + 16)?,
+                filesz: self.u64(at + 32)?,
+                memsz: self.u64(at + 40)?,
+                align: self.u64(at + 48)?,
+            }
+        } else {
+            ProgramHeader {
+                kind: self.u32(at)?,
+                offset: self.word(at + 4)?,
+                vaddr: self.word(at + 8)?,
+                filesz: self.word(at + 16)?,
+                memsz: self.word(at + 20)?,
+                flags: self.u32(at + 24)?,
+                align: self.word(at + 28)?,
+            }
+        })
+    }
+
+    fn section_header(&self, at: usize) -> Result<(u32, SectionHeader), ElfError> {
+        let w = if self.is_64bit { 8 } else { 4 };
+        let name = self.u32(at)?;
+        let sh = SectionHeader {
+            name: String::new(),
+            kind: self.u32(at + 4)?,
+            flags: self.word(at + 8)?,
+            addr: self.word(at + 8 + w)?,
+            offset: self.word(at + 8 + 2 * w)?,
+            size: self.word(at + 8 + 3 * w)?,
+            link: self.u32(at + 8 + 4 * w)?,
+            info: self.u32(at + 12 + 4 * w)?,
+            entsize: self.word(at + 16 + 5 * w)?,
+        };
+        Ok((name, sh))
+    }
+
+    /// Return the NUL terminated string at `offset` of a string table.
+    pub(crate) fn data_str(&self, strtab: &SectionHeader, offset: u32) -> Option<&'d str> {
+        let table = self.section_data(strtab).ok()?;
+        let s = table.get(offset as usize..)?;
+        let len = s.iter().position(|&b| b == 0)?;
+        core::str::from_utf8(&s[..len]).ok()
+    }
+
+    fn range(&self, offset: u64, size: u64) -> Result<&'d [u8], ElfError> {
+        let end = offset.checked_add(size).ok_or(ElfError::Truncated)?;
+        self.data
+            .get(offset as usize..end as usize)
+            .ok_or(ElfError::Truncated)
+    }
+
+    fn bytes<const N: usize>(&self, at: usize) -> Result<[u8; N], ElfError> {
+        let end = at.checked_add(N).ok_or(ElfError::Truncated)?;
+        let b = self.data.get(at..end).ok_or(ElfError::Truncated)?;
+        let mut bytes: [u8; N] = b.try_into().unwrap();
+        if self.big_endian {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    pub(crate) fn u8(&self, at: usize) -> Result<u8, ElfError> {
+        self.data.get(at).copied().ok_or(ElfError::Truncated)
+    }
+
+    pub(crate) fn u16(&self, at: usize) -> Result<u16, ElfError> {
+        self.bytes(at).map(u16::from_le_bytes)
+    }
+
+    pub(crate) fn u32(&self, at: usize) -> Result<u32, ElfError> {
+        self.bytes(at).map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn u64(&self, at: usize) -> Result<u64, ElfError> {
+        self.bytes(at).map(u64::from_le_bytes)
+    }
+
+    /// Read an address sized word.
+    pub(crate) fn word(&self, at: usize) -> Result<u64, ElfError> {
+        if self.is_64bit {
+            self.u64(at)
+        } else {
+            self.u32(at).map(u64::from)
+        }
+    }
+}
+
+/// A mapped segment.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Segment {
+    pub address: u64,
+    /// Offset of the segment in the file.
+    pub offset: u64,
+    /// Size in memory, including the zero-filled part.
+    pub size: u64,
+    /// Bytes copied from the image, the rest is zero-filled.
+    pub file_size: u64,
+    pub perms: Permission,
+}
+
+/// Description of a loaded image.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LoadedElf {
+    pub arch: Arch,
+    pub mode: Mode,
+    /// Entry point, the load bias applied.
+    pub entry: u64,
+    /// Added to every address of the image, non-zero only for moved `ET_DYN` images.
+    pub bias: u64,
+    /// Guest address of the program header table, if it is part of a segment.
+    pub phdr: Option<u64>,
+    pub phentsize: u16,
+    pub phnum: u16,
+    pub segments: Vec<Segment>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl LoadedElf {
+    /// Return the defined symbol `name`.
+    #[must_use]
+    pub fn symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|s| s.defined && s.name == name)
+    }
+
+    /// Name the pages of the segments after the file at `path` in `memory`, so they show
+    /// up in `VirtualMemory::maps` like a mapped file.
+    pub fn track<D>(
+        &self,
+        emu: &Unicorn<D>,
+        memory: &mut VirtualMemory,
+        path: &str,
+    ) -> Result<(), uc_error> {
+        let page_size = memory.page_size();
+        for segment in &self.segments {
+            let begin = segment.address & !(page_size - 1);
+            let origin = Origin::File {
+                path: String::from(path),
+                offset: segment.offset.saturating_sub(segment.address - begin),
+            };
+            memory.set_origin(emu, begin, segment.address - begin + segment.size, origin)?;
+        }
+        Ok(())
+    }
+
+    /// Return the lowest and the highest address of the image.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(u64, u64)> {
+        let low = self.segments.iter().map(|s| s.address).min()?;
+        let mut high = 0;
+        for s in &self.segments {
+            high = high.max(s.address.checked_add(s.size - 1)?);
+        }
+        Some((low, high))
+    }
+}
+
+/// Map the image in `data` into `emu` without touching any register.
+///
+/// `ET_DYN` images are loaded with their lowest segment at `base`, other images at the
+/// addresses they were linked for.
+pub fn map<D>(emu: &mut Unicorn<D>, data: &[u8], base: u64) -> Result<LoadedElf, ElfError> {
+    let elf = Elf::parse(data)?;
+    let (arch, mode) = elf.arch_mode()?;
+    check_arch(emu, arch, mode)?;
+
+    let bias = elf.load_bias(base);
+    let mut segments = Vec::new();
+    for ph in elf.loads().filter(|ph| ph.memsz > 0) {
+        let address = ph.vaddr.wrapping_add(bias);
+        // the segment has to end within the address space
+        address
+            .checked_add(ph.memsz - 1)
+            .ok_or(ElfError::Truncated)?;
+        segments.push(Segment {
+            address,
+            offset: ph.offset,
+            size: ph.memsz,
+            file_size: ph.filesz.min(ph.memsz),
+            perms: ph.permissions(),
+        });
+    }
+    let areas: Vec<_> = segments
+        .iter()
+        .map(|s| (s.address, s.size, s.perms))
+        .collect();
+    map_areas(emu, &areas)?;
+
+    for (ph, segment) in elf.loads().filter(|ph| ph.memsz > 0).zip(&segments) {
+        let contents = elf.segment_data(ph)?;
+        emu.mem_write(segment.address, &contents[..segment.file_size as usize])?;
+        if segment.size > segment.file_size {
+            zero_fill(
+                emu,
+                segment.address + segment.file_size,
+                segment.size - segment.file_size,
+            )?;
+        }
+    }
+
+    Ok(LoadedElf {
+        arch,
+        mode,
+        entry: elf.entry.wrapping_add(bias),
+        bias,
+        phdr: elf.phdr_address(bias),
+        phentsize: elf.phentsize,
+        phnum: elf.program_headers.len() as u16,
+        segments,
+        symbols: elf.symbols(bias)?,
+    })
+}
+
+/// Map the image in `data` into `emu` like `map` and point the PC at its entry.
+pub fn load<D>(emu: &mut Unicorn<D>, data: &[u8], base: u64) -> Result<LoadedElf, ElfError> {
+    let image = map(emu, data, base)?;
+    // bit 0 of the entry selects Thumb mode on ARM
+    emu.set_pc(image.entry)?;
+    Ok(image)
+}
+
+/// Fail unless `emu` emulates `arch` with the word size and, except for ARM, the
+/// endianness of `mode`.
+pub(crate) fn check_arch<D>(emu: &Unicorn<D>, arch: Arch, mode: Mode) -> Result<(), ElfError> {
+    let width = Mode::MODE_32 | Mode::MODE_64;
+    let emu_mode = emu.get_mode();
+    if emu.get_arch() != arch
+        || emu_mode & width != mode & width
+        || (arch != Arch::ARM && emu_mode & Mode::BIG_ENDIAN != mode & Mode::BIG_ENDIAN)
+    {
+        return Err(ElfError::ArchMismatch { arch, mode });
+    }
+    Ok(())
+}
+
+/// Return the file offset of entry `index` of a table of `entsize` byte entries at
+/// `offset`.
+fn table_entry(offset: u64, index: u64, entsize: u64) -> Result<usize, ElfError> {
+    index
+        .checked_mul(entsize)
+        .and_then(|delta| offset.checked_add(delta))
+        .and_then(|at| usize::try_from(at).ok())
+        .ok_or(ElfError::Truncated)
+}
+
+/// Write `size` zero bytes at `address`.
+pub(crate) fn zero_fill<D>(
+    emu: &mut Unicorn<D>,
+    mut address: u64,
+    mut size: u64,
+) -> Result<(), uc_error> {
+    const ZEROS: [u8; 0x1000] = [0; 0x1000];
+    while size > 0 {
+        let n = size.min(ZEROS.len() as u64);
+        emu.mem_write(address, &ZEROS[..n as usize])?;
+        address += n;
+        size -= n;
+    }
+    Ok(())
+}
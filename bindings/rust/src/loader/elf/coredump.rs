@@ -0,0 +1,457 @@
+//! Resuming from Linux ELF core dumps.
+//!
+//! `load` maps every `PT_LOAD` segment of a core file with its permissions and restores
+//! the registers of the thread that crashed, the first `NT_PRSTATUS` note, so that
+//! emulation continues from the state the process died in. Segments the kernel did not
+//! dump, e.g. unmodified file mappings, are mapped zero-filled; `Core::files` tells
+//! which files they came from so they can be filled in.
+//!
+//! Supported are cores of x86-64, ARM64, ARM, MIPS and RISC-V processes. Floating point
+//! and vector registers come from `NT_FPREGSET`, or `NT_ARM_VFP` on ARM; the thread
+//! pointer of ARM and ARM64 from `NT_ARM_TLS`.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("core")?;
+//! let core = coredump::load(&mut emu, &data)?;
+//! let pc = emu.reg_read(RegisterARM64::PC)?;
+//! emu.emu_start(pc, 0, 0, 0)?;
+//! ```
+
+use super::{map_areas, zero_fill, Elf, ElfError, Segment, ET_CORE, PT_NOTE};
+use crate::unicorn_const::{uc_error, Arch, Mode};
+use crate::{RegisterARM, RegisterARM64, RegisterMIPS, RegisterRISCV, RegisterX86, Unicorn};
+use alloc::{string::String, vec::Vec};
+
+pub const NT_PRSTATUS: u32 = 1;
+pub const NT_FPREGSET: u32 = 2;
+pub const NT_PRPSINFO: u32 = 3;
+pub const NT_AUXV: u32 = 6;
+pub const NT_SIGINFO: u32 = 0x5349_4749;
+pub const NT_FILE: u32 = 0x4649_4c45;
+pub const NT_ARM_VFP: u32 = 0x400;
+pub const NT_ARM_TLS: u32 = 0x401;
+
+const EM_ARM: u16 = 40;
+const EM_MIPS: u16 = 8;
+const EM_X86_64: u16 = 62;
+const EM_AARCH64: u16 = 183;
+const EM_RISCV: u16 = 243;
+
+/// The FPU state field of the RISC-V `mstatus`, all ones for dirty.
+const MSTATUS_FS: u64 = 0x6000;
+
+/// A note of a `PT_NOTE` segment.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Note {
+    /// Owner of the note, `CORE` or `LINUX` for the notes of the kernel.
+    pub name: String,
+    /// `n_type`, e.g. `NT_PRSTATUS`.
+    pub kind: u32,
+    pub desc: Vec<u8>,
+}
+
+/// A file mapped into the process, from `NT_FILE`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MappedFile {
+    pub start: u64,
+    /// End of the mapping, exclusive.
+    pub end: u64,
+    /// Offset into the file in bytes.
+    pub offset: u64,
+    pub path: String,
+}
+
+/// A thread of the dumped process.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Thread {
+    pub pid: u32,
+    /// Signal the thread was stopped by, `pr_cursig`.
+    pub signal: u16,
+    /// General purpose registers of `NT_PRSTATUS`, in the order of the kernel's
+    /// `elf_gregset_t` of the architecture.
+    pub registers: Vec<u64>,
+    /// Floating point state of `NT_FPREGSET` or `NT_ARM_VFP`, as dumped.
+    pub fpregs: Option<Vec<u8>>,
+    /// Thread pointer of `NT_ARM_TLS`.
+    pub tls: Option<u64>,
+}
+
+/// A parsed core file.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Core {
+    pub arch: Arch,
+    pub mode: Mode,
+    pub big_endian: bool,
+    pub segments: Vec<Segment>,
+    /// Threads in the order of their notes, the one that crashed first.
+    pub threads: Vec<Thread>,
+    /// The auxiliary vector, without the terminating `AT_NULL`.
+    pub auxv: Vec<(u64, u64)>,
+    pub files: Vec<MappedFile>,
+}
+
+impl Core {
+    /// Parse the core file in `data`.
+    ///
+    /// Fails with `ElfError::BadHeader` if `data` is not a core file, and with
+    /// `ElfError::UnsupportedMachine` for architectures whose registers cannot be
+    /// restored.
+    pub fn parse(data: &[u8]) -> Result<Core, ElfError> {
+        let elf = Elf::parse(data)?;
+        if elf.kind != ET_CORE {
+            return Err(ElfError::BadHeader);
+        }
+        match (elf.machine, elf.is_64bit) {
+            (EM_X86_64 | EM_AARCH64, true) | (EM_ARM, false) | (EM_MIPS | EM_RISCV, _) => {}
+            (machine, _) => return Err(ElfError::UnsupportedMachine(machine)),
+        }
+        let (arch, mode) = elf.arch_mode()?;
+        let mut core = Core {
+            arch,
+            mode,
+            big_endian: elf.big_endian,
+            segments: elf
+                .loads()
+                .filter(|ph| ph.memsz > 0)
+                .map(|ph| Segment {
+                    address: ph.vaddr,
+                    offset: ph.offset,
+                    size: ph.memsz,
+                    file_size: ph.filesz.min(ph.memsz),
+                    perms: ph.permissions(),
+                })
+                .collect(),
+            threads: Vec::new(),
+            auxv: Vec::new(),
+            files: Vec::new(),
+        };
+
+        let w = if elf.is_64bit { 8 } else { 4 };
+        for note in notes(&elf)? {
+            let word = |at: usize| read(&note.desc, at, w, elf.big_endian);
+            match note.kind {
+                NT_PRSTATUS => {
+                    // pr_reg follows the siginfo, pending and held signals, four ids and
+                    // four timevals
+                    let regs = 16 + 2 * w + 16 + 8 * w;
+                    let count = match elf.machine {
+                        EM_X86_64 => 27,
+                        EM_AARCH64 => 34,
+                        EM_ARM => 18,
+                        EM_MIPS => 45,
+                        _ => 32,
+                    };
+                    let registers = (0..count)
+                        .map(|i| word(regs + i * w))
+                        .collect::<Option<Vec<u64>>>()
+                        .ok_or(ElfError::Truncated)?;
+                    core.threads.push(Thread {
+                        pid: read(&note.desc, 16 + 2 * w, 4, elf.big_endian)
+                            .ok_or(ElfError::Truncated)? as u32,
+                        signal: read(&note.desc, 12, 2, elf.big_endian)
+                            .ok_or(ElfError::Truncated)? as u16,
+                        registers,
+                        fpregs: None,
+                        tls: None,
+                    });
+                }
+                NT_FPREGSET | NT_ARM_VFP => {
+                    if let Some(thread) = core.threads.last_mut() {
+                        thread.fpregs = Some(note.desc);
+                    }
+                }
+                NT_ARM_TLS => {
+                    if let Some(thread) = core.threads.last_mut() {
+                        thread.tls = word(0);
+                    }
+                }
+                NT_AUXV => {
+                    core.auxv = (0..note.desc.len() / (2 * w))
+                        .map_while(|i| Some((word(2 * w * i)?, word(2 * w * i + w)?)))
+                        .take_while(|&(kind, _)| kind != 0)
+                        .collect();
+                }
+                NT_FILE => core.files = files(&note.desc, w, elf.big_endian)?,
+                _ => {}
+            }
+        }
+        Ok(core)
+    }
+
+    /// Return the value of auxiliary vector entry `kind`, e.g. `AT_ENTRY`.
+    #[must_use]
+    pub fn auxv_value(&self, kind: u64) -> Option<u64> {
+        self.auxv.iter().find(|&&(k, _)| k == kind).map(|&(_, v)| v)
+    }
+
+    /// Return the file mapped at `address`.
+    #[must_use]
+    pub fn file(&self, address: u64) -> Option<&MappedFile> {
+        self.files
+            .iter()
+            .find(|f| (f.start..f.end).contains(&address))
+    }
+
+    /// Write the registers of thread `index` into `emu`.
+    ///
+    /// Only what the engine exposes is restored: segment selectors of x86-64 and the
+    /// FPSR and FPCR of ARM64 are skipped. Fails with `uc_error::ARG` if the thread
+    /// has fewer registers than the gregset of the architecture.
+    pub fn restore<D>(&self, emu: &mut Unicorn<D>, index: usize) -> Result<(), uc_error> {
+        let thread = self.threads.get(index).ok_or(uc_error::ARG)?;
+        let regs = &thread.registers;
+        let count = match self.arch {
+            Arch::X86 => 23,
+            Arch::ARM64 => 34,
+            Arch::ARM => 17,
+            Arch::MIPS if self.mode.contains(Mode::MODE_64) => 35,
+            Arch::MIPS => 41,
+            _ => 32,
+        };
+        if regs.len() < count {
+            return Err(uc_error::ARG);
+        }
+        let fpregs = thread.fpregs.as_deref();
+        let u64_at = |data: &[u8], at: usize| read(data, at, 8, self.big_endian);
+        match self.arch {
+            Arch::X86 => {
+                // user_regs_struct: r15, r14, r13, r12, rbp, rbx, r11, r10, r9, r8, rax,
+                // rcx, rdx, rsi, rdi, orig_rax, rip, cs, eflags, rsp, ss, fs_base, gs_base
+                let order = [
+                    RegisterX86::R15,
+                    RegisterX86::R14,
+                    RegisterX86::R13,
+                    RegisterX86::R12,
+                    RegisterX86::RBP,
+                    RegisterX86::RBX,
+                    RegisterX86::R11,
+                    RegisterX86::R10,
+                    RegisterX86::R9,
+                    RegisterX86::R8,
+                    RegisterX86::RAX,
+                    RegisterX86::RCX,
+                    RegisterX86::RDX,
+                    RegisterX86::RSI,
+                    RegisterX86::RDI,
+                ];
+                for (reg, &value) in order.into_iter().zip(regs) {
+                    emu.reg_write(reg, value)?;
+                }
+                emu.reg_write(RegisterX86::RIP, regs[16])?;
+                emu.reg_write(RegisterX86::RFLAGS, regs[18])?;
+                emu.reg_write(RegisterX86::RSP, regs[19])?;
+                emu.reg_write(RegisterX86::FS_BASE, regs[21])?;
+                emu.reg_write(RegisterX86::GS_BASE, regs[22])?;
+                // the fxsave area
+                if let Some(fp) = fpregs.filter(|fp| fp.len() >= 416) {
+                    emu.reg_write(
+                        RegisterX86::FPCW,
+                        u64::from(u16::from_le_bytes([fp[0], fp[1]])),
+                    )?;
+                    emu.reg_write(
+                        RegisterX86::FPSW,
+                        u64::from(u16::from_le_bytes([fp[2], fp[3]])),
+                    )?;
+                    emu.reg_write(
+                        RegisterX86::MXCSR,
+                        u64::from(u32::from_le_bytes(fp[24..28].try_into().unwrap())),
+                    )?;
+                    for i in 0..8 {
+                        let at = 32 + 16 * i;
+                        emu.reg_write_long(RegisterX86::ST0 as i32 + i as i32, &fp[at..at + 10])?;
+                    }
+                    for i in 0..16 {
+                        let at = 160 + 16 * i;
+                        emu.reg_write_long(RegisterX86::XMM0 as i32 + i as i32, &fp[at..at + 16])?;
+                    }
+                }
+            }
+            Arch::ARM64 => {
+                // user_pt_regs: x0 to x30, sp, pc, pstate
+                for (i, &value) in regs[..29].iter().enumerate() {
+                    emu.reg_write(RegisterARM64::X0 as i32 + i as i32, value)?;
+                }
+                emu.reg_write(RegisterARM64::X29, regs[29])?;
+                emu.reg_write(RegisterARM64::X30, regs[30])?;
+                emu.reg_write(RegisterARM64::SP, regs[31])?;
+                emu.reg_write(RegisterARM64::PC, regs[32])?;
+                emu.reg_write(RegisterARM64::NZCV, regs[33] & 0xf000_0000)?;
+                // user_fpsimd_state: v0 to v31, fpsr, fpcr
+                if let Some(fp) = fpregs.filter(|fp| fp.len() >= 512) {
+                    for i in 0..32 {
+                        let at = 16 * i;
+                        emu.reg_write_long(RegisterARM64::Q0 as i32 + i as i32, &fp[at..at + 16])?;
+                    }
+                }
+                if let Some(tls) = thread.tls {
+                    emu.reg_write(RegisterARM64::TPIDR_EL0, tls)?;
+                }
+            }
+            Arch::ARM => {
+                // r0 to r15, cpsr, orig_r0; the mode of the cpsr selects the bank the
+                // stack pointer and link register are written to
+                let cpsr = regs[16];
+                emu.reg_write(RegisterARM::CPSR, cpsr)?;
+                for (i, &value) in regs[..13].iter().enumerate() {
+                    emu.reg_write(RegisterARM::R0 as i32 + i as i32, value)?;
+                }
+                emu.reg_write(RegisterARM::SP, regs[13])?;
+                emu.reg_write(RegisterARM::LR, regs[14])?;
+                // writing the PC sets the Thumb bit of the cpsr from bit 0
+                emu.reg_write(RegisterARM::PC, regs[15] | ((cpsr >> 5) & 1))?;
+                // user_vfp: d0 to d31, fpscr
+                if let Some(fp) = fpregs.filter(|fp| fp.len() >= 260) {
+                    for i in 0..32 {
+                        let value = u64_at(fp, 8 * i).ok_or(uc_error::ARG)?;
+                        emu.reg_write(RegisterARM::D0 as i32 + i as i32, value)?;
+                    }
+                    let fpscr = read(fp, 256, 4, self.big_endian).ok_or(uc_error::ARG)?;
+                    emu.reg_write(RegisterARM::FPSCR, fpscr)?;
+                }
+                if let Some(tls) = thread.tls {
+                    emu.reg_write(RegisterARM::C13_C0_3, tls)?;
+                }
+            }
+            Arch::MIPS => {
+                // o32 pads the gregset with six registers
+                let r0 = if self.mode.contains(Mode::MODE_64) {
+                    0
+                } else {
+                    6
+                };
+                for (i, &value) in regs[r0..r0 + 32].iter().enumerate() {
+                    emu.reg_write(RegisterMIPS::R0 as i32 + i as i32, value)?;
+                }
+                emu.reg_write(RegisterMIPS::LO, regs[r0 + 32])?;
+                emu.reg_write(RegisterMIPS::HI, regs[r0 + 33])?;
+                emu.reg_write(RegisterMIPS::PC, regs[r0 + 34])?;
+                // f0 to f31 as doubles, then fcr31
+                if let Some(fp) = fpregs.filter(|fp| fp.len() >= 260) {
+                    for i in 0..32 {
+                        let value = u64_at(fp, 8 * i).ok_or(uc_error::ARG)?;
+                        emu.reg_write(RegisterMIPS::F0 as i32 + i as i32, value)?;
+                    }
+                    let fcr31 = read(fp, 256, 4, self.big_endian).ok_or(uc_error::ARG)?;
+                    emu.reg_write(RegisterMIPS::FCR31, fcr31)?;
+                }
+            }
+            Arch::RISCV => {
+                // pc, then x1 to x31
+                emu.reg_write(RegisterRISCV::PC, regs[0])?;
+                for (i, &value) in regs.iter().enumerate().skip(1) {
+                    emu.reg_write(RegisterRISCV::X0 as i32 + i as i32, value)?;
+                }
+                // f0 to f31, fcsr; the FPU is off until mstatus.FS says otherwise, and
+                // fcsr is only writable with it on
+                if let Some(fp) = fpregs.filter(|fp| fp.len() >= 260) {
+                    let mstatus = emu.reg_read(RegisterRISCV::MSTATUS)?;
+                    emu.reg_write(RegisterRISCV::MSTATUS, mstatus | MSTATUS_FS)?;
+                    for i in 0..32 {
+                        let value = u64_at(fp, 8 * i).ok_or(uc_error::ARG)?;
+                        emu.reg_write(RegisterRISCV::F0 as i32 + i as i32, value)?;
+                    }
+                    let fcsr = read(fp, 256, 4, self.big_endian).ok_or(uc_error::ARG)?;
+                    emu.reg_write(RegisterRISCV::FCSR, fcsr)?;
+                }
+            }
+            _ => return Err(uc_error::ARCH),
+        }
+        Ok(())
+    }
+}
+
+/// Map the memory of the core file in `data` into `emu` and restore the registers of
+/// the thread that crashed.
+pub fn load<D>(emu: &mut Unicorn<D>, data: &[u8]) -> Result<Core, ElfError> {
+    let core = Core::parse(data)?;
+    super::check_arch(emu, core.arch, core.mode)?;
+    let elf = Elf::parse(data)?;
+
+    let areas: Vec<_> = core
+        .segments
+        .iter()
+        .map(|s| (s.address, s.size, s.perms))
+        .collect();
+    map_areas(emu, &areas)?;
+    for (ph, segment) in elf.loads().filter(|ph| ph.memsz > 0).zip(&core.segments) {
+        let contents = elf.segment_data(ph)?;
+        emu.mem_write(segment.address, &contents[..segment.file_size as usize])?;
+        zero_fill(
+            emu,
+            segment.address + segment.file_size,
+            segment.size - segment.file_size,
+        )?;
+    }
+
+    if !core.threads.is_empty() {
+        core.restore(emu, 0)?;
+    }
+    Ok(core)
+}
+
+/// Return the notes of the `PT_NOTE` segments of `elf`.
+pub fn notes(elf: &Elf) -> Result<Vec<Note>, ElfError> {
+    let mut notes = Vec::new();
+    for ph in elf.program_headers.iter().filter(|ph| ph.kind == PT_NOTE) {
+        let data = elf.segment_data(ph)?;
+        let mut at = 0;
+        while at + 12 <= data.len() {
+            let field = |i: usize| read(data, at + 4 * i, 4, elf.big_endian).unwrap() as usize;
+            let (namesz, descsz, kind) = (field(0), field(1), field(2) as u32);
+            let name = at + 12;
+            let desc = name + namesz.next_multiple_of(4);
+            let end = desc.checked_add(descsz).ok_or(ElfError::Truncated)?;
+            if end > data.len() {
+                return Err(ElfError::Truncated);
+            }
+            let name = &data[name..name + namesz];
+            notes.push(Note {
+                name: String::from_utf8_lossy(name.split(|&b| b == 0).next().unwrap_or(name))
+                    .into_owned(),
+                kind,
+                desc: data[desc..end].to_vec(),
+            });
+            at = desc + descsz.next_multiple_of(4);
+        }
+    }
+    Ok(notes)
+}
+
+/// Parse `NT_FILE`: the count and page size, `(start, end, page offset)` per file and
+/// then the paths.
+fn files(desc: &[u8], w: usize, big_endian: bool) -> Result<Vec<MappedFile>, ElfError> {
+    let word = |at: usize| read(desc, at, w, big_endian).ok_or(ElfError::Truncated);
+    let count = word(0)? as usize;
+    let page_size = word(w)?;
+    let paths = count
+        .checked_mul(3 * w)
+        .and_then(|n| n.checked_add(2 * w))
+        .ok_or(ElfError::Truncated)?;
+    let mut names = desc
+        .get(paths..)
+        .ok_or(ElfError::Truncated)?
+        .split(|&b| b == 0);
+    (0..count)
+        .map(|i| {
+            let at = 2 * w + 3 * w * i;
+            let path = names.next().ok_or(ElfError::Truncated)?;
+            Ok(MappedFile {
+                start: word(at)?,
+                end: word(at + w)?,
+                offset: word(at + 2 * w)?.wrapping_mul(page_size),
+                path: String::from_utf8_lossy(path).into_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Read an unsigned integer of `size` bytes at `at`.
+fn read(data: &[u8], at: usize, size: usize, big_endian: bool) -> Option<u64> {
+    let bytes = data.get(at..at.checked_add(size)?)?;
+    let fold = |v: u64, &b: &u8| v << 8 | u64::from(b);
+    Some(if big_endian {
+        bytes.iter().fold(0, fold)
+    } else {
+        bytes.iter().rev().fold(0, fold)
+    })
+}
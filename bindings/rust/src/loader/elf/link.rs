@@ -0,0 +1,902 @@
+//! Dynamic linking of ELF executables.
+//!
+//! `Linker::link` does the job of the dynamic loader: it maps the executable and,
+//! breadth first, every library named by a `DT_NEEDED` entry, then applies the
+//! relocations of all of them and points the PC at the entry of the executable.
+//! Libraries are looked up through a `Resolver`, e.g. a `Sysroot` directory.
+//!
+//! Symbols are looked up in load order, the executable first. Imports can be bound to
+//! Rust functions with `Linker::bind`, which take precedence over the libraries. A
+//! bound import is pointed at a stub; calling the stub runs the function and returns
+//! its result to the caller. Imports that are neither bound nor found in a library
+//! fail the link, unless they are weak. A stub that fails to return to its caller
+//! stops the emulation, `LinkedImage::error` tells why.
+//!
+//! Supported are the relocations used by dynamic objects of x86, x86-64, ARM, ARM64,
+//! MIPS (including the global offset table of the MIPS ABI), RISC-V, PPC and PPC64
+//! ELFv2. Relocations for TLS and IFUNCs are not applied, they are reported in
+//! `LinkedImage::unsupported` instead.
+//!
+//! ```rust,ignore
+//! let mut linker = Linker::new(Sysroot::new("/usr/aarch64-linux-gnu"));
+//! linker.bind("getenv", |_uc| 0);
+//! let image = linker.link(&mut emu, &std::fs::read("target")?)?;
+//! ```
+
+use super::{
+    map, Elf, ElfError, LoadedElf, SectionHeader, Symbol, SymbolBinding, SymbolKind, PT_DYNAMIC,
+    PT_INTERP,
+};
+use crate::memory::VirtualMemory;
+use crate::unicorn_const::{uc_error, Permission};
+use crate::Unicorn;
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::cell::Cell;
+
+/// Default address of position independent executables.
+pub const EXE_BASE: u64 = 0x0040_0000;
+
+/// Default address of the first library, the others follow.
+pub const LIBRARY_BASE: u64 = 0x4000_0000;
+
+/// Default address of the stubs of bound imports.
+pub const STUB_BASE: u64 = 0x3fff_0000;
+
+/// Bytes reserved per stub.
+pub const STUB_SIZE: u64 = 0x10;
+
+const DT_NULL: u64 = 0;
+const DT_NEEDED: u64 = 1;
+const DT_PLTRELSZ: u64 = 2;
+const DT_PLTGOT: u64 = 3;
+const DT_HASH: u64 = 4;
+const DT_STRTAB: u64 = 5;
+const DT_SYMTAB: u64 = 6;
+const DT_RELA: u64 = 7;
+const DT_RELASZ: u64 = 8;
+const DT_STRSZ: u64 = 10;
+const DT_INIT: u64 = 12;
+const DT_SONAME: u64 = 14;
+const DT_REL: u64 = 17;
+const DT_RELSZ: u64 = 18;
+const DT_PLTREL: u64 = 20;
+const DT_JMPREL: u64 = 23;
+const DT_INIT_ARRAY: u64 = 25;
+const DT_INIT_ARRAYSZ: u64 = 27;
+const DT_GNU_HASH: u64 = 0x6fff_fef5;
+const DT_MIPS_LOCAL_GOTNO: u64 = 0x7000_000a;
+const DT_MIPS_SYMTABNO: u64 = 0x7000_0011;
+const DT_MIPS_GOTSYM: u64 = 0x7000_0013;
+
+const EM_386: u16 = 3;
+const EM_MIPS: u16 = 8;
+const EM_PPC: u16 = 20;
+const EM_PPC64: u16 = 21;
+const EM_ARM: u16 = 40;
+const EM_X86_64: u16 = 62;
+const EM_AARCH64: u16 = 183;
+const EM_RISCV: u16 = 243;
+
+/// Callback standing in for an import, returning the result of the call.
+pub type ImportFn<'a, D> = Box<dyn FnMut(&mut Unicorn<D>) -> u64 + 'a>;
+
+/// Finds the libraries an executable needs.
+pub trait Resolver {
+    /// Return the contents of the library `name`, as named by `DT_NEEDED`, for the
+    /// image `requester`.
+    fn open(&mut self, name: &str, requester: &Elf) -> Option<Vec<u8>>;
+}
+
+impl<F: FnMut(&str) -> Option<Vec<u8>>> Resolver for F {
+    fn open(&mut self, name: &str, _requester: &Elf) -> Option<Vec<u8>> {
+        self(name)
+    }
+}
+
+/// Looks up libraries in the usual directories below a root directory.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct Sysroot {
+    root: std::path::PathBuf,
+    dirs: Vec<std::path::PathBuf>,
+}
+
+#[cfg(feature = "std")]
+impl Sysroot {
+    /// Look up libraries in `lib`, `usr/lib`, their `64` variants and the multiarch
+    /// directories below them, e.g. `usr/lib/aarch64-linux-gnu`, of `root`.
+    pub fn new<P: Into<std::path::PathBuf>>(root: P) -> Sysroot {
+        let root = root.into();
+        let mut dirs = Vec::new();
+        for dir in ["lib", "usr/lib", "lib64", "usr/lib64"] {
+            let dir = std::path::PathBuf::from(dir);
+            if let Ok(entries) = std::fs::read_dir(root.join(&dir)) {
+                let mut multiarch: Vec<_> = entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.file_name())
+                    .filter(|name| name.to_string_lossy().contains("-linux-"))
+                    .map(|name| dir.join(name))
+                    .collect();
+                multiarch.sort();
+                dirs.push(dir);
+                dirs.extend(multiarch);
+            }
+        }
+        Sysroot { root, dirs }
+    }
+
+    /// Look up libraries in `dir` below the root first, like `LD_LIBRARY_PATH`.
+    pub fn add_dir<P: AsRef<std::path::Path>>(&mut self, dir: P) {
+        let dir = dir.as_ref();
+        let dir = dir.strip_prefix("/").unwrap_or(dir);
+        self.dirs.insert(0, dir.to_path_buf());
+    }
+}
+
+#[cfg(feature = "std")]
+impl Resolver for Sysroot {
+    fn open(&mut self, name: &str, requester: &Elf) -> Option<Vec<u8>> {
+        // e.g. lib and lib64 both hold a libc.so.6, only one of them fits
+        self.dirs.iter().find_map(|dir| {
+            let data = std::fs::read(self.root.join(dir).join(name)).ok()?;
+            let fits = Elf::parse(&data).is_ok_and(|elf| {
+                elf.machine == requester.machine
+                    && elf.is_64bit == requester.is_64bit
+                    && elf.big_endian == requester.big_endian
+            });
+            Some(data).filter(|_| fits)
+        })
+    }
+}
+
+/// Why linking failed.
+#[derive(PartialEq, Debug, Clone)]
+pub enum LinkError {
+    /// An object is malformed or could not be mapped. The object is named as in
+    /// `DT_NEEDED`, the executable has no name.
+    Elf { object: String, error: ElfError },
+    /// The resolver could not find a library.
+    MissingLibrary(String),
+    /// Imports neither bound nor defined by any library.
+    Unresolved(Vec<String>),
+    /// Writing to the guest failed.
+    Emu(uc_error),
+}
+
+impl From<uc_error> for LinkError {
+    fn from(err: uc_error) -> Self {
+        LinkError::Emu(err)
+    }
+}
+
+/// A relocation `Linker::link` did not apply.
+#[derive(PartialEq, Debug, Clone)]
+pub struct UnsupportedRelocation {
+    pub object: String,
+    /// Guest address of the relocated word.
+    pub address: u64,
+    /// `r_type`, e.g. `R_X86_64_TPOFF64`.
+    pub kind: u32,
+    /// Symbol the relocation refers to, empty for none.
+    pub symbol: String,
+}
+
+/// Description of a linked executable.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LinkedImage {
+    pub executable: LoadedElf,
+    /// Loaded libraries, in load order, with their names as in `DT_NEEDED`.
+    pub libraries: Vec<(String, LoadedElf)>,
+    /// Interpreter requested by the executable, it is not loaded.
+    pub interpreter: Option<String>,
+    /// Bound imports that are used, with the addresses of their stubs.
+    pub imports: Vec<(String, u64)>,
+    /// `DT_INIT` and `DT_INIT_ARRAY` functions of the libraries in the order the
+    /// dynamic loader runs them, dependencies first. Those of the executable are left
+    /// to its startup code.
+    pub init: Vec<u64>,
+    pub unsupported: Vec<UnsupportedRelocation>,
+    error: Rc<Cell<Option<uc_error>>>,
+}
+
+impl LinkedImage {
+    /// Return the address of the defined symbol `name`, looked up in load order.
+    #[must_use]
+    pub fn symbol(&self, name: &str) -> Option<u64> {
+        core::iter::once(&self.executable)
+            .chain(self.libraries.iter().map(|(_, lib)| lib))
+            .find_map(|image| image.symbol(name))
+            .map(|s| s.address)
+    }
+
+    /// Name the pages of the executable after `path` and those of the libraries after
+    /// their names in `memory`, see `LoadedElf::track`.
+    pub fn track<D>(
+        &self,
+        emu: &Unicorn<D>,
+        memory: &mut VirtualMemory,
+        path: &str,
+    ) -> Result<(), uc_error> {
+        self.executable.track(emu, memory, path)?;
+        for (name, library) in &self.libraries {
+            library.track(emu, memory, name)?;
+        }
+        Ok(())
+    }
+
+    /// Return why a stub stopped the emulation, if one failed to return the result of
+    /// its binding to the caller.
+    #[must_use]
+    pub fn error(&self) -> Option<uc_error> {
+        self.error.get()
+    }
+}
+
+/// Loads and links dynamically linked executables.
+pub struct Linker<'a, D> {
+    /// Address of position independent executables.
+    pub exe_base: u64,
+    /// Address of the first library, the others follow with a page in between.
+    pub library_base: u64,
+    /// Address of the stubs of bound imports.
+    pub stub_base: u64,
+    resolver: Box<dyn Resolver + 'a>,
+    imports: BTreeMap<String, ImportFn<'a, D>>,
+}
+
+/// What a relocation computes, with `S` the symbol, `A` the addend, `B` the load bias
+/// and `P` the relocated address.
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Action {
+    None,
+    /// `S + A`
+    Abs(usize),
+    /// `S + A - P`
+    Pc(usize),
+    /// `S`, plus `A` if it is explicit; implicit addends of GOT and PLT slots hold the
+    /// lazy binding address instead.
+    Slot(usize),
+    /// `B + A`
+    Relative(usize),
+    /// `S + A`, or `B + A` without a symbol.
+    MipsRel32(usize),
+    /// Copy the data of the symbol from the library defining it.
+    Copy,
+    Unsupported,
+}
+
+fn action(machine: u16, is_64bit: bool, kind: u32) -> Action {
+    let word = if is_64bit { 8 } else { 4 };
+    match (machine, kind) {
+        (EM_X86_64, 0) => Action::None,
+        (EM_X86_64, 1) => Action::Abs(8),
+        (EM_X86_64, 2) => Action::Pc(4),
+        (EM_X86_64, 5) => Action::Copy,
+        (EM_X86_64, 6 | 7) => Action::Slot(8),
+        (EM_X86_64, 8) => Action::Relative(8),
+        (EM_X86_64, 10 | 11) => Action::Abs(4),
+        (EM_X86_64, 24) => Action::Pc(8),
+        (EM_386, 0) => Action::None,
+        (EM_386, 1) => Action::Abs(4),
+        (EM_386, 2) => Action::Pc(4),
+        (EM_386, 5) => Action::Copy,
+        (EM_386, 6 | 7) => Action::Slot(4),
+        (EM_386, 8) => Action::Relative(4),
+        (EM_ARM, 0) => Action::None,
+        (EM_ARM, 2) => Action::Abs(4),
+        (EM_ARM, 3) => Action::Pc(4),
+        (EM_ARM, 20) => Action::Copy,
+        (EM_ARM, 21 | 22) => Action::Slot(4),
+        (EM_ARM, 23) => Action::Relative(4),
+        (EM_AARCH64, 0 | 256) => Action::None,
+        (EM_AARCH64, 257) => Action::Abs(8),
+        (EM_AARCH64, 258) => Action::Abs(4),
+        (EM_AARCH64, 260) => Action::Pc(8),
+        (EM_AARCH64, 261) => Action::Pc(4),
+        (EM_AARCH64, 1024) => Action::Copy,
+        (EM_AARCH64, 1025 | 1026) => Action::Slot(8),
+        (EM_AARCH64, 1027) => Action::Relative(8),
+        (EM_MIPS, 0) => Action::None,
+        (EM_MIPS, 2) => Action::Abs(4),
+        (EM_MIPS, 3) => Action::MipsRel32(word),
+        (EM_MIPS, 18) => Action::Abs(8),
+        (EM_MIPS, 126) => Action::Copy,
+        (EM_MIPS, 127) => Action::Slot(word),
+        (EM_RISCV, 0) => Action::None,
+        (EM_RISCV, 1) => Action::Abs(4),
+        (EM_RISCV, 2) => Action::Abs(8),
+        (EM_RISCV, 3) => Action::Relative(word),
+        (EM_RISCV, 4) => Action::Copy,
+        (EM_RISCV, 5) => Action::Slot(word),
+        (EM_PPC | EM_PPC64, 0) => Action::None,
+        (EM_PPC | EM_PPC64, 1) => Action::Abs(4),
+        (EM_PPC | EM_PPC64, 19) => Action::Copy,
+        (EM_PPC | EM_PPC64, 20 | 21) => Action::Slot(word),
+        (EM_PPC | EM_PPC64, 22) => Action::Relative(word),
+        (EM_PPC | EM_PPC64, 26) => Action::Pc(4),
+        (EM_PPC64, 38) => Action::Abs(8),
+        _ => Action::Unsupported,
+    }
+}
+
+/// A relocation of `.rel.dyn`, `.rela.dyn` or the PLT.
+#[derive(Debug, Clone, Copy)]
+struct Relocation {
+    offset: u64,
+    kind: u32,
+    sym: u32,
+    /// Explicit addend of `Elf_Rela` entries.
+    addend: Option<u64>,
+}
+
+/// What the dynamic section of an object says.
+#[derive(Debug, Default)]
+pub(super) struct Dynamic {
+    needed: Vec<String>,
+    soname: Option<String>,
+    /// `.dynsym` including the reserved entry 0, addresses not moved by the load bias.
+    pub(super) symbols: Vec<Symbol>,
+    relocations: Vec<Relocation>,
+    init: Option<u64>,
+    init_array: Option<(u64, u64)>,
+    /// `DT_PLTGOT`, `DT_MIPS_LOCAL_GOTNO` and `DT_MIPS_GOTSYM` of MIPS objects.
+    mips_got: Option<(u64, u64, u64)>,
+}
+
+impl<'d> Elf<'d> {
+    /// Return the file offset of the guest address `vaddr`, not moved by the load bias.
+    fn file_offset(&self, vaddr: u64) -> Option<u64> {
+        self.loads()
+            .find(|ph| ph.vaddr <= vaddr && vaddr - ph.vaddr < ph.filesz)
+            .and_then(|ph| (vaddr - ph.vaddr).checked_add(ph.offset))
+    }
+
+    /// Parse the dynamic section, moving the addresses of `.dynsym` by `bias`.
+    pub(super) fn dynamic(&self, bias: u64) -> Result<Dynamic, ElfError> {
+        let mut dynamic = Dynamic::default();
+        let ph = match self.program_headers.iter().find(|ph| ph.kind == PT_DYNAMIC) {
+            Some(ph) => *ph,
+            None => return Ok(dynamic),
+        };
+        let w = if self.is_64bit { 8 } else { 4 };
+        let mut entries: Vec<(u64, u64)> = Vec::new();
+        let end = ph
+            .offset
+            .checked_add(ph.filesz)
+            .ok_or(ElfError::Truncated)?;
+        for at in (ph.offset..end).step_by(2 * w) {
+            let at = at as usize;
+            let tag = self.word(at)?;
+            if tag == DT_NULL {
+                break;
+            }
+            entries.push((
+                tag,
+                self.word(at.checked_add(w).ok_or(ElfError::Truncated)?)?,
+            ));
+        }
+        let get = |tag: u64| entries.iter().find(|e| e.0 == tag).map(|e| e.1);
+        let offset = |vaddr: u64| self.file_offset(vaddr).ok_or(ElfError::Truncated);
+
+        let strtab = SectionHeader {
+            name: String::new(),
+            kind: super::SHT_STRTAB,
+            flags: 0,
+            addr: 0,
+            offset: offset(get(DT_STRTAB).unwrap_or(0))?,
+            size: get(DT_STRSZ).unwrap_or(0),
+            link: 0,
+            info: 0,
+            entsize: 0,
+        };
+        let string =
+            |offset: u64| String::from(self.data_str(&strtab, offset as u32).unwrap_or(""));
+        dynamic.needed = entries
+            .iter()
+            .filter(|e| e.0 == DT_NEEDED)
+            .map(|e| string(e.1))
+            .collect();
+        dynamic.soname = get(DT_SONAME).map(string);
+
+        if let Some(symtab) = get(DT_SYMTAB) {
+            let symtab = offset(symtab)?;
+            let entsize = if self.is_64bit { 24 } else { 16 };
+            for i in 0..self.dynamic_symbol_count(&get)? {
+                let at = super::table_entry(symtab, i, entsize)?;
+                dynamic.symbols.push(self.symbol(at, &strtab, bias)?);
+            }
+        }
+
+        let rela = get(DT_PLTREL) == Some(DT_RELA);
+        let tables = [
+            (get(DT_REL), get(DT_RELSZ), false),
+            (get(DT_RELA), get(DT_RELASZ), true),
+            (get(DT_JMPREL), get(DT_PLTRELSZ), rela),
+        ];
+        for (address, size, rela) in tables {
+            if let (Some(address), Some(size)) = (address, size) {
+                self.relocations(offset(address)?, size, rela, &mut dynamic.relocations)?;
+            }
+        }
+
+        dynamic.init = get(DT_INIT);
+        dynamic.init_array = get(DT_INIT_ARRAY).zip(get(DT_INIT_ARRAYSZ));
+        if let (Some(got), Some(local), Some(gotsym)) = (
+            get(DT_PLTGOT),
+            get(DT_MIPS_LOCAL_GOTNO),
+            get(DT_MIPS_GOTSYM),
+        ) {
+            dynamic.mips_got = Some((got, local, gotsym));
+        }
+        if let Some(symtabno) = get(DT_MIPS_SYMTABNO) {
+            dynamic.symbols.truncate(symtabno as usize);
+        }
+        Ok(dynamic)
+    }
+
+    /// Count the entries of `.dynsym`, which the dynamic section does not record.
+    fn dynamic_symbol_count(&self, get: &dyn Fn(u64) -> Option<u64>) -> Result<u64, ElfError> {
+        if let Some(sh) = self
+            .section_headers
+            .iter()
+            .find(|sh| sh.kind == super::SHT_DYNSYM)
+        {
+            return Ok(sh.size / if self.is_64bit { 24 } else { 16 });
+        }
+        if let Some(count) = get(DT_MIPS_SYMTABNO) {
+            return Ok(count);
+        }
+        if let Some(hash) = get(DT_HASH).and_then(|hash| self.file_offset(hash)) {
+            // nchain
+            return self.u32(hash as usize + 4).map(u64::from);
+        }
+        let hash = match get(DT_GNU_HASH).and_then(|hash| self.file_offset(hash)) {
+            Some(hash) => hash as usize,
+            None => return Ok(0),
+        };
+        // walk the chain of the highest bucket up to the entry marking its end
+        let (nbuckets, symoffset, bloom_size) =
+            (self.u32(hash)?, self.u32(hash + 4)?, self.u32(hash + 8)?);
+        let w = if self.is_64bit { 8 } else { 4 };
+        let buckets = hash + 16 + bloom_size as usize * w;
+        let mut last = 0;
+        for i in 0..nbuckets as usize {
+            last = last.max(self.u32(buckets + 4 * i)?);
+        }
+        if last < symoffset {
+            return Ok(u64::from(symoffset));
+        }
+        let chains = buckets + 4 * nbuckets as usize;
+        while self.u32(chains + 4 * (last - symoffset) as usize)? & 1 == 0 {
+            last += 1;
+        }
+        Ok(u64::from(last) + 1)
+    }
+
+    fn relocations(
+        &self,
+        at: u64,
+        size: u64,
+        rela: bool,
+        out: &mut Vec<Relocation>,
+    ) -> Result<(), ElfError> {
+        let w = if self.is_64bit { 8 } else { 4 };
+        let entsize = if rela { 3 * w } else { 2 * w };
+        let mips64_le = self.machine == EM_MIPS && self.is_64bit && !self.big_endian;
+        let end = at.checked_add(size).ok_or(ElfError::Truncated)?;
+        for at in (at..end).step_by(entsize as usize) {
+            let at = at as usize;
+            let field = |n: u64| at.checked_add(n as usize).ok_or(ElfError::Truncated);
+            let info = self.word(field(w)?)?;
+            let (sym, kind) = if mips64_le {
+                // r_sym is a little endian word of its own, r_type the last byte
+                (info as u32, (info >> 56) as u32)
+            } else if self.is_64bit {
+                (
+                    (info >> 32) as u32,
+                    info as u32 & if self.machine == EM_MIPS { 0xff } else { !0 },
+                )
+            } else {
+                ((info >> 8) as u32, info as u32 & 0xff)
+            };
+            out.push(Relocation {
+                offset: self.word(at)?,
+                kind,
+                sym,
+                addend: if rela {
+                    Some(self.word(field(2 * w)?)?)
+                } else {
+                    None
+                },
+            });
+        }
+        Ok(())
+    }
+}
+
+/// An object taking part in the link.
+struct Object<'d> {
+    name: String,
+    elf: Elf<'d>,
+    image: LoadedElf,
+    dynamic: Dynamic,
+}
+
+impl<'a, D> Linker<'a, D> {
+    /// Create a linker finding libraries through `resolver`.
+    pub fn new<R: Resolver + 'a>(resolver: R) -> Linker<'a, D> {
+        Linker {
+            exe_base: EXE_BASE,
+            library_base: LIBRARY_BASE,
+            stub_base: STUB_BASE,
+            resolver: Box::new(resolver),
+            imports: BTreeMap::new(),
+        }
+    }
+
+    /// Bind the import `name` to `callback`, whether a library defines it or not.
+    ///
+    /// The callback runs in place of the function and returns its result, the
+    /// arguments are found with e.g. `Unicorn::function_arg0_val`.
+    pub fn bind<F>(&mut self, name: &str, callback: F)
+    where
+        F: FnMut(&mut Unicorn<D>) -> u64 + 'a,
+    {
+        self.imports.insert(String::from(name), Box::new(callback));
+    }
+
+    /// Map the executable in `data` and its libraries into `emu`, link them and point
+    /// the PC at the entry of the executable.
+    pub fn link(mut self, emu: &mut Unicorn<'a, D>, data: &[u8]) -> Result<LinkedImage, LinkError>
+    where
+        D: 'a,
+    {
+        let elf_err = |object: &str| {
+            let object = String::from(object);
+            move |error| LinkError::Elf { object, error }
+        };
+        let exe = Elf::parse(data).map_err(elf_err(""))?;
+
+        // gather the libraries breadth first, like the dynamic loader does
+        let mut libraries: Vec<(String, Vec<u8>)> = Vec::new();
+        let mut queue: Vec<String> = exe.dynamic(0).map_err(elf_err(""))?.needed;
+        let mut next = 0;
+        while next < queue.len() {
+            let name = queue[next].clone();
+            next += 1;
+            if libraries.iter().any(|(n, _)| *n == name) {
+                continue;
+            }
+            let lib = self
+                .resolver
+                .open(&name, &exe)
+                .ok_or_else(|| LinkError::MissingLibrary(name.clone()))?;
+            let needed = Elf::parse(&lib)
+                .and_then(|elf| elf.dynamic(0))
+                .map_err(elf_err(&name))?
+                .needed;
+            queue.extend(needed);
+            libraries.push((name, lib));
+        }
+
+        let mut objects = Vec::new();
+        let image = map(emu, data, self.exe_base).map_err(elf_err(""))?;
+        objects.push(Object {
+            name: String::new(),
+            dynamic: exe.dynamic(0).map_err(elf_err(""))?,
+            elf: exe,
+            image,
+        });
+        let mut base = self.library_base;
+        for (name, lib) in &libraries {
+            let elf = Elf::parse(lib).map_err(elf_err(name))?;
+            let image = map(emu, lib, base).map_err(elf_err(name))?;
+            if let Some((_, high)) = image.bounds() {
+                // leave a page between libraries, overflows show up as faults
+                base = (high | 0xfff) + 0x1001;
+            }
+            objects.push(Object {
+                name: name.clone(),
+                dynamic: elf.dynamic(0).map_err(elf_err(name))?,
+                elf,
+                image,
+            });
+        }
+
+        let mut state = LinkState {
+            imports: &self.imports,
+            stub_base: self.stub_base,
+            stubs: Vec::new(),
+            unresolved: Vec::new(),
+            unsupported: Vec::new(),
+        };
+        // libraries in reverse load order and the executable last, as ld.so does, so
+        // copy relocations copy data the defining library already relocated
+        for i in (0..objects.len()).rev() {
+            state.relocate(emu, &objects, i)?;
+            state.mips_got(emu, &objects, i)?;
+        }
+        if !state.unresolved.is_empty() {
+            return Err(LinkError::Unresolved(state.unresolved));
+        }
+        let stubs = core::mem::take(&mut state.stubs);
+        let unsupported = core::mem::take(&mut state.unsupported);
+        let error = self.install_stubs(emu, &stubs)?;
+
+        let mut init = Vec::new();
+        for object in objects.iter().skip(1).rev() {
+            let bias = object.image.bias;
+            if let Some(address) = object.dynamic.init {
+                init.push(address.wrapping_add(bias));
+            }
+            if let Some((array, size)) = object.dynamic.init_array {
+                let w = if object.elf.is_64bit { 8 } else { 4 };
+                for at in (0..size).step_by(w) {
+                    let entry =
+                        read_word(emu, array.wrapping_add(bias) + at, w, object.elf.big_endian)?;
+                    // 0 and -1 are placeholders
+                    if entry != 0 && entry != u64::MAX >> (64 - 8 * w) {
+                        init.push(entry);
+                    }
+                }
+            }
+        }
+
+        let interpreter = objects[0]
+            .elf
+            .program_headers
+            .iter()
+            .find(|ph| ph.kind == PT_INTERP)
+            .and_then(|ph| objects[0].elf.segment_data(ph).ok())
+            .map(|data| {
+                String::from_utf8_lossy(data.split(|&b| b == 0).next().unwrap_or(&[])).into_owned()
+            });
+        let mut objects = objects.into_iter();
+        let executable = objects.next().unwrap().image;
+        emu.set_pc(executable.entry)?;
+        Ok(LinkedImage {
+            executable,
+            libraries: objects.map(|o| (o.name, o.image)).collect(),
+            interpreter,
+            imports: stubs
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.clone(), self.stub_base + i as u64 * STUB_SIZE))
+                .collect(),
+            init,
+            unsupported,
+            error,
+        })
+    }
+
+    /// Map the stubs of the bound imports that are used and hook them. Return where
+    /// the hook records why it stopped the emulation.
+    fn install_stubs(
+        &mut self,
+        emu: &mut Unicorn<'a, D>,
+        stubs: &[String],
+    ) -> Result<Rc<Cell<Option<uc_error>>>, uc_error>
+    where
+        D: 'a,
+    {
+        let error = Rc::new(Cell::new(None));
+        if stubs.is_empty() {
+            return Ok(error);
+        }
+        let size = (stubs.len() as u64 * STUB_SIZE + 0xfff) & !0xfff;
+        emu.mem_map(
+            self.stub_base,
+            size as usize,
+            Permission::READ | Permission::EXEC,
+        )?;
+        let mut callbacks: Vec<ImportFn<'a, D>> = stubs
+            .iter()
+            .map(|name| self.imports.remove(name).unwrap())
+            .collect();
+        let base = self.stub_base;
+        let end = base + stubs.len() as u64 * STUB_SIZE - 1;
+        let hook_error = error.clone();
+        emu.add_code_hook(base, end, move |uc, address, _| {
+            let value = (callbacks[((address - base) / STUB_SIZE) as usize])(uc);
+            let returned = uc
+                .syscall_return_reg()
+                .and_then(|reg| uc.reg_write(reg, value))
+                .and_then(|()| uc.simulate_return());
+            if let Err(err) = returned {
+                hook_error.set(Some(err));
+                let _ = uc.emu_stop();
+            }
+        })?;
+        Ok(error)
+    }
+}
+
+/// Progress of relocating the objects.
+struct LinkState<'l, 'a, D> {
+    imports: &'l BTreeMap<String, ImportFn<'a, D>>,
+    stub_base: u64,
+    /// Bound imports that got a stub, in stub order.
+    stubs: Vec<String>,
+    unresolved: Vec<String>,
+    unsupported: Vec<UnsupportedRelocation>,
+}
+
+impl<'l, 'a, D> LinkState<'l, 'a, D> {
+    /// Return the address symbol `sym` of object `i` resolves to. `None` if it does not
+    /// resolve, which is recorded unless the symbol is weak.
+    fn resolve(&mut self, objects: &[Object], i: usize, sym: u32, skip_exe: bool) -> Option<u64> {
+        let object = &objects[i];
+        let symbol = object.dynamic.symbols.get(sym as usize)?;
+        if symbol.binding == SymbolBinding::Local {
+            return Some(symbol.address.wrapping_add(object.image.bias));
+        }
+        if self.imports.contains_key(&symbol.name) {
+            let index = match self.stubs.iter().position(|name| *name == symbol.name) {
+                Some(index) => index,
+                None => {
+                    self.stubs.push(symbol.name.clone());
+                    self.stubs.len() - 1
+                }
+            };
+            return Some(self.stub_base + index as u64 * STUB_SIZE);
+        }
+        let skip = if skip_exe { 1 } else { 0 };
+        let found = objects.iter().skip(skip).find_map(|o| {
+            o.dynamic
+                .symbols
+                .iter()
+                .find(|s| s.defined && s.binding != SymbolBinding::Local && s.name == symbol.name)
+                .map(|s| {
+                    if s.kind == SymbolKind::Tls {
+                        s.address
+                    } else {
+                        s.address.wrapping_add(o.image.bias)
+                    }
+                })
+        });
+        if found.is_none() {
+            if symbol.binding == SymbolBinding::Weak {
+                return Some(0);
+            }
+            if !self.unresolved.contains(&symbol.name) {
+                self.unresolved.push(symbol.name.clone());
+            }
+        }
+        found
+    }
+
+    fn relocate(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        objects: &[Object],
+        i: usize,
+    ) -> Result<(), LinkError> {
+        let object = &objects[i];
+        let (elf, bias) = (&object.elf, object.image.bias);
+        for reloc in &object.dynamic.relocations {
+            let place = reloc.offset.wrapping_add(bias);
+            let symbol = object.dynamic.symbols.get(reloc.sym as usize);
+            let is_ifunc = symbol.is_some_and(|s| s.kind == SymbolKind::Other(10));
+            let mut action = action(elf.machine, elf.is_64bit, reloc.kind);
+            if is_ifunc {
+                action = Action::Unsupported;
+            }
+            let size = match action {
+                Action::None => continue,
+                Action::Abs(size)
+                | Action::Pc(size)
+                | Action::Slot(size)
+                | Action::Relative(size)
+                | Action::MipsRel32(size) => size,
+                Action::Copy => {
+                    if let (Some(symbol), Some(source)) =
+                        (symbol, self.resolve(objects, i, reloc.sym, true))
+                    {
+                        let data = emu.mem_read_as_vec(source, symbol.size as usize)?;
+                        emu.mem_write(place, &data)?;
+                    }
+                    continue;
+                }
+                Action::Unsupported => {
+                    self.unsupported.push(UnsupportedRelocation {
+                        object: object.name.clone(),
+                        address: place,
+                        kind: reloc.kind,
+                        symbol: symbol.map(|s| s.name.clone()).unwrap_or_default(),
+                    });
+                    continue;
+                }
+            };
+            let addend = match reloc.addend {
+                Some(addend) => addend,
+                None if matches!(action, Action::Slot(_)) => 0,
+                None => read_word(emu, place, size, elf.big_endian)?,
+            };
+            let s = if reloc.sym == 0 {
+                0
+            } else {
+                match self.resolve(objects, i, reloc.sym, false) {
+                    Some(s) => s,
+                    None => continue,
+                }
+            };
+            let value = match action {
+                Action::Abs(_) | Action::Slot(_) => s.wrapping_add(addend),
+                Action::Pc(_) => s.wrapping_add(addend).wrapping_sub(place),
+                Action::Relative(_) => bias.wrapping_add(addend),
+                Action::MipsRel32(_) if reloc.sym == 0 => bias.wrapping_add(addend),
+                Action::MipsRel32(_) => s.wrapping_add(addend),
+                _ => unreachable!(),
+            };
+            write_word(emu, place, value, size, elf.big_endian)?;
+        }
+        Ok(())
+    }
+
+    /// Relocate the global offset table of the MIPS ABI, which has no relocations of
+    /// its own: local entries move with the object, global entries hold the address of
+    /// the symbols from `DT_MIPS_GOTSYM` on.
+    fn mips_got(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        objects: &[Object],
+        i: usize,
+    ) -> Result<(), LinkError> {
+        let object = &objects[i];
+        let (got, local, gotsym) = match object.dynamic.mips_got {
+            Some(got) => got,
+            None => return Ok(()),
+        };
+        let (elf, bias) = (&object.elf, object.image.bias);
+        let w = if elf.is_64bit { 8 } else { 4 };
+        let got = got.wrapping_add(bias);
+        // entry 0 is the lazy resolver, entry 1 the module pointer if its MSB is set
+        let module = read_word(emu, got + w as u64, w, elf.big_endian)?;
+        let first = if module >> (8 * w - 1) & 1 == 1 { 2 } else { 1 };
+        if bias != 0 {
+            for n in first..local {
+                let at = got + n * w as u64;
+                let value = read_word(emu, at, w, elf.big_endian)?;
+                write_word(emu, at, value.wrapping_add(bias), w, elf.big_endian)?;
+            }
+        }
+        for sym in gotsym..object.dynamic.symbols.len() as u64 {
+            let at = got + (local + sym - gotsym) * w as u64;
+            if let Some(s) = self.resolve(objects, i, sym as u32, false) {
+                write_word(emu, at, s, w, elf.big_endian)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_word<D>(
+    emu: &Unicorn<D>,
+    address: u64,
+    size: usize,
+    big_endian: bool,
+) -> Result<u64, uc_error> {
+    let data = emu.mem_read_as_vec(address, size)?;
+    let mut bytes = [0u8; 8];
+    if big_endian {
+        bytes[8 - size..].copy_from_slice(&data);
+        Ok(u64::from_be_bytes(bytes))
+    } else {
+        bytes[..size].copy_from_slice(&data);
+        Ok(u64::from_le_bytes(bytes))
+    }
+}
+
+fn write_word<D>(
+    emu: &mut Unicorn<D>,
+    address: u64,
+    value: u64,
+    size: usize,
+    big_endian: bool,
+) -> Result<(), uc_error> {
+    if big_endian {
+        emu.mem_write(address, &value.to_be_bytes()[8 - size..])
+    } else {
+        emu.mem_write(address, &value.to_le_bytes()[..size])
+    }
+}
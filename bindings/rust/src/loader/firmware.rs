@@ -0,0 +1,513 @@
+//! Raw firmware images placed by a memory map.
+//!
+//! A `MemoryMap` describes the memory of a bare-metal target: RAM, ROM and flash
+//! regions with their contents, taken from files or given inline, MMIO regions of
+//! peripherals, the initial registers and where execution starts, either at `entry`
+//! or through the vector table of a Cortex-M. `MemoryMap::materialize` maps it all onto
+//! an instance in one call.
+//!
+//! Maps are built in Rust, or read from TOML or JSON with the `config` feature:
+//!
+//! ```toml
+//! vector_table = 0x0800_0000
+//!
+//! [[regions]]
+//! name = "flash"
+//! kind = "flash"
+//! address = 0x0800_0000
+//! size = 0x10_0000
+//! file = "firmware.bin"
+//!
+//! [[regions]]
+//! name = "sram"
+//! kind = "ram"
+//! address = 0x2000_0000
+//! size = 0x2_0000
+//!
+//! [[regions]]
+//! name = "usart1"
+//! kind = "mmio"
+//! address = 0x4001_3800
+//! size = 0x400
+//!
+//! [registers]
+//! r0 = 0
+//! ```
+//!
+//! ```rust,ignore
+//! let map = MemoryMap::new()
+//!     .region(Region::flash("flash", 0x0800_0000, 0x10_0000).file("firmware.bin", 0))
+//!     .region(Region::ram("sram", 0x2000_0000, 0x2_0000))
+//!     .region(Region::mmio("usart1", 0x4001_3800, 0x400))
+//!     .vector_table(0x0800_0000);
+//! map.materialize(&mut emu)?;
+//! ```
+
+use super::map_areas;
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission, Query};
+use crate::Unicorn;
+use crate::{RegisterARM, RegisterARM64, RegisterMIPS, RegisterPPC, RegisterRISCV, RegisterX86};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+/// What a region holds.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "config", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config", serde(rename_all = "lowercase"))]
+pub enum RegionKind {
+    /// Readable and writable memory.
+    Ram,
+    /// Read-only memory holding code or data.
+    Rom,
+    /// Like `Rom`, but erased to 0xff.
+    Flash,
+    /// Registers of a peripheral. Until it is replaced by a model, reads return 0 and
+    /// writes are dropped.
+    Mmio,
+}
+
+impl RegionKind {
+    /// Return the permissions regions of this kind get by default.
+    #[must_use]
+    pub fn default_permissions(self) -> Permission {
+        match self {
+            RegionKind::Ram => Permission::ALL,
+            RegionKind::Rom | RegionKind::Flash => Permission::READ | Permission::EXEC,
+            RegionKind::Mmio => Permission::READ | Permission::WRITE,
+        }
+    }
+}
+
+/// A region of the memory map.
+///
+/// Its contents are the `file_size` bytes of `file` from `file_offset` on, or `data`
+/// without a file, placed `offset` bytes into the region. The rest of the region holds
+/// the `fill` byte.
+#[derive(PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "config", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config", serde(deny_unknown_fields))]
+pub struct Region {
+    pub name: String,
+    pub kind: RegionKind,
+    pub address: u64,
+    pub size: u64,
+    /// Permissions, those of the kind if `None`. Written as a string like `"rx"` in
+    /// TOML and JSON.
+    #[cfg_attr(
+        feature = "config",
+        serde(default, deserialize_with = "config::permissions")
+    )]
+    pub perms: Option<Permission>,
+    /// Byte the region is filled with, 0xff for flash and 0 otherwise if `None`.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub fill: Option<u8>,
+    #[cfg_attr(feature = "config", serde(default))]
+    pub file: Option<String>,
+    #[cfg_attr(feature = "config", serde(default))]
+    pub file_offset: u64,
+    /// Bytes taken from the file, everything after `file_offset` if `None`.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub file_size: Option<u64>,
+    #[cfg_attr(feature = "config", serde(default))]
+    pub data: Vec<u8>,
+    /// Offset of the contents into the region.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub offset: u64,
+}
+
+impl Region {
+    /// Create an empty region of `kind`.
+    #[must_use]
+    pub fn new(name: &str, kind: RegionKind, address: u64, size: u64) -> Region {
+        Region {
+            name: String::from(name),
+            kind,
+            address,
+            size,
+            perms: None,
+            fill: None,
+            file: None,
+            file_offset: 0,
+            file_size: None,
+            data: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn ram(name: &str, address: u64, size: u64) -> Region {
+        Region::new(name, RegionKind::Ram, address, size)
+    }
+
+    #[must_use]
+    pub fn rom(name: &str, address: u64, size: u64) -> Region {
+        Region::new(name, RegionKind::Rom, address, size)
+    }
+
+    #[must_use]
+    pub fn flash(name: &str, address: u64, size: u64) -> Region {
+        Region::new(name, RegionKind::Flash, address, size)
+    }
+
+    #[must_use]
+    pub fn mmio(name: &str, address: u64, size: u64) -> Region {
+        Region::new(name, RegionKind::Mmio, address, size)
+    }
+
+    /// Take the contents from `path`, starting `file_offset` bytes into the file.
+    #[must_use]
+    pub fn file(mut self, path: &str, file_offset: u64) -> Region {
+        self.file = Some(String::from(path));
+        self.file_offset = file_offset;
+        self
+    }
+
+    /// Take only `size` bytes from the file.
+    #[must_use]
+    pub fn file_size(mut self, size: u64) -> Region {
+        self.file_size = Some(size);
+        self
+    }
+
+    /// Use `data` as contents.
+    #[must_use]
+    pub fn data(mut self, data: &[u8]) -> Region {
+        self.data = data.to_vec();
+        self
+    }
+
+    /// Place the contents `offset` bytes into the region.
+    #[must_use]
+    pub fn offset(mut self, offset: u64) -> Region {
+        self.offset = offset;
+        self
+    }
+
+    #[must_use]
+    pub fn perms(mut self, perms: Permission) -> Region {
+        self.perms = Some(perms);
+        self
+    }
+
+    #[must_use]
+    pub fn fill(mut self, fill: u8) -> Region {
+        self.fill = Some(fill);
+        self
+    }
+
+    /// Return the permissions the region is mapped with.
+    #[must_use]
+    pub fn permissions(&self) -> Permission {
+        self.perms.unwrap_or(self.kind.default_permissions())
+    }
+
+    fn fill_byte(&self) -> u8 {
+        match (self.fill, self.kind) {
+            (Some(fill), _) => fill,
+            (None, RegionKind::Flash) => 0xff,
+            (None, _) => 0,
+        }
+    }
+}
+
+/// Why a memory map could not be read or materialized.
+#[derive(PartialEq, Debug, Clone)]
+pub enum FirmwareError {
+    /// The TOML or JSON text is malformed, with the message of the parser.
+    Parse(String),
+    /// A region names a file that could not be read.
+    MissingFile(String),
+    /// The contents of the region do not fit into it or its file is too short, or the
+    /// region runs past the end of the address space.
+    BadContents(String),
+    /// `registers` names a register the architecture does not have.
+    UnknownRegister(String),
+    /// Mapping or writing the regions failed.
+    Emu(uc_error),
+}
+
+impl From<uc_error> for FirmwareError {
+    fn from(err: uc_error) -> Self {
+        FirmwareError::Emu(err)
+    }
+}
+
+/// Description of the memory and initial state of a bare-metal target.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+#[cfg_attr(feature = "config", derive(serde::Deserialize))]
+#[cfg_attr(feature = "config", serde(deny_unknown_fields))]
+pub struct MemoryMap {
+    #[cfg_attr(feature = "config", serde(default))]
+    pub regions: Vec<Region>,
+    /// Initial values of registers by name, e.g. `r0`, `sp` or `rip`.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub registers: BTreeMap<String, u64>,
+    /// Address execution starts at, overriding the vector table.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub entry: Option<u64>,
+    /// Address of a Cortex-M vector table: the initial stack pointer is its first word
+    /// and the reset handler its second.
+    #[cfg_attr(feature = "config", serde(default))]
+    pub vector_table: Option<u64>,
+}
+
+impl MemoryMap {
+    #[must_use]
+    pub fn new() -> MemoryMap {
+        MemoryMap::default()
+    }
+
+    /// Parse a memory map from TOML.
+    #[cfg(feature = "config")]
+    pub fn from_toml(text: &str) -> Result<MemoryMap, FirmwareError> {
+        toml::from_str(text).map_err(|err| FirmwareError::Parse(format!("{err}")))
+    }
+
+    /// Parse a memory map from JSON.
+    #[cfg(feature = "config")]
+    pub fn from_json(text: &str) -> Result<MemoryMap, FirmwareError> {
+        serde_json::from_str(text).map_err(|err| FirmwareError::Parse(format!("{err}")))
+    }
+
+    #[must_use]
+    pub fn region(mut self, region: Region) -> MemoryMap {
+        self.regions.push(region);
+        self
+    }
+
+    /// Set register `name` to `value`, see `MemoryMap::registers`.
+    #[must_use]
+    pub fn register(mut self, name: &str, value: u64) -> MemoryMap {
+        self.registers.insert(String::from(name), value);
+        self
+    }
+
+    #[must_use]
+    pub fn entry(mut self, entry: u64) -> MemoryMap {
+        self.entry = Some(entry);
+        self
+    }
+
+    #[must_use]
+    pub fn vector_table(mut self, address: u64) -> MemoryMap {
+        self.vector_table = Some(address);
+        self
+    }
+
+    /// Map the regions onto `emu`, write their contents and set up the registers,
+    /// reading files from the file system.
+    #[cfg(feature = "std")]
+    pub fn materialize<D>(&self, emu: &mut Unicorn<D>) -> Result<(), FirmwareError> {
+        self.materialize_with(emu, |path| std::fs::read(path).ok())
+    }
+
+    /// Map the regions onto `emu`, write their contents and set up the registers,
+    /// reading files with `files`.
+    ///
+    /// Regions are mapped on whole pages: memory regions sharing a page get the union
+    /// of their permissions, MMIO regions must not share pages with other regions.
+    pub fn materialize_with<D, F>(
+        &self,
+        emu: &mut Unicorn<D>,
+        mut files: F,
+    ) -> Result<(), FirmwareError>
+    where
+        F: FnMut(&str) -> Option<Vec<u8>>,
+    {
+        let page_size = emu.query(Query::PAGE_SIZE)? as u64;
+        let (mmio, memory): (Vec<&Region>, Vec<&Region>) = self
+            .regions
+            .iter()
+            .filter(|r| r.size > 0)
+            .partition(|r| r.kind == RegionKind::Mmio);
+
+        let areas: Vec<_> = memory
+            .iter()
+            .map(|r| (r.address, r.size, r.permissions()))
+            .collect();
+        map_areas(emu, &areas)?;
+        for region in mmio {
+            let begin = region.address & !(page_size - 1);
+            let end = region
+                .address
+                .checked_add(region.size)
+                .and_then(|end| end.div_ceil(page_size).checked_mul(page_size))
+                .ok_or_else(|| FirmwareError::BadContents(region.name.clone()))?;
+            emu.mmio_map(
+                begin,
+                (end - begin) as usize,
+                Some(|_: &mut Unicorn<D>, _, _| 0),
+                Some(|_: &mut Unicorn<D>, _, _, _| {}),
+            )?;
+        }
+
+        for region in memory {
+            let fill = region.fill_byte();
+            if fill != 0 {
+                emu.mem_write(region.address, &vec![fill; region.size as usize])?;
+            }
+            let contents = match &region.file {
+                Some(path) => {
+                    let data =
+                        files(path).ok_or_else(|| FirmwareError::MissingFile(path.clone()))?;
+                    let begin = region.file_offset as usize;
+                    let end = match region.file_size {
+                        Some(size) => begin.checked_add(size as usize),
+                        None => Some(data.len()),
+                    };
+                    match end.and_then(|end| data.get(begin..end)) {
+                        Some(contents) => contents.to_vec(),
+                        None => return Err(FirmwareError::BadContents(region.name.clone())),
+                    }
+                }
+                None => region.data.clone(),
+            };
+            let address = region
+                .offset
+                .checked_add(contents.len() as u64)
+                .filter(|&end| end <= region.size)
+                .and_then(|_| region.address.checked_add(region.offset))
+                .ok_or_else(|| FirmwareError::BadContents(region.name.clone()))?;
+            emu.mem_write(address, &contents)?;
+        }
+
+        if let Some(table) = self.vector_table {
+            let mut words = [0u8; 8];
+            emu.mem_read(table, &mut words)?;
+            let sp = u32::from_le_bytes(words[..4].try_into().unwrap());
+            let reset = u32::from_le_bytes(words[4..].try_into().unwrap());
+            emu.set_sp(u64::from(sp))?;
+            // bit 0 of the reset handler selects Thumb mode
+            emu.set_pc(u64::from(reset))?;
+        }
+        if let Some(entry) = self.entry {
+            emu.set_pc(entry)?;
+        }
+        for (name, &value) in &self.registers {
+            match name.as_str() {
+                "pc" => emu.set_pc(value)?,
+                "sp" => emu.set_sp(value)?,
+                _ => {
+                    let reg = register(emu.get_arch(), emu.get_mode(), name)
+                        .ok_or_else(|| FirmwareError::UnknownRegister(name.clone()))?;
+                    emu.reg_write(reg, value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Name the pages of the materialized regions after the regions in `memory`, so they
+    /// show up in `VirtualMemory::maps`. A page shared by memory regions is named after
+    /// the last of them.
+    pub fn track<D>(&self, emu: &Unicorn<D>, memory: &mut VirtualMemory) -> Result<(), uc_error> {
+        for region in self.regions.iter().filter(|r| r.size > 0) {
+            let origin = Origin::Named(region.name.clone());
+            memory.set_origin(emu, region.address, region.size, origin)?;
+        }
+        Ok(())
+    }
+}
+
+/// Return the general purpose or status register `name` of `arch`, e.g. `r0`, `x30`,
+/// `lr`, `eax` or `rflags`.
+fn register(arch: Arch, mode: Mode, name: &str) -> Option<i32> {
+    let numbered = |prefix: &str, first: i32, count: i32| {
+        let n: i32 = name.strip_prefix(prefix)?.parse().ok()?;
+        Some(first + n).filter(|_| (0..count).contains(&n))
+    };
+    match arch {
+        Arch::ARM => numbered("r", RegisterARM::R0 as i32, 13).or(match name {
+            "r13" => Some(RegisterARM::SP as i32),
+            "r14" | "lr" => Some(RegisterARM::LR as i32),
+            "r15" => Some(RegisterARM::PC as i32),
+            "cpsr" => Some(RegisterARM::CPSR as i32),
+            "xpsr" => Some(RegisterARM::XPSR as i32),
+            "msp" => Some(RegisterARM::MSP as i32),
+            "psp" => Some(RegisterARM::PSP as i32),
+            "control" => Some(RegisterARM::CONTROL as i32),
+            "primask" => Some(RegisterARM::PRIMASK as i32),
+            _ => None,
+        }),
+        Arch::ARM64 => numbered("x", RegisterARM64::X0 as i32, 29).or(match name {
+            "x29" | "fp" => Some(RegisterARM64::X29 as i32),
+            "x30" | "lr" => Some(RegisterARM64::X30 as i32),
+            "nzcv" => Some(RegisterARM64::NZCV as i32),
+            "pstate" => Some(RegisterARM64::PSTATE as i32),
+            _ => None,
+        }),
+        Arch::RISCV => numbered("x", RegisterRISCV::X0 as i32, 32),
+        Arch::MIPS => numbered("r", RegisterMIPS::R0 as i32, 32),
+        Arch::PPC => numbered("r", RegisterPPC::R0 as i32, 32),
+        Arch::X86 => {
+            let regs: &[(&str, RegisterX86)] = &[
+                ("eax", RegisterX86::EAX),
+                ("ebx", RegisterX86::EBX),
+                ("ecx", RegisterX86::ECX),
+                ("edx", RegisterX86::EDX),
+                ("esi", RegisterX86::ESI),
+                ("edi", RegisterX86::EDI),
+                ("ebp", RegisterX86::EBP),
+                ("esp", RegisterX86::ESP),
+                ("eflags", RegisterX86::EFLAGS),
+            ];
+            let regs64: &[(&str, RegisterX86)] = &[
+                ("rax", RegisterX86::RAX),
+                ("rbx", RegisterX86::RBX),
+                ("rcx", RegisterX86::RCX),
+                ("rdx", RegisterX86::RDX),
+                ("rsi", RegisterX86::RSI),
+                ("rdi", RegisterX86::RDI),
+                ("rbp", RegisterX86::RBP),
+                ("rsp", RegisterX86::RSP),
+                ("r8", RegisterX86::R8),
+                ("r9", RegisterX86::R9),
+                ("r10", RegisterX86::R10),
+                ("r11", RegisterX86::R11),
+                ("r12", RegisterX86::R12),
+                ("r13", RegisterX86::R13),
+                ("r14", RegisterX86::R14),
+                ("r15", RegisterX86::R15),
+                ("rflags", RegisterX86::RFLAGS),
+            ];
+            let regs64 = if mode.contains(Mode::MODE_64) {
+                regs64
+            } else {
+                &[]
+            };
+            regs.iter()
+                .chain(regs64)
+                .find(|(n, _)| *n == name)
+                .map(|&(_, reg)| reg as i32)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "config")]
+mod config {
+    use crate::unicorn_const::Permission;
+    use alloc::string::String;
+    use serde::de::{Deserialize, Deserializer, Error};
+
+    /// Parse permissions written like `"rwx"`, `"r-x"` or `""`.
+    pub(super) fn permissions<'de, D: Deserializer<'de>>(
+        de: D,
+    ) -> Result<Option<Permission>, D::Error> {
+        let text = match Option::<String>::deserialize(de)? {
+            Some(text) => text,
+            None => return Ok(None),
+        };
+        let mut perms = Permission::NONE;
+        for c in text.chars() {
+            perms |= match c {
+                'r' => Permission::READ,
+                'w' => Permission::WRITE,
+                'x' => Permission::EXEC,
+                '-' => Permission::NONE,
+                c => return Err(D::Error::custom(format!("unknown permission `{c}`"))),
+            };
+        }
+        Ok(Some(perms))
+    }
+}
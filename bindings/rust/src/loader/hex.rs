@@ -0,0 +1,283 @@
+//! Intel HEX, Motorola S-record and UF2 loader.
+//!
+//! These formats carry the bytes of a firmware image together with their addresses,
+//! but no permissions or sections. The parsers turn them into an `Image`, a list of
+//! contiguous `Chunk`s and the entry address the file reports, if any: the start
+//! linear or start segment address of Intel HEX, or the S7, S8 or S9 record of an
+//! S-record file. UF2 has no entry address.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("firmware.hex")?;
+//! let image = hex::load(&mut emu, &data, Permission::ALL)?;
+//! ```
+
+use super::map_areas;
+use crate::unicorn_const::{uc_error, Permission};
+use crate::Unicorn;
+use alloc::vec::Vec;
+
+/// First magic number of a UF2 block, "UF2\n".
+pub const UF2_MAGIC_START0: u32 = 0x0a32_4655;
+pub const UF2_MAGIC_START1: u32 = 0x9e5d_5157;
+pub const UF2_MAGIC_END: u32 = 0x0ab1_6f30;
+pub const UF2_BLOCK_SIZE: usize = 512;
+/// The block is not meant for the main flash, e.g. comments or debug information.
+pub const UF2_FLAG_NOT_MAIN_FLASH: u32 = 0x0000_0001;
+/// The block is part of a file container instead of a flash image.
+pub const UF2_FLAG_FILE_CONTAINER: u32 = 0x0000_1000;
+/// The file size field holds the family ID of the target.
+pub const UF2_FLAG_FAMILY_ID: u32 = 0x0000_2000;
+
+/// Why an image could not be parsed or loaded. Lines are numbered from 1, UF2 blocks
+/// from 0.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum HexError {
+    /// The data is neither Intel HEX, S-record nor UF2.
+    UnknownFormat,
+    /// The line is not a well-formed record.
+    BadRecord(usize),
+    /// The checksum of the record on the line does not match.
+    Checksum(usize),
+    /// The record type on the line is not defined by the format.
+    UnsupportedRecord(usize),
+    /// The Intel HEX file ends before its end-of-file record.
+    MissingEnd,
+    /// The UF2 block has wrong magic numbers or a payload beyond the block.
+    BadBlock(usize),
+    /// Mapping or writing the image failed.
+    Emu(uc_error),
+}
+
+impl From<uc_error> for HexError {
+    fn from(err: uc_error) -> Self {
+        HexError::Emu(err)
+    }
+}
+
+/// Bytes at consecutive addresses.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Chunk {
+    pub address: u64,
+    pub data: Vec<u8>,
+}
+
+/// The contents of an Intel HEX, S-record or UF2 file.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Image {
+    /// Chunks in file order. Records at consecutive addresses are merged; where chunks
+    /// overlap, later ones win.
+    pub chunks: Vec<Chunk>,
+    pub entry: Option<u64>,
+}
+
+impl Image {
+    /// Parse an Intel HEX, S-record or UF2 file, telling them apart by their first
+    /// bytes.
+    pub fn parse(data: &[u8]) -> Result<Image, HexError> {
+        if data.len() >= 8 && read_u32(data, 0) == UF2_MAGIC_START0 {
+            return Image::parse_uf2(data, None);
+        }
+        let text = core::str::from_utf8(data).map_err(|_| HexError::UnknownFormat)?;
+        match text.trim_start().as_bytes().first() {
+            Some(b':') => Image::parse_ihex(text),
+            Some(b'S') => Image::parse_srec(text),
+            _ => Err(HexError::UnknownFormat),
+        }
+    }
+
+    /// Parse an Intel HEX file with 8, 16 or 32-bit addressing.
+    ///
+    /// A start segment address `CS:IP` becomes the linear entry address `CS * 16 + IP`.
+    pub fn parse_ihex(text: &str) -> Result<Image, HexError> {
+        let mut image = Image::default();
+        let mut base = 0u64;
+        for (n, line) in text.lines().enumerate() {
+            let n = n + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let bytes = line
+                .strip_prefix(':')
+                .and_then(decode)
+                .ok_or(HexError::BadRecord(n))?;
+            if bytes.len() < 5 || bytes.len() != 5 + bytes[0] as usize {
+                return Err(HexError::BadRecord(n));
+            }
+            if bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+                return Err(HexError::Checksum(n));
+            }
+            let offset = u64::from(u16::from_be_bytes([bytes[1], bytes[2]]));
+            let data = &bytes[4..bytes.len() - 1];
+            match (bytes[3], data.len()) {
+                (0x00, _) => image.push(base + offset, data),
+                (0x01, _) => return Ok(image),
+                (0x02, 2) => base = u64::from(u16::from_be_bytes([data[0], data[1]])) << 4,
+                (0x03, 4) => {
+                    let cs = u64::from(u16::from_be_bytes([data[0], data[1]]));
+                    let ip = u64::from(u16::from_be_bytes([data[2], data[3]]));
+                    image.entry = Some((cs << 4) + ip);
+                }
+                (0x04, 2) => base = u64::from(u16::from_be_bytes([data[0], data[1]])) << 16,
+                (0x05, 4) => image.entry = Some(u64::from(read_u32_be(data))),
+                (0x02..=0x05, _) => return Err(HexError::BadRecord(n)),
+                _ => return Err(HexError::UnsupportedRecord(n)),
+            }
+        }
+        Err(HexError::MissingEnd)
+    }
+
+    /// Parse a Motorola S-record file with 16, 24 or 32-bit addresses.
+    ///
+    /// Header (S0) and record count (S5, S6) records are checked but otherwise ignored.
+    pub fn parse_srec(text: &str) -> Result<Image, HexError> {
+        let mut image = Image::default();
+        for (n, line) in text.lines().enumerate() {
+            let n = n + 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut chars = line.chars();
+            if chars.next() != Some('S') {
+                return Err(HexError::BadRecord(n));
+            }
+            let kind = chars.next().ok_or(HexError::BadRecord(n))?;
+            let bytes = decode(chars.as_str()).ok_or(HexError::BadRecord(n))?;
+            let width = match kind {
+                '0' | '1' | '5' | '9' => 2,
+                '2' | '6' | '8' => 3,
+                '3' | '7' => 4,
+                '0'..='9' => return Err(HexError::UnsupportedRecord(n)),
+                _ => return Err(HexError::BadRecord(n)),
+            };
+            if bytes.len() < 2 + width || bytes.len() != 1 + bytes[0] as usize {
+                return Err(HexError::BadRecord(n));
+            }
+            if bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0xff {
+                return Err(HexError::Checksum(n));
+            }
+            let address = bytes[1..1 + width]
+                .iter()
+                .fold(0u64, |a, &b| a << 8 | u64::from(b));
+            let data = &bytes[1 + width..bytes.len() - 1];
+            match kind {
+                '1' | '2' | '3' => image.push(address, data),
+                '7' | '8' | '9' => image.entry = Some(address),
+                _ => {}
+            }
+        }
+        Ok(image)
+    }
+
+    /// Parse a UF2 file, keeping the blocks for the main flash and, given `family`,
+    /// only those for that family ID or without one.
+    pub fn parse_uf2(data: &[u8], family: Option<u32>) -> Result<Image, HexError> {
+        let mut image = Image::default();
+        for (n, block) in data.chunks(UF2_BLOCK_SIZE).enumerate() {
+            if block.len() != UF2_BLOCK_SIZE
+                || read_u32(block, 0) != UF2_MAGIC_START0
+                || read_u32(block, 4) != UF2_MAGIC_START1
+                || read_u32(block, 508) != UF2_MAGIC_END
+            {
+                return Err(HexError::BadBlock(n));
+            }
+            let flags = read_u32(block, 8);
+            let address = u64::from(read_u32(block, 12));
+            let size = read_u32(block, 16) as usize;
+            if size > 476 {
+                return Err(HexError::BadBlock(n));
+            }
+            if flags & (UF2_FLAG_NOT_MAIN_FLASH | UF2_FLAG_FILE_CONTAINER) != 0 {
+                continue;
+            }
+            if let Some(family) = family {
+                if flags & UF2_FLAG_FAMILY_ID != 0 && read_u32(block, 28) != family {
+                    continue;
+                }
+            }
+            image.push(address, &block[32..32 + size]);
+        }
+        Ok(image)
+    }
+
+    /// Return the lowest address and the end of the highest chunk.
+    #[must_use]
+    pub fn bounds(&self) -> Option<(u64, u64)> {
+        let begin = self.chunks.iter().map(|c| c.address).min()?;
+        let end = self
+            .chunks
+            .iter()
+            .map(|c| c.address + c.data.len() as u64)
+            .max()?;
+        Some((begin, end))
+    }
+
+    /// Write the chunks into memory that is already mapped.
+    pub fn write<D>(&self, emu: &mut Unicorn<D>) -> Result<(), uc_error> {
+        for chunk in &self.chunks {
+            emu.mem_write(chunk.address, &chunk.data)?;
+        }
+        Ok(())
+    }
+
+    /// Map the pages covering the chunks with `perms`, write the chunks and set the
+    /// PC to the entry address, if there is one.
+    pub fn map<D>(&self, emu: &mut Unicorn<D>, perms: Permission) -> Result<(), uc_error> {
+        let areas: Vec<_> = self
+            .chunks
+            .iter()
+            .map(|c| (c.address, c.data.len() as u64, perms))
+            .collect();
+        map_areas(emu, &areas)?;
+        self.write(emu)?;
+        if let Some(entry) = self.entry {
+            emu.set_pc(entry)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, address: u64, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+        match self.chunks.last_mut() {
+            Some(last) if last.address + last.data.len() as u64 == address => {
+                last.data.extend_from_slice(data);
+            }
+            _ => self.chunks.push(Chunk {
+                address,
+                data: data.to_vec(),
+            }),
+        }
+    }
+}
+
+/// Parse an Intel HEX, S-record or UF2 file and map it onto `emu`, see `Image::map`.
+pub fn load<D>(emu: &mut Unicorn<D>, data: &[u8], perms: Permission) -> Result<Image, HexError> {
+    let image = Image::parse(data)?;
+    image.map(emu, perms)?;
+    Ok(image)
+}
+
+/// Decode pairs of hex digits.
+fn decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(2) || !text.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    text.chunks(2)
+        .map(|pair| {
+            let pair = core::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(pair, 16).ok()
+        })
+        .collect()
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u32_be(data: &[u8]) -> u32 {
+    u32::from_be_bytes(data[..4].try_into().unwrap())
+}
@@ -0,0 +1,612 @@
+//! PE/COFF loader.
+//!
+//! Parses PE32 and PE32+ images of Windows executables and DLLs, maps their headers
+//! and sections with the permissions of the section and applies base relocations when
+//! the image is not loaded at its preferred base. Imports and exports are parsed, but
+//! only `Linker::link` binds imports and prepares a thread to run the image, see the
+//! `link` module.
+//!
+//! ```rust,ignore
+//! let data = std::fs::read("sample.exe")?;
+//! let (arch, mode) = Pe::parse(&data)?.arch_mode()?;
+//! let mut emu = Unicorn::new(arch, mode)?;
+//! let image = pe::map(&mut emu, &data, None)?;
+//! let run = image.export("Run").unwrap().address;
+//! ```
+
+pub mod link;
+
+pub use link::{LinkError, LinkedPe, Linker};
+
+use super::map_areas;
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission};
+use crate::Unicorn;
+use alloc::{string::String, vec::Vec};
+
+pub const IMAGE_FILE_RELOCS_STRIPPED: u16 = 0x0001;
+pub const IMAGE_FILE_DLL: u16 = 0x2000;
+
+pub const IMAGE_SCN_MEM_EXECUTE: u32 = 0x2000_0000;
+pub const IMAGE_SCN_MEM_READ: u32 = 0x4000_0000;
+pub const IMAGE_SCN_MEM_WRITE: u32 = 0x8000_0000;
+
+pub const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+pub const IMAGE_DIRECTORY_ENTRY_IMPORT: usize = 1;
+pub const IMAGE_DIRECTORY_ENTRY_BASERELOC: usize = 5;
+
+const IMAGE_FILE_MACHINE_I386: u16 = 0x014c;
+const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+
+const IMAGE_REL_BASED_ABSOLUTE: u16 = 0;
+const IMAGE_REL_BASED_HIGH: u16 = 1;
+const IMAGE_REL_BASED_LOW: u16 = 2;
+const IMAGE_REL_BASED_HIGHLOW: u16 = 3;
+const IMAGE_REL_BASED_DIR64: u16 = 10;
+
+/// Malformed or unsupported image.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PeError {
+    /// Not a PE image, or of an unknown optional header format.
+    BadHeader,
+    /// A header, section or table lies outside of the image.
+    Truncated,
+    /// `Machine` names an architecture the loader does not support.
+    UnsupportedMachine(u16),
+    /// The image is for another architecture or mode than the instance.
+    ArchMismatch { arch: Arch, mode: Mode },
+    /// The image has to be moved but has no base relocations.
+    NotRelocatable,
+    /// A base relocation of a type the loader does not apply.
+    UnsupportedRelocation(u16),
+    /// Mapping or writing the image failed.
+    Emu(uc_error),
+}
+
+impl From<uc_error> for PeError {
+    fn from(err: uc_error) -> Self {
+        PeError::Emu(err)
+    }
+}
+
+/// An entry of the section table.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Section {
+    pub name: String,
+    /// `VirtualAddress`, relative to the image base.
+    pub rva: u32,
+    pub virtual_size: u32,
+    /// `PointerToRawData`.
+    pub raw_offset: u32,
+    /// `SizeOfRawData`.
+    pub raw_size: u32,
+    /// Made of `IMAGE_SCN_*` flags.
+    pub characteristics: u32,
+}
+
+impl Section {
+    /// Return the permissions of the section.
+    #[must_use]
+    pub fn permissions(&self) -> Permission {
+        let mut perms = Permission::NONE;
+        if self.characteristics & IMAGE_SCN_MEM_READ != 0 {
+            perms |= Permission::READ;
+        }
+        if self.characteristics & IMAGE_SCN_MEM_WRITE != 0 {
+            perms |= Permission::WRITE;
+        }
+        if self.characteristics & IMAGE_SCN_MEM_EXECUTE != 0 {
+            perms |= Permission::EXEC;
+        }
+        perms
+    }
+
+    /// Return the size of the section in memory, which is `SizeOfRawData` for linkers
+    /// leaving `VirtualSize` at 0.
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        if self.virtual_size != 0 {
+            self.virtual_size
+        } else {
+            self.raw_size
+        }
+    }
+}
+
+/// A function imported from a DLL.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Import {
+    /// Name of the DLL as in the import directory, e.g. `KERNEL32.dll`.
+    pub dll: String,
+    /// Name of the function, `None` for imports by ordinal.
+    pub name: Option<String>,
+    /// The ordinal of imports by ordinal, the hint of the others.
+    pub ordinal: u16,
+    /// Address of the slot of the import address table.
+    pub iat: u64,
+}
+
+impl Import {
+    /// Return the name of the function, or `#` followed by the ordinal.
+    #[must_use]
+    pub fn function(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("#{}", self.ordinal),
+        }
+    }
+}
+
+/// A function or variable exported by the image.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Export {
+    /// Name of the export, `None` for exports by ordinal only.
+    pub name: Option<String>,
+    /// Ordinal, `Base` of the export directory applied.
+    pub ordinal: u32,
+    /// Address of the export, 0 for forwarders.
+    pub address: u64,
+    /// `DLL.Function` the export is forwarded to.
+    pub forwarder: Option<String>,
+}
+
+/// A parsed PE image.
+#[derive(Debug, Clone)]
+pub struct Pe<'d> {
+    data: &'d [u8],
+    /// Whether the image is PE32+.
+    pub is_64bit: bool,
+    pub machine: u16,
+    /// Made of `IMAGE_FILE_*` flags.
+    pub characteristics: u16,
+    /// Preferred base address.
+    pub image_base: u64,
+    /// `AddressOfEntryPoint`, relative to the image base, 0 for none.
+    pub entry: u32,
+    pub section_alignment: u32,
+    pub size_of_image: u32,
+    pub size_of_headers: u32,
+    pub subsystem: u16,
+    pub dll_characteristics: u16,
+    /// `(VirtualAddress, Size)` of the data directories.
+    pub directories: Vec<(u32, u32)>,
+    pub sections: Vec<Section>,
+}
+
+impl<'d> Pe<'d> {
+    /// Parse the headers of the image in `data`.
+    pub fn parse(data: &'d [u8]) -> Result<Pe<'d>, PeError> {
+        if data.len() < 0x40 || &data[..2] != b"MZ" {
+            return Err(PeError::BadHeader);
+        }
+        let mut pe = Pe {
+            data,
+            is_64bit: false,
+            machine: 0,
+            characteristics: 0,
+            image_base: 0,
+            entry: 0,
+            section_alignment: 0,
+            size_of_image: 0,
+            size_of_headers: 0,
+            subsystem: 0,
+            dll_characteristics: 0,
+            directories: Vec::new(),
+            sections: Vec::new(),
+        };
+        let nt = pe.u32(0x3c)? as usize;
+        if pe.data.get(nt..nt + 4) != Some(b"PE\0\0") {
+            return Err(PeError::BadHeader);
+        }
+        let coff = nt + 4;
+        pe.machine = pe.u16(coff)?;
+        let nsections = pe.u16(coff + 2)?;
+        let optional_size = pe.u16(coff + 16)?;
+        pe.characteristics = pe.u16(coff + 18)?;
+
+        let opt = coff + 20;
+        pe.is_64bit = match pe.u16(opt)? {
+            0x10b => false,
+            0x20b => true,
+            _ => return Err(PeError::BadHeader),
+        };
+        pe.entry = pe.u32(opt + 16)?;
+        pe.image_base = if pe.is_64bit {
+            pe.u64(opt + 24)?
+        } else {
+            u64::from(pe.u32(opt + 28)?)
+        };
+        pe.section_alignment = pe.u32(opt + 32)?;
+        pe.size_of_image = pe.u32(opt + 56)?;
+        pe.size_of_headers = pe.u32(opt + 60)?;
+        pe.subsystem = pe.u16(opt + 68)?;
+        pe.dll_characteristics = pe.u16(opt + 70)?;
+        // the sizes of stack and heap after DllCharacteristics are words
+        let w = if pe.is_64bit { 8 } else { 4 };
+        let ndirs = pe.u32(opt + 76 + 4 * w)?.min(16);
+        for i in 0..ndirs as usize {
+            let at = opt + 80 + 4 * w + 8 * i;
+            pe.directories.push((pe.u32(at)?, pe.u32(at + 4)?));
+        }
+
+        let table = opt + optional_size as usize;
+        for i in 0..nsections as usize {
+            let at = table + 40 * i;
+            let name = pe.data.get(at..at + 8).ok_or(PeError::Truncated)?;
+            let len = name.iter().position(|&b| b == 0).unwrap_or(8);
+            pe.sections.push(Section {
+                name: String::from_utf8_lossy(&name[..len]).into_owned(),
+                virtual_size: pe.u32(at + 8)?,
+                rva: pe.u32(at + 12)?,
+                raw_size: pe.u32(at + 16)?,
+                raw_offset: pe.u32(at + 20)?,
+                characteristics: pe.u32(at + 36)?,
+            });
+        }
+        Ok(pe)
+    }
+
+    /// Return the architecture and mode to emulate the image with.
+    pub fn arch_mode(&self) -> Result<(Arch, Mode), PeError> {
+        match self.machine {
+            IMAGE_FILE_MACHINE_I386 => Ok((Arch::X86, Mode::MODE_32)),
+            IMAGE_FILE_MACHINE_AMD64 => Ok((Arch::X86, Mode::MODE_64)),
+            machine => Err(PeError::UnsupportedMachine(machine)),
+        }
+    }
+
+    /// Return whether the image is a DLL.
+    #[must_use]
+    pub fn is_dll(&self) -> bool {
+        self.characteristics & IMAGE_FILE_DLL != 0
+    }
+
+    /// Return the data directory `index`, e.g. `IMAGE_DIRECTORY_ENTRY_IMPORT`, if the
+    /// image has it.
+    #[must_use]
+    pub fn directory(&self, index: usize) -> Option<(u32, u32)> {
+        self.directories
+            .get(index)
+            .copied()
+            .filter(|&(rva, size)| rva != 0 && size != 0)
+    }
+
+    /// Return the file contents of a section.
+    pub fn section_data(&self, section: &Section) -> Result<&'d [u8], PeError> {
+        let size = section.raw_size.min(section.size());
+        let end = section
+            .raw_offset
+            .checked_add(size)
+            .ok_or(PeError::Truncated)?;
+        self.data
+            .get(section.raw_offset as usize..end as usize)
+            .ok_or(PeError::Truncated)
+    }
+
+    /// Return the imports, the addresses of their IAT slots for the image loaded at
+    /// `base`.
+    pub fn imports(&self, base: u64) -> Result<Vec<Import>, PeError> {
+        let mut imports = Vec::new();
+        let (rva, _) = match self.directory(IMAGE_DIRECTORY_ENTRY_IMPORT) {
+            Some(directory) => directory,
+            None => return Ok(imports),
+        };
+        let w = if self.is_64bit { 8 } else { 4 };
+        let mut descriptor = rva;
+        loop {
+            let at = self.offset(descriptor)?;
+            let (lookup, name, iat) = (self.u32(at)?, self.u32(at + 12)?, self.u32(at + 16)?);
+            if name == 0 && iat == 0 {
+                break;
+            }
+            let dll = String::from(self.rva_str(name)?);
+            // without an import lookup table the IAT still holds the names
+            let lookup = if lookup != 0 { lookup } else { iat };
+            for i in 0.. {
+                let entry = self.word(self.offset(lookup.wrapping_add(i * w))?)?;
+                if entry == 0 {
+                    break;
+                }
+                let by_ordinal = entry >> (8 * w - 1) != 0;
+                let (name, ordinal) = if by_ordinal {
+                    (None, entry as u16)
+                } else {
+                    let hint = self.offset(entry as u32)?;
+                    (
+                        Some(String::from(self.rva_str((entry as u32).wrapping_add(2))?)),
+                        self.u16(hint)?,
+                    )
+                };
+                imports.push(Import {
+                    dll: dll.clone(),
+                    name,
+                    ordinal,
+                    iat: base + u64::from(iat) + u64::from(i * w),
+                });
+            }
+            descriptor = descriptor.checked_add(20).ok_or(PeError::Truncated)?;
+        }
+        Ok(imports)
+    }
+
+    /// Return the exports, their addresses for the image loaded at `base`.
+    pub fn exports(&self, base: u64) -> Result<Vec<Export>, PeError> {
+        let mut exports = Vec::new();
+        let (rva, size) = match self.directory(IMAGE_DIRECTORY_ENTRY_EXPORT) {
+            Some(directory) => directory,
+            None => return Ok(exports),
+        };
+        let at = self.offset(rva)?;
+        let ordinal_base = self.u32(at + 0x10)?;
+        let (nfunctions, nnames) = (self.u32(at + 0x14)?, self.u32(at + 0x18)?);
+        let (functions, names, ordinals) = (
+            self.u32(at + 0x1c)?,
+            self.u32(at + 0x20)?,
+            self.u32(at + 0x24)?,
+        );
+        // the address, name and ordinal tables lie inside the export directory,
+        // which bounds their lengths before anything is allocated for them
+        if u64::from(nfunctions) * 4 + u64::from(nnames) * 6 > u64::from(size) {
+            return Err(PeError::Truncated);
+        }
+        let mut named = vec![None; nfunctions as usize];
+        for i in 0..nnames {
+            let index = self.u16(self.offset(ordinals.wrapping_add(2 * i))?)? as usize;
+            let name = self.u32(self.offset(names.wrapping_add(4 * i))?)?;
+            if let Some(slot) = named.get_mut(index) {
+                *slot = Some(String::from(self.rva_str(name)?));
+            }
+        }
+        for (i, name) in named.into_iter().enumerate() {
+            let function = self.u32(self.offset(functions.wrapping_add(4 * i as u32))?)?;
+            if function == 0 {
+                continue;
+            }
+            // addresses inside the export directory are forwarder strings
+            let forwarder = if function.wrapping_sub(rva) < size {
+                Some(String::from(self.rva_str(function)?))
+            } else {
+                None
+            };
+            let ordinal = ordinal_base
+                .checked_add(i as u32)
+                .ok_or(PeError::BadHeader)?;
+            exports.push(Export {
+                name,
+                ordinal,
+                address: if forwarder.is_some() {
+                    0
+                } else {
+                    base + u64::from(function)
+                },
+                forwarder,
+            });
+        }
+        Ok(exports)
+    }
+
+    /// Return the base relocations as `(rva, type)`, skipping padding entries.
+    pub fn relocations(&self) -> Result<Vec<(u32, u16)>, PeError> {
+        let mut relocations = Vec::new();
+        let (rva, size) = match self.directory(IMAGE_DIRECTORY_ENTRY_BASERELOC) {
+            Some(directory) => directory,
+            None => return Ok(relocations),
+        };
+        let mut block = rva;
+        while (block - rva).checked_add(8).is_some_and(|end| end <= size) {
+            let at = self.offset(block)?;
+            let (page, block_size) = (self.u32(at)?, self.u32(at + 4)?);
+            if block_size < 8 {
+                break;
+            }
+            for i in 0..(block_size - 8) / 2 {
+                let entry = self.u16(at + 8 + 2 * i as usize)?;
+                if entry >> 12 != IMAGE_REL_BASED_ABSOLUTE {
+                    let target = page
+                        .checked_add(u32::from(entry & 0xfff))
+                        .ok_or(PeError::Truncated)?;
+                    relocations.push((target, entry >> 12));
+                }
+            }
+            block = match block.checked_add(block_size) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(relocations)
+    }
+
+    /// Return the file offset of `rva`.
+    fn offset(&self, rva: u32) -> Result<usize, PeError> {
+        if rva < self.size_of_headers {
+            return Ok(rva as usize);
+        }
+        self.sections
+            .iter()
+            .find(|s| s.rva <= rva && rva - s.rva < s.raw_size.min(s.size()))
+            .map(|s| (rva - s.rva + s.raw_offset) as usize)
+            .ok_or(PeError::Truncated)
+    }
+
+    /// Return the NUL terminated string at `rva`.
+    fn rva_str(&self, rva: u32) -> Result<&'d str, PeError> {
+        let s = self
+            .data
+            .get(self.offset(rva)?..)
+            .ok_or(PeError::Truncated)?;
+        let len = s.iter().position(|&b| b == 0).ok_or(PeError::Truncated)?;
+        core::str::from_utf8(&s[..len]).map_err(|_| PeError::BadHeader)
+    }
+
+    fn bytes<const N: usize>(&self, at: usize) -> Result<[u8; N], PeError> {
+        let end = at.checked_add(N).ok_or(PeError::Truncated)?;
+        let b = self.data.get(at..end).ok_or(PeError::Truncated)?;
+        Ok(b.try_into().unwrap())
+    }
+
+    fn u16(&self, at: usize) -> Result<u16, PeError> {
+        self.bytes(at).map(u16::from_le_bytes)
+    }
+
+    fn u32(&self, at: usize) -> Result<u32, PeError> {
+        self.bytes(at).map(u32::from_le_bytes)
+    }
+
+    fn u64(&self, at: usize) -> Result<u64, PeError> {
+        self.bytes(at).map(u64::from_le_bytes)
+    }
+
+    /// Read an address sized word.
+    fn word(&self, at: usize) -> Result<u64, PeError> {
+        if self.is_64bit {
+            self.u64(at)
+        } else {
+            self.u32(at).map(u64::from)
+        }
+    }
+}
+
+/// A mapped section.
+#[derive(PartialEq, Debug, Clone)]
+pub struct MappedSection {
+    pub name: String,
+    pub address: u64,
+    pub size: u64,
+    pub perms: Permission,
+}
+
+/// Description of a loaded image.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LoadedPe {
+    pub arch: Arch,
+    pub mode: Mode,
+    /// Address the image was loaded at.
+    pub base: u64,
+    /// `SizeOfImage`.
+    pub size: u64,
+    /// Entry point, `None` for images without one, e.g. resource DLLs.
+    pub entry: Option<u64>,
+    pub is_dll: bool,
+    pub sections: Vec<MappedSection>,
+    /// Imports, their IAT slots not bound yet.
+    pub imports: Vec<Import>,
+    pub exports: Vec<Export>,
+}
+
+impl LoadedPe {
+    /// Return the export `name`.
+    #[must_use]
+    pub fn export(&self, name: &str) -> Option<&Export> {
+        self.exports
+            .iter()
+            .find(|e| e.name.as_deref() == Some(name))
+    }
+
+    /// Return the section `name`, e.g. `.text`.
+    #[must_use]
+    pub fn section(&self, name: &str) -> Option<&MappedSection> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+
+    /// Name the pages of the image after the file at `path` in `memory`, so they show up
+    /// in `VirtualMemory::maps` like a mapped file. Offsets are relative addresses, as
+    /// for images mapped with `SEC_IMAGE`.
+    pub fn track<D>(
+        &self,
+        emu: &Unicorn<D>,
+        memory: &mut VirtualMemory,
+        path: &str,
+    ) -> Result<(), uc_error> {
+        let origin = Origin::File {
+            path: String::from(path),
+            offset: 0,
+        };
+        memory.set_origin(emu, self.base, self.size, origin)
+    }
+}
+
+/// Map the image in `data` into `emu` without touching any register or binding any
+/// import.
+///
+/// The image is loaded at `base`, or at its preferred base for `None`, and relocated if
+/// the two differ. Fails with `PeError::BadHeader` for a base within 4 GiB of the end
+/// of the address space.
+pub fn map<D>(emu: &mut Unicorn<D>, data: &[u8], base: Option<u64>) -> Result<LoadedPe, PeError> {
+    let pe = Pe::parse(data)?;
+    let (arch, mode) = pe.arch_mode()?;
+    let width = Mode::MODE_32 | Mode::MODE_64;
+    if emu.get_arch() != arch || emu.get_mode() & width != mode & width {
+        return Err(PeError::ArchMismatch { arch, mode });
+    }
+    let base = base.unwrap_or(pe.image_base);
+    if base != pe.image_base && pe.characteristics & IMAGE_FILE_RELOCS_STRIPPED != 0 {
+        return Err(PeError::NotRelocatable);
+    }
+    // RVAs are 32-bit, so no address in the image overflows past this check
+    if base.checked_add(u64::from(u32::MAX)).is_none() {
+        return Err(PeError::BadHeader);
+    }
+
+    let sections: Vec<MappedSection> = pe
+        .sections
+        .iter()
+        .filter(|s| s.size() > 0)
+        .map(|s| MappedSection {
+            name: s.name.clone(),
+            address: base + u64::from(s.rva),
+            size: u64::from(s.size()),
+            perms: s.permissions(),
+        })
+        .collect();
+    let mut areas = vec![(base, u64::from(pe.size_of_headers), Permission::READ)];
+    areas.extend(sections.iter().map(|s| (s.address, s.size, s.perms)));
+    map_areas(emu, &areas)?;
+
+    let headers = data
+        .get(..pe.size_of_headers as usize)
+        .ok_or(PeError::Truncated)?;
+    emu.mem_write(base, headers)?;
+    for section in pe.sections.iter().filter(|s| s.size() > 0) {
+        emu.mem_write(base + u64::from(section.rva), pe.section_data(section)?)?;
+    }
+    if base != pe.image_base {
+        relocate(emu, &pe, base)?;
+    }
+
+    Ok(LoadedPe {
+        arch,
+        mode,
+        base,
+        size: u64::from(pe.size_of_image),
+        entry: Some(pe.entry)
+            .filter(|&entry| entry != 0)
+            .map(|entry| base + u64::from(entry)),
+        is_dll: pe.is_dll(),
+        sections,
+        imports: pe.imports(base)?,
+        exports: pe.exports(base)?,
+    })
+}
+
+/// Apply the base relocations of an image loaded at `base`.
+fn relocate<D>(emu: &mut Unicorn<D>, pe: &Pe, base: u64) -> Result<(), PeError> {
+    let delta = base.wrapping_sub(pe.image_base);
+    for (rva, kind) in pe.relocations()? {
+        let address = base + u64::from(rva);
+        let size = match kind {
+            IMAGE_REL_BASED_HIGH | IMAGE_REL_BASED_LOW => 2,
+            IMAGE_REL_BASED_HIGHLOW => 4,
+            IMAGE_REL_BASED_DIR64 => 8,
+            kind => return Err(PeError::UnsupportedRelocation(kind)),
+        };
+        let mut bytes = [0u8; 8];
+        emu.mem_read(address, &mut bytes[..size])?;
+        let value = u64::from_le_bytes(bytes);
+        let value = match kind {
+            IMAGE_REL_BASED_HIGH => value.wrapping_add(delta >> 16),
+            _ => value.wrapping_add(delta),
+        };
+        emu.mem_write(address, &value.to_le_bytes()[..size])?;
+    }
+    Ok(())
+}
@@ -0,0 +1,451 @@
+//! Running PE images.
+//!
+//! `Linker::link` does what the Windows loader does for a single image: it maps the
+//! image, points every slot of its import address table at a thunk and prepares the
+//! main thread. No DLLs are loaded; every import has to be bound to a Rust function
+//! with `Linker::bind`, which runs in place of the import when its thunk is called.
+//!
+//! The thread gets a stack and a minimal TEB and PEB: stack bounds, the `Self` and
+//! PEB pointers, an empty TLS array, the image base, an empty loader list and a
+//! Windows 10 version. The TEB is found through FS on x86 and GS on x86-64.
+//! Executables are entered with no arguments, DLLs with those of
+//! `DllMain(base, DLL_PROCESS_ATTACH, NULL)`; returning from the entry point stops
+//! the emulation at `LinkedPe::exit`. A thunk that fails to return to its caller
+//! stops the emulation too, `LinkedPe::error` tells why.
+//!
+//! ```rust,ignore
+//! let mut linker = Linker::new();
+//! linker.bind("kernel32.dll!GetTickCount", 0, |_uc| 1000);
+//! linker.bind("Sleep", 1, |uc| {
+//!     println!("Sleep({})", pe::link::arg(uc, 0).unwrap());
+//!     0
+//! });
+//! let process = linker.link(&mut emu, &std::fs::read("sample.exe")?)?;
+//! emu.emu_start(process.image.entry.unwrap(), process.exit, 0, 0)?;
+//! ```
+
+use super::{map, Import, LoadedPe, PeError};
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, Mode, Permission};
+use crate::{RegisterX86, Unicorn};
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::cell::Cell;
+
+/// Default address of the thunks of bound imports.
+pub const THUNK_BASE: u64 = 0x7ff0_0000;
+
+/// Bytes reserved per thunk.
+pub const THUNK_SIZE: u64 = 0x10;
+
+/// Default address of the PEB.
+pub const PEB_BASE: u64 = 0x7ffd_d000;
+
+/// Default address of the TEB of the main thread.
+pub const TEB_BASE: u64 = 0x7ffd_e000;
+
+/// Default lowest address of the stack of the main thread.
+pub const STACK_BASE: u64 = 0x0010_0000;
+
+/// Default size of the stack of the main thread.
+pub const STACK_SIZE: u64 = 0x0010_0000;
+
+const TEB_SIZE: u64 = 0x2000;
+const PEB_SIZE: u64 = 0x1000;
+/// Offset of `PEB_LDR_DATA` in the PEB page.
+const LDR_OFFSET: u64 = 0x800;
+/// Offset of the TLS slots in the TEB pages.
+const TLS_OFFSET: u64 = 0x1c00;
+
+const PROCESS_ID: u64 = 0x1000;
+const THREAD_ID: u64 = 0x1004;
+
+const DLL_PROCESS_ATTACH: u64 = 1;
+const MSR_FS_BASE: u32 = 0xc000_0100;
+
+/// Callback standing in for an import, returning the result of the call.
+pub type ImportFn<'a, D> = Box<dyn FnMut(&mut Unicorn<D>) -> u64 + 'a>;
+
+/// Why linking failed.
+#[derive(PartialEq, Debug, Clone)]
+pub enum LinkError {
+    /// The image is malformed or could not be mapped.
+    Pe(PeError),
+    /// Imports that are not bound, as `dll!function`.
+    Unresolved(Vec<String>),
+    /// Mapping or writing the thread structures failed.
+    Emu(uc_error),
+}
+
+impl From<PeError> for LinkError {
+    fn from(err: PeError) -> Self {
+        LinkError::Pe(err)
+    }
+}
+
+impl From<uc_error> for LinkError {
+    fn from(err: uc_error) -> Self {
+        LinkError::Emu(err)
+    }
+}
+
+/// Description of a linked image and its main thread.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LinkedPe {
+    pub image: LoadedPe,
+    /// Imports with the addresses of the thunks their IAT slots point at.
+    pub imports: Vec<(Import, u64)>,
+    /// Address the entry point returns to, the emulation stops there.
+    pub exit: u64,
+    pub teb: u64,
+    pub peb: u64,
+    /// Lowest address and end of the stack.
+    pub stack: (u64, u64),
+    error: Rc<Cell<Option<uc_error>>>,
+}
+
+impl LinkedPe {
+    /// Return the address of the thunk of the import `function`.
+    #[must_use]
+    pub fn thunk(&self, function: &str) -> Option<u64> {
+        self.imports
+            .iter()
+            .find(|(import, _)| import.function() == function)
+            .map(|&(_, thunk)| thunk)
+    }
+
+    /// Name the pages of the image after `path` and those of the stack `[stack]` in
+    /// `memory`, see `LoadedPe::track`.
+    pub fn track<D>(
+        &self,
+        emu: &Unicorn<D>,
+        memory: &mut VirtualMemory,
+        path: &str,
+    ) -> Result<(), uc_error> {
+        self.image.track(emu, memory, path)?;
+        let (low, end) = self.stack;
+        memory.set_origin(emu, low, end - low, Origin::Stack)
+    }
+
+    /// Return why a thunk stopped the emulation, if one failed to return the result
+    /// of its binding to the caller.
+    #[must_use]
+    pub fn error(&self) -> Option<uc_error> {
+        self.error.get()
+    }
+}
+
+struct Binding<'a, D> {
+    callback: ImportFn<'a, D>,
+    /// Stack arguments the function removes on 32-bit x86.
+    args: u32,
+}
+
+/// Loads a PE image and prepares its main thread.
+pub struct Linker<'a, D> {
+    /// Address to load the image at, its preferred base for `None`.
+    pub base: Option<u64>,
+    /// Address of the thunks of bound imports.
+    pub thunk_base: u64,
+    pub teb_base: u64,
+    pub peb_base: u64,
+    /// Lowest address of the stack.
+    pub stack_base: u64,
+    pub stack_size: u64,
+    imports: BTreeMap<String, Binding<'a, D>>,
+}
+
+impl<'a, D> Default for Linker<'a, D> {
+    fn default() -> Self {
+        Linker::new()
+    }
+}
+
+impl<'a, D> Linker<'a, D> {
+    #[must_use]
+    pub fn new() -> Linker<'a, D> {
+        Linker {
+            base: None,
+            thunk_base: THUNK_BASE,
+            teb_base: TEB_BASE,
+            peb_base: PEB_BASE,
+            stack_base: STACK_BASE,
+            stack_size: STACK_SIZE,
+            imports: BTreeMap::new(),
+        }
+    }
+
+    /// Bind the import `name` to `callback`.
+    ///
+    /// `name` is the name of the function, or `#` and the ordinal for imports by
+    /// ordinal, optionally prefixed by the DLL and `!`, e.g. `ws2_32.dll!#23`. DLL names
+    /// are not case sensitive, and a binding with a DLL wins over one without.
+    ///
+    /// The callback runs in place of the function and returns its result, the
+    /// arguments are found with `arg`. `args` is the number of stack arguments the
+    /// function removes on 32-bit x86, i.e. 0 for `cdecl` functions; it is ignored
+    /// elsewhere.
+    pub fn bind<F>(&mut self, name: &str, args: u32, callback: F)
+    where
+        F: FnMut(&mut Unicorn<D>) -> u64 + 'a,
+    {
+        let key = match name.split_once('!') {
+            Some((dll, function)) => format!("{}!{}", dll.to_ascii_lowercase(), function),
+            None => String::from(name),
+        };
+        let callback = Box::new(callback);
+        self.imports.insert(key, Binding { callback, args });
+    }
+
+    /// Map the image in `data` into `emu`, bind its imports, set up the main thread
+    /// and point the PC at the entry of the image, if it has one.
+    pub fn link(mut self, emu: &mut Unicorn<'a, D>, data: &[u8]) -> Result<LinkedPe, LinkError>
+    where
+        D: 'a,
+    {
+        let image = map(emu, data, self.base)?;
+        let is_64bit = image.mode.contains(Mode::MODE_64);
+
+        // thunk 0 is where the entry point returns to
+        let mut thunks: Vec<String> = vec![String::new()];
+        let mut imports = Vec::new();
+        let mut unresolved = Vec::new();
+        for import in &image.imports {
+            let function = import.function();
+            let qualified = format!("{}!{}", import.dll.to_ascii_lowercase(), function);
+            let key = if self.imports.contains_key(&qualified) {
+                qualified
+            } else if self.imports.contains_key(&function) {
+                function
+            } else {
+                unresolved.push(format!("{}!{}", import.dll, function));
+                continue;
+            };
+            let index = match thunks.iter().position(|k| *k == key) {
+                Some(index) => index,
+                None => {
+                    thunks.push(key);
+                    thunks.len() - 1
+                }
+            };
+            imports.push((import.clone(), self.thunk_base + index as u64 * THUNK_SIZE));
+        }
+        if !unresolved.is_empty() {
+            return Err(LinkError::Unresolved(unresolved));
+        }
+        for (import, thunk) in &imports {
+            write_word(emu, import.iat, *thunk, is_64bit)?;
+        }
+        let error = self.install_thunks(emu, &thunks)?;
+
+        let stack_top = self.stack_base + self.stack_size;
+        emu.mem_map(
+            self.stack_base,
+            self.stack_size as usize,
+            Permission::READ | Permission::WRITE,
+        )?;
+        self.write_peb(emu, image.base, is_64bit)?;
+        self.write_teb(emu, stack_top, is_64bit)?;
+
+        let exit = self.thunk_base;
+        if is_64bit {
+            emu.reg_write(RegisterX86::GS_BASE, self.teb_base)?;
+            // the return address above the home space of four registers
+            let sp = stack_top - 0x28;
+            write_word(emu, sp, exit, true)?;
+            emu.reg_write(RegisterX86::RSP, sp)?;
+            emu.reg_write(RegisterX86::RCX, image.base)?;
+            emu.reg_write(RegisterX86::RDX, DLL_PROCESS_ATTACH)?;
+            emu.reg_write(RegisterX86::R8, 0)?;
+        } else {
+            let mut msr = [0u8; 16];
+            msr[..4].copy_from_slice(&MSR_FS_BASE.to_le_bytes());
+            msr[8..].copy_from_slice(&self.teb_base.to_le_bytes());
+            emu.reg_write_long(RegisterX86::MSR, &msr)?;
+            // the return address, then the arguments of DllMain
+            let sp = stack_top - 16;
+            for (i, value) in [exit, image.base, DLL_PROCESS_ATTACH, 0].iter().enumerate() {
+                write_word(emu, sp + 4 * i as u64, *value, false)?;
+            }
+            emu.reg_write(RegisterX86::ESP, sp)?;
+        }
+        if let Some(entry) = image.entry {
+            emu.set_pc(entry)?;
+        }
+
+        Ok(LinkedPe {
+            image,
+            imports,
+            exit,
+            teb: self.teb_base,
+            peb: self.peb_base,
+            stack: (self.stack_base, stack_top),
+            error,
+        })
+    }
+
+    /// Map the thunks and hook them, `keys` naming the binding of every thunk but the
+    /// first, which stops the emulation. Return where the hook records why it stopped
+    /// the emulation after a binding.
+    fn install_thunks(
+        &mut self,
+        emu: &mut Unicorn<'a, D>,
+        keys: &[String],
+    ) -> Result<Rc<Cell<Option<uc_error>>>, uc_error>
+    where
+        D: 'a,
+    {
+        let size = (keys.len() as u64 * THUNK_SIZE + 0xfff) & !0xfff;
+        emu.mem_map(
+            self.thunk_base,
+            size as usize,
+            Permission::READ | Permission::EXEC,
+        )?;
+        let mut bindings: Vec<Option<Binding<'a, D>>> =
+            keys.iter().map(|key| self.imports.remove(key)).collect();
+        let base = self.thunk_base;
+        let end = base + keys.len() as u64 * THUNK_SIZE - 1;
+        let error = Rc::new(Cell::new(None));
+        let hook_error = error.clone();
+        emu.add_code_hook(base, end, move |uc, address, _| {
+            let binding = match &mut bindings[((address - base) / THUNK_SIZE) as usize] {
+                Some(binding) => binding,
+                None => {
+                    let _ = uc.emu_stop();
+                    return;
+                }
+            };
+            let value = (binding.callback)(uc);
+            if let Err(err) = return_from_thunk(uc, value, binding.args) {
+                hook_error.set(Some(err));
+                let _ = uc.emu_stop();
+            }
+        })?;
+        Ok(error)
+    }
+
+    fn write_peb(
+        &self,
+        emu: &mut Unicorn<D>,
+        image_base: u64,
+        is_64bit: bool,
+    ) -> Result<(), uc_error> {
+        emu.mem_map(
+            self.peb_base,
+            PEB_SIZE as usize,
+            Permission::READ | Permission::WRITE,
+        )?;
+        let peb = self.peb_base;
+        let ldr = peb + LDR_OFFSET;
+        let w = if is_64bit { 8 } else { 4 };
+        write_word(emu, peb + 2 * w, image_base, is_64bit)?;
+        write_word(emu, peb + 3 * w, ldr, is_64bit)?;
+        // NumberOfProcessors, then OSMajorVersion, OSMinorVersion, OSBuildNumber and
+        // OSPlatformId of Windows 10 22H2
+        let (processors, version) = if is_64bit {
+            (0xb8, 0x118)
+        } else {
+            (0x64, 0xa4)
+        };
+        emu.mem_write(peb + processors, &1u32.to_le_bytes())?;
+        emu.mem_write(peb + version, &10u32.to_le_bytes())?;
+        emu.mem_write(peb + version + 8, &19045u16.to_le_bytes())?;
+        emu.mem_write(peb + version + 12, &2u32.to_le_bytes())?;
+
+        // Length and Initialized, then the three module lists, empty
+        let (length, lists) = if is_64bit { (0x58, 0x10) } else { (0x30, 0x0c) };
+        emu.mem_write(ldr, &[length, 0, 0, 0, 1])?;
+        for i in 0..3 {
+            let head = ldr + lists + 2 * w * i;
+            write_word(emu, head, head, is_64bit)?;
+            write_word(emu, head + w, head, is_64bit)?;
+        }
+        Ok(())
+    }
+
+    fn write_teb(
+        &self,
+        emu: &mut Unicorn<D>,
+        stack_top: u64,
+        is_64bit: bool,
+    ) -> Result<(), uc_error> {
+        emu.mem_map(
+            self.teb_base,
+            TEB_SIZE as usize,
+            Permission::READ | Permission::WRITE,
+        )?;
+        let teb = self.teb_base;
+        let w = if is_64bit { 8 } else { 4 };
+        // offsets of Self, ClientId, ThreadLocalStoragePointer and ProcessEnvironmentBlock
+        let (this, client_id, tls, peb) = if is_64bit {
+            (0x30, 0x40, 0x58, 0x60)
+        } else {
+            (0x18, 0x20, 0x2c, 0x30)
+        };
+        // the end of the SEH chain on x86
+        let exception_list = if is_64bit { 0 } else { 0xffff_ffff };
+        write_word(emu, teb, exception_list, is_64bit)?;
+        write_word(emu, teb + w, stack_top, is_64bit)?;
+        write_word(emu, teb + 2 * w, self.stack_base, is_64bit)?;
+        write_word(emu, teb + this, teb, is_64bit)?;
+        write_word(emu, teb + client_id, PROCESS_ID, is_64bit)?;
+        write_word(emu, teb + client_id + w, THREAD_ID, is_64bit)?;
+        write_word(emu, teb + tls, teb + TLS_OFFSET, is_64bit)?;
+        write_word(emu, teb + peb, self.peb_base, is_64bit)?;
+        Ok(())
+    }
+}
+
+/// Return argument `n` of a call following the Windows calling convention, read in a
+/// callback bound with `Linker::bind`.
+pub fn arg<D>(emu: &Unicorn<D>, n: usize) -> Result<u64, uc_error> {
+    let read_stack = |address: u64, size: usize| -> Result<u64, uc_error> {
+        let mut bytes = [0u8; 8];
+        emu.mem_read(address, &mut bytes[..size])?;
+        Ok(u64::from_le_bytes(bytes))
+    };
+    match emu.get_mode() {
+        Mode::MODE_32 => {
+            let esp = emu.reg_read(RegisterX86::ESP)?;
+            read_stack(esp + 4 + 4 * n as u64, 4)
+        }
+        _ => {
+            const REGS: [RegisterX86; 4] = [
+                RegisterX86::RCX,
+                RegisterX86::RDX,
+                RegisterX86::R8,
+                RegisterX86::R9,
+            ];
+            match REGS.get(n) {
+                Some(&reg) => emu.reg_read(reg),
+                None => {
+                    // above the return address and the home space
+                    let rsp = emu.reg_read(RegisterX86::RSP)?;
+                    read_stack(rsp + 8 + 8 * n as u64, 8)
+                }
+            }
+        }
+    }
+}
+
+/// Return `value` from the function a thunk stands in for, removing `args` stack
+/// arguments on 32-bit x86.
+fn return_from_thunk<D>(uc: &mut Unicorn<D>, value: u64, args: u32) -> Result<(), uc_error> {
+    let reg = uc.syscall_return_reg()?;
+    uc.reg_write(reg, value)?;
+    uc.simulate_return()?;
+    if uc.get_mode() == Mode::MODE_32 {
+        let esp = uc.reg_read(RegisterX86::ESP)?;
+        uc.reg_write(RegisterX86::ESP, esp + 4 * u64::from(args))?;
+    }
+    Ok(())
+}
+
+fn write_word<D>(
+    emu: &mut Unicorn<D>,
+    address: u64,
+    value: u64,
+    is_64bit: bool,
+) -> Result<(), uc_error> {
+    let size = if is_64bit { 8 } else { 4 };
+    emu.mem_write(address, &value.to_le_bytes()[..size])
+}
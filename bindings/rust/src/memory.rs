@@ -0,0 +1,548 @@
+//! Guest virtual memory management on top of `mem_map`.
+//!
+//! `Unicorn::mem_map` maps exactly the page aligned range it is given. A
+//! `VirtualMemory` picks the addresses instead, the way the kernel of a process does:
+//! `VirtualMemory::map` finds a free gap, `VirtualMemory::set_brk` grows and shrinks a
+//! heap, and `unmap` and `protect` work on any page aligned range, splitting the
+//! mappings they cut through. Every mapping remembers where it came from, and
+//! `VirtualMemory::maps` lists them in the format of `/proc/self/maps`.
+//!
+//! Memory mapped directly on the instance is picked up by `VirtualMemory::sync` as
+//! anonymous memory. The loaders name what they mapped with `track` methods, e.g.
+//! `LoadedElf::track`, and `StackBuilder::build_in` and `Heap::install_in` map the
+//! stack and the heap arena through a `VirtualMemory`.
+//!
+//! ```rust,ignore
+//! let mut memory = VirtualMemory::new(&emu)?;
+//! memory.set_heap_start(image.bounds().unwrap().1 + 1);
+//! let buffer = memory.map(&mut emu, 0, 0x3000, Permission::READ, Origin::Anonymous)?;
+//! memory.protect(&mut emu, buffer + 0x1000, 0x1000, Permission::NONE)?;
+//! let heap = memory.set_brk(&mut emu, memory.brk() + 0x10000);
+//! print!("{}", memory.maps());
+//! ```
+
+use crate::unicorn_const::{uc_error, Arch, Mode, Permission, Query};
+use crate::Unicorn;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::Write;
+
+/// Where the memory of a mapping came from.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Origin {
+    /// Memory without a name, e.g. from an anonymous `mmap`.
+    Anonymous,
+    /// The heap grown by `VirtualMemory::set_brk`.
+    Heap,
+    /// The stack of the main thread.
+    Stack,
+    /// The contents of the file at `path`, from `offset` on.
+    File { path: String, offset: u64 },
+    /// Anything else, e.g. `[vdso]` or a peripheral.
+    Named(String),
+}
+
+impl Origin {
+    /// Return the name `/proc/self/maps` shows for the mapping.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Origin::Anonymous => "",
+            Origin::Heap => "[heap]",
+            Origin::Stack => "[stack]",
+            Origin::File { path, .. } => path,
+            Origin::Named(name) => name,
+        }
+    }
+
+    /// Return the file offset the mapping starts at.
+    #[must_use]
+    pub fn offset(&self) -> u64 {
+        match self {
+            Origin::File { offset, .. } => *offset,
+            _ => 0,
+        }
+    }
+
+    /// Return the origin of the part of a mapping `delta` bytes into it.
+    fn advance(&self, delta: u64) -> Origin {
+        match self {
+            Origin::File { path, offset } => Origin::File {
+                path: path.clone(),
+                offset: offset + delta,
+            },
+            origin => origin.clone(),
+        }
+    }
+}
+
+/// A range of mapped guest memory.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Mapping {
+    pub begin: u64,
+    /// Last byte of the mapping, like `MemRegion::end`.
+    pub end: u64,
+    pub perms: Permission,
+    pub origin: Origin,
+}
+
+impl Mapping {
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.end - self.begin + 1
+    }
+
+    #[must_use]
+    pub fn contains(&self, address: u64) -> bool {
+        self.begin <= address && address <= self.end
+    }
+}
+
+/// Allocator of guest address space, see the module documentation.
+#[derive(Debug, Clone)]
+pub struct VirtualMemory {
+    page_size: u64,
+    /// End of the address space `map` allocates from.
+    limit: u64,
+    mmap_base: u64,
+    heap_start: u64,
+    brk: u64,
+    mappings: BTreeMap<u64, Mapping>,
+}
+
+impl VirtualMemory {
+    /// Manage the memory of `emu`, taking over what is mapped already.
+    ///
+    /// Allocations start at 0x7f00_0000_0000 below a 47-bit limit on 64-bit
+    /// architectures and at 0x7000_0000 below 4 GiB otherwise.
+    pub fn new<D>(emu: &Unicorn<D>) -> Result<VirtualMemory, uc_error> {
+        let arch = emu.get_arch();
+        let mode = Mode::from_bits_truncate(emu.query(Query::MODE)? as i32);
+        let is_64bit = match arch {
+            Arch::ARM64 | Arch::S390X => true,
+            Arch::X86 | Arch::MIPS | Arch::PPC | Arch::RISCV | Arch::SPARC => {
+                mode.contains(Mode::MODE_64)
+            }
+            _ => false,
+        };
+        let (mmap_base, limit) = if is_64bit {
+            (0x7f00_0000_0000, 1 << 47)
+        } else {
+            (0x7000_0000, 1 << 32)
+        };
+        let mut memory = VirtualMemory {
+            page_size: emu.query(Query::PAGE_SIZE)? as u64,
+            limit,
+            mmap_base,
+            heap_start: 0,
+            brk: 0,
+            mappings: BTreeMap::new(),
+        };
+        memory.sync(emu)?;
+        Ok(memory)
+    }
+
+    #[must_use]
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// Set the address `map` starts looking for free memory at without a hint.
+    pub fn set_mmap_base(&mut self, address: u64) {
+        self.mmap_base = address;
+    }
+
+    /// Set the end of the address space `map` allocates from.
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit;
+    }
+
+    /// Set where the heap grown by `set_brk` starts, usually the end of the loaded
+    /// image. Without it the heap cannot grow.
+    pub fn set_heap_start(&mut self, address: u64) {
+        self.heap_start = address;
+        self.brk = address;
+    }
+
+    #[must_use]
+    pub fn heap_start(&self) -> u64 {
+        self.heap_start
+    }
+
+    /// Return the current end of the heap.
+    #[must_use]
+    pub fn brk(&self) -> u64 {
+        self.brk
+    }
+
+    /// Return the mappings, sorted by address.
+    pub fn mappings(&self) -> impl Iterator<Item = &Mapping> {
+        self.mappings.values()
+    }
+
+    /// Return the mapping containing `address`.
+    #[must_use]
+    pub fn mapping(&self, address: u64) -> Option<&Mapping> {
+        self.mappings
+            .range(..=address)
+            .next_back()
+            .map(|(_, mapping)| mapping)
+            .filter(|mapping| mapping.contains(address))
+    }
+
+    /// Return whether nothing is mapped in `[address, address + size)`.
+    #[must_use]
+    pub fn is_free(&self, address: u64, size: u64) -> bool {
+        match address.checked_add(size) {
+            Some(end) => self.overlapping(address, end).next().is_none(),
+            None => false,
+        }
+    }
+
+    /// Return the lowest page aligned free range of `size` bytes at `hint` or above, or
+    /// above the mmap base if `hint` is 0.
+    #[must_use]
+    pub fn find_free(&self, hint: u64, size: u64) -> Option<u64> {
+        let size = self.page_up(size)?;
+        let mut address = self.page_up(if hint != 0 { hint } else { self.mmap_base })?;
+        for mapping in self.mappings.values() {
+            if mapping.end < address {
+                continue;
+            }
+            if mapping.begin >= address.checked_add(size)? {
+                break;
+            }
+            address = self.page_up(mapping.end.checked_add(1)?)?;
+        }
+        address
+            .checked_add(size)
+            .filter(|&end| end <= self.limit)
+            .map(|_| address)
+    }
+
+    /// Map `size` bytes at the lowest free address at `hint` or above, see
+    /// `VirtualMemory::find_free`, and return the address.
+    ///
+    /// Fails with `uc_error::NOMEM` if there is no room left.
+    pub fn map<D>(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        hint: u64,
+        size: u64,
+        perms: Permission,
+        origin: Origin,
+    ) -> Result<u64, uc_error> {
+        if size == 0 {
+            return Err(uc_error::ARG);
+        }
+        let address = self.find_free(hint, size).ok_or(uc_error::NOMEM)?;
+        self.insert(emu, address, self.page_up(size).unwrap(), perms, origin)?;
+        Ok(address)
+    }
+
+    /// Map `size` bytes at `address`, replacing what is mapped there if `replace` is set
+    /// and failing with `uc_error::MAP` otherwise, like `MAP_FIXED` and
+    /// `MAP_FIXED_NOREPLACE`.
+    pub fn map_fixed<D>(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        address: u64,
+        size: u64,
+        perms: Permission,
+        origin: Origin,
+        replace: bool,
+    ) -> Result<(), uc_error> {
+        let size = self.check(address, size)?;
+        if replace {
+            self.unmap(emu, address, size)?;
+        } else if !self.is_free(address, size) {
+            return Err(uc_error::MAP);
+        }
+        self.insert(emu, address, size, perms, origin)
+    }
+
+    /// Unmap whatever is mapped in `[address, address + size)`, `size` rounded up to
+    /// whole pages. Mappings only partly in the range keep the rest.
+    pub fn unmap<D>(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        address: u64,
+        size: u64,
+    ) -> Result<(), uc_error> {
+        let size = self.check(address, size)?;
+        let end = address + size;
+        self.split(address);
+        self.split(end);
+        let begins: Vec<u64> = self.overlapping(address, end).map(|m| m.begin).collect();
+        for begin in begins {
+            let size = self.mappings[&begin].size();
+            emu.mem_unmap(begin, size as usize)?;
+            self.mappings.remove(&begin);
+        }
+        Ok(())
+    }
+
+    /// Change the permissions of `[address, address + size)`, `size` rounded up to whole
+    /// pages, splitting the mappings at the ends of the range.
+    ///
+    /// Fails with `uc_error::NOMEM` without changing anything if part of the range is
+    /// not mapped, like `mprotect`.
+    pub fn protect<D>(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        address: u64,
+        size: u64,
+        perms: Permission,
+    ) -> Result<(), uc_error> {
+        let size = self.check(address, size)?;
+        let end = address + size;
+        let mut covered = address;
+        for mapping in self.overlapping(address, end) {
+            if mapping.begin > covered {
+                break;
+            }
+            covered = mapping.end.saturating_add(1);
+        }
+        if covered < end {
+            return Err(uc_error::NOMEM);
+        }
+        self.split(address);
+        self.split(end);
+        let begins: Vec<u64> = self.overlapping(address, end).map(|m| m.begin).collect();
+        for begin in begins {
+            let mapping = self.mappings.get_mut(&begin).unwrap();
+            emu.mem_protect(begin, mapping.size() as usize, perms)?;
+            mapping.perms = perms;
+        }
+        Ok(())
+    }
+
+    /// Move the end of the heap to `address` and return the new end, or the old one if
+    /// the heap cannot be moved there, like the `brk` system call.
+    pub fn set_brk<D>(&mut self, emu: &mut Unicorn<D>, address: u64) -> u64 {
+        if self.heap_start == 0 || address < self.heap_start {
+            return self.brk;
+        }
+        let (old_end, new_end) = match (self.page_up(self.brk), self.page_up(address)) {
+            (Some(old_end), Some(new_end)) => (old_end, new_end),
+            _ => return self.brk,
+        };
+        if new_end > old_end {
+            let size = new_end - old_end;
+            if !self.is_free(old_end, size)
+                || self
+                    .insert(
+                        emu,
+                        old_end,
+                        size,
+                        Permission::READ | Permission::WRITE,
+                        Origin::Heap,
+                    )
+                    .is_err()
+            {
+                return self.brk;
+            }
+        } else if new_end < old_end && self.unmap(emu, new_end, old_end - new_end).is_err() {
+            return self.brk;
+        }
+        self.brk = address;
+        address
+    }
+
+    /// Bring the mappings up to date with the regions of `emu`, for memory that was
+    /// mapped, unmapped or protected on the instance directly.
+    ///
+    /// Regions that were not tracked yet become `Origin::Anonymous` mappings.
+    pub fn sync<D>(&mut self, emu: &Unicorn<D>) -> Result<(), uc_error> {
+        let mut regions = emu.mem_regions()?;
+        regions.sort_by_key(|region| region.begin);
+        let mut mappings = BTreeMap::new();
+        for region in regions {
+            let mut next = Some(region.begin);
+            for old in self.overlapping(region.begin, region.end.saturating_add(1)) {
+                let address = match next {
+                    Some(address) => address,
+                    None => break,
+                };
+                let begin = old.begin.max(address);
+                if begin > address {
+                    mappings.insert(
+                        address,
+                        Mapping {
+                            begin: address,
+                            end: begin - 1,
+                            perms: region.perms,
+                            origin: Origin::Anonymous,
+                        },
+                    );
+                }
+                let end = old.end.min(region.end);
+                mappings.insert(
+                    begin,
+                    Mapping {
+                        begin,
+                        end,
+                        perms: region.perms,
+                        origin: old.origin.advance(begin - old.begin),
+                    },
+                );
+                next = end.checked_add(1).filter(|&next| next <= region.end);
+            }
+            if let Some(address) = next {
+                mappings.insert(
+                    address,
+                    Mapping {
+                        begin: address,
+                        end: region.end,
+                        perms: region.perms,
+                        origin: Origin::Anonymous,
+                    },
+                );
+            }
+        }
+        self.mappings = mappings;
+        Ok(())
+    }
+
+    /// Give the mapped pages of `[address, address + size)` the origin `origin`, e.g. for
+    /// memory a loader mapped on the instance directly. `origin` describes the page
+    /// holding `address`, pages that are not mapped are skipped.
+    pub fn set_origin<D>(
+        &mut self,
+        emu: &Unicorn<D>,
+        address: u64,
+        size: u64,
+        origin: Origin,
+    ) -> Result<(), uc_error> {
+        self.sync(emu)?;
+        let begin = address & !(self.page_size - 1);
+        let end = address
+            .checked_add(size)
+            .and_then(|end| self.page_up(end))
+            .ok_or(uc_error::ARG)?;
+        self.split(begin);
+        self.split(end);
+        for (_, mapping) in self.mappings.range_mut(begin..end) {
+            mapping.origin = origin.advance(mapping.begin - begin);
+        }
+        Ok(())
+    }
+
+    /// Describe the mappings in the format of `/proc/self/maps`, merging neighbours
+    /// the way the kernel does.
+    #[must_use]
+    pub fn maps(&self) -> String {
+        let mut merged: Vec<Mapping> = Vec::new();
+        for mapping in self.mappings.values() {
+            if let Some(last) = merged.last_mut() {
+                if last.end.checked_add(1) == Some(mapping.begin)
+                    && last.perms == mapping.perms
+                    && last.origin.advance(last.size()) == mapping.origin
+                {
+                    last.end = mapping.end;
+                    continue;
+                }
+            }
+            merged.push(mapping.clone());
+        }
+
+        let mut maps = String::new();
+        for mapping in merged {
+            let mut line = String::new();
+            let _ = write!(
+                line,
+                "{:08x}-{:08x} {}{}{}p {:08x} 00:00 0",
+                mapping.begin,
+                mapping.end.wrapping_add(1),
+                if mapping.perms.contains(Permission::READ) {
+                    'r'
+                } else {
+                    '-'
+                },
+                if mapping.perms.contains(Permission::WRITE) {
+                    'w'
+                } else {
+                    '-'
+                },
+                if mapping.perms.contains(Permission::EXEC) {
+                    'x'
+                } else {
+                    '-'
+                },
+                mapping.origin.offset(),
+            );
+            let name = mapping.origin.name();
+            if !name.is_empty() {
+                while line.len() < 73 {
+                    line.push(' ');
+                }
+                line.push_str(name);
+            }
+            maps.push_str(&line);
+            maps.push('\n');
+        }
+        maps
+    }
+
+    /// Map and track a range known to be free.
+    fn insert<D>(
+        &mut self,
+        emu: &mut Unicorn<D>,
+        address: u64,
+        size: u64,
+        perms: Permission,
+        origin: Origin,
+    ) -> Result<(), uc_error> {
+        emu.mem_map(address, size as usize, perms)?;
+        self.mappings.insert(
+            address,
+            Mapping {
+                begin: address,
+                end: address + size - 1,
+                perms,
+                origin,
+            },
+        );
+        Ok(())
+    }
+
+    /// Return the mappings overlapping `[begin, end)`.
+    fn overlapping(&self, begin: u64, end: u64) -> impl Iterator<Item = &Mapping> {
+        let first = self.mapping(begin).map_or(begin, |mapping| mapping.begin);
+        self.mappings
+            .range(first..end.max(first))
+            .map(|(_, mapping)| mapping)
+    }
+
+    /// Split the mapping containing `address` so that one starts there.
+    fn split(&mut self, address: u64) {
+        let mapping = match self.mapping(address) {
+            Some(mapping) if mapping.begin != address => mapping,
+            _ => return,
+        };
+        let begin = mapping.begin;
+        let tail = Mapping {
+            begin: address,
+            end: mapping.end,
+            perms: mapping.perms,
+            origin: mapping.origin.advance(address - begin),
+        };
+        self.mappings.get_mut(&begin).unwrap().end = address - 1;
+        self.mappings.insert(address, tail);
+    }
+
+    /// Check that `address` is page aligned and return `size` rounded up to whole pages.
+    fn check(&self, address: u64, size: u64) -> Result<u64, uc_error> {
+        if address & (self.page_size - 1) != 0 || size == 0 {
+            return Err(uc_error::ARG);
+        }
+        self.page_up(size)
+            .filter(|&size| address.checked_add(size).is_some())
+            .ok_or(uc_error::ARG)
+    }
+
+    fn page_up(&self, value: u64) -> Option<u64> {
+        value
+            .checked_add(self.page_size - 1)
+            .map(|value| value & !(self.page_size - 1))
+    }
+}
@@ -157,7 +157,8 @@ pub enum RegisterMIPS {
     CP0_CONFIG3 = 137,
     CP0_USERLOCAL = 138,
     CP0_STATUS = 139,
-    ENDING = 140,
+    FCR31 = 140,
+    ENDING = 141,
 }
 
 impl RegisterMIPS {
@@ -0,0 +1,210 @@
+//! Shadow memory tracking which guest bytes have been initialized.
+//!
+//! Once enabled with `Unicorn::shadow_enable`, every region mapped with `mem_map` or
+//! `mem_map_ptr` starts out uninitialized. Bytes become initialized when they are written by `mem_write` or
+//! by a guest store, and guest reads of bytes that are still uninitialized are recorded
+//! as `UninitRead`s, similar to MemorySanitizer. Memory mapped before the shadow was
+//! enabled is considered initialized; use `ShadowMemory::poison` to track it anyway,
+//! e.g. for a stack that was mapped up front.
+
+use crate::ffi;
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+const PAGE_SIZE: u64 = 0x1000;
+const PAGE_MASK: u64 = !(PAGE_SIZE - 1);
+
+/// Guest read of bytes that were never initialized.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct UninitRead {
+    /// PC of the reading instruction.
+    pub pc: u64,
+    /// First uninitialized byte that was read.
+    pub address: u64,
+    /// Size of the whole read.
+    pub size: usize,
+}
+
+/// Initialized bits of the tracked pages.
+#[derive(Debug, Clone)]
+pub struct ShadowMemory {
+    /// One bit per byte, set once the byte is initialized. Pages without an entry are
+    /// not tracked and count as initialized.
+    pages: BTreeMap<u64, Box<[u8; (PAGE_SIZE / 8) as usize]>>,
+    reads: Vec<UninitRead>,
+    /// Pages whose bits changed since the last `checkpoint`.
+    changed: BTreeSet<u64>,
+    pub(crate) stop_on_read: bool,
+    pub(crate) hooks: Vec<ffi::uc_hook>,
+}
+
+impl ShadowMemory {
+    pub(crate) fn new(stop_on_read: bool, hooks: Vec<ffi::uc_hook>) -> ShadowMemory {
+        ShadowMemory {
+            pages: BTreeMap::new(),
+            reads: Vec::new(),
+            changed: BTreeSet::new(),
+            stop_on_read,
+            hooks,
+        }
+    }
+
+    /// Mark `[address, address + size)` as uninitialized.
+    pub fn poison(&mut self, address: u64, size: u64) {
+        self.update(address, size, false);
+    }
+
+    /// Mark `[address, address + size)` as initialized.
+    pub fn unpoison(&mut self, address: u64, size: u64) {
+        self.update(address, size, true);
+    }
+
+    /// Stop tracking `[address, address + size)`, e.g. after it was unmapped.
+    pub fn forget(&mut self, address: u64, size: u64) {
+        if size == 0 {
+            return;
+        }
+        let first = address & PAGE_MASK;
+        let last = (address + size - 1) & PAGE_MASK;
+        let pages: Vec<u64> = self.pages.range(first..=last).map(|(&p, _)| p).collect();
+        for page in pages {
+            self.pages.remove(&page);
+            self.changed.insert(page);
+        }
+    }
+
+    /// Give `[to, to + size)` the initialized state of `[from, from + size)`, e.g. when
+    /// a chunk is moved by `realloc`.
+    pub fn copy(&mut self, from: u64, to: u64, size: u64) {
+        let mut offset = 0;
+        while offset < size {
+            let init = self.first_uninit(from + offset, 1).is_none();
+            let run = (offset + 1..size)
+                .find(|&o| self.first_uninit(from + o, 1).is_none() != init)
+                .unwrap_or(size)
+                - offset;
+            self.update(to + offset, run, init);
+            offset += run;
+        }
+    }
+
+    /// Return the first uninitialized byte of `[address, address + size)`, if any.
+    #[must_use]
+    pub fn first_uninit(&self, address: u64, size: u64) -> Option<u64> {
+        if size == 0 {
+            return None;
+        }
+        (address..=address.saturating_add(size - 1)).find(|&a| {
+            match self.pages.get(&(a & PAGE_MASK)) {
+                Some(bits) => {
+                    let offset = a - (a & PAGE_MASK);
+                    bits[(offset / 8) as usize] & (1 << (offset % 8)) == 0
+                }
+                None => false,
+            }
+        })
+    }
+
+    /// Return the uninitialized reads recorded so far.
+    #[must_use]
+    pub fn reads(&self) -> &[UninitRead] {
+        &self.reads
+    }
+
+    /// Forget the recorded uninitialized reads.
+    pub fn clear_reads(&mut self) {
+        self.reads.clear();
+    }
+
+    /// Start tracking changes from the current state and return a copy of it, see
+    /// `restore`.
+    pub(crate) fn checkpoint(&mut self) -> ShadowMemory {
+        self.changed.clear();
+        self.clone()
+    }
+
+    /// Go back to `checkpoint`, copying only the pages that changed since it was taken.
+    pub(crate) fn restore(&mut self, checkpoint: &ShadowMemory) {
+        for page in core::mem::take(&mut self.changed) {
+            match checkpoint.pages.get(&page) {
+                Some(bits) => {
+                    self.pages.insert(page, bits.clone());
+                }
+                None => {
+                    self.pages.remove(&page);
+                }
+            }
+        }
+        self.reads.clone_from(&checkpoint.reads);
+    }
+
+    /// Record a guest read, returns whether it touched uninitialized bytes.
+    pub(crate) fn check_read(&mut self, pc: u64, address: u64, size: usize) -> bool {
+        match self.first_uninit(address, size as u64) {
+            Some(uninit) => {
+                self.reads.push(UninitRead {
+                    pc,
+                    address: uninit,
+                    size,
+                });
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn update(&mut self, address: u64, size: u64, init: bool) {
+        if size == 0 {
+            return;
+        }
+        // inclusive, the range may end at the top of the address space
+        let last = address.saturating_add(size - 1);
+        let mut a = address;
+        loop {
+            let page = a & PAGE_MASK;
+            let page_last = (page + (PAGE_SIZE - 1)).min(last);
+            // untracked pages are initialized already
+            if !init || self.pages.contains_key(&page) {
+                let bits = self
+                    .pages
+                    .entry(page)
+                    .or_insert_with(|| Box::new([0xff; (PAGE_SIZE / 8) as usize]));
+                set_bits(
+                    &mut bits[..],
+                    (a - page) as usize,
+                    (page_last - page + 1) as usize,
+                    init,
+                );
+                if init && bits.iter().all(|&b| b == 0xff) {
+                    self.pages.remove(&page);
+                }
+                self.changed.insert(page);
+            }
+            if page_last == last {
+                break;
+            }
+            a = page_last + 1;
+        }
+    }
+}
+
+/// Set or clear the bits `[lo, hi)` of `bits`.
+fn set_bits(bits: &mut [u8], mut lo: usize, hi: usize, set: bool) {
+    while lo < hi {
+        if lo & 7 == 0 && hi - lo >= 8 {
+            let bytes = (hi - lo) / 8;
+            bits[lo / 8..lo / 8 + bytes].fill(if set { 0xff } else { 0 });
+            lo += bytes * 8;
+            continue;
+        }
+        if set {
+            bits[lo / 8] |= 1 << (lo % 8);
+        } else {
+            bits[lo / 8] &= !(1 << (lo % 8));
+        }
+        lo += 1;
+    }
+}
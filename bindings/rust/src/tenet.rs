@@ -0,0 +1,281 @@
+//! Instruction traces for Tenet.
+//!
+//! Tenet replays a trace in IDA, stepping through the execution forwards and backwards.
+//! Its traces are text, one line per executed instruction holding the registers that
+//! changed since the previous line along with the memory accessed in between:
+//!
+//! ```text
+//! eax=0x0,ebx=0x0,ecx=0x0,edx=0x0,ebp=0x0,esp=0x0,esi=0x0,edi=0x0,eip=0x1000
+//! eax=0x11223344,eip=0x1005,mr=0x2000:44332211
+//! eip=0x100a,mw=0x2004:44332211
+//! ```
+//!
+//! Every line is the state of the guest right before the instruction at the PC of the
+//! line runs, so the memory accesses of an instruction show up on the line after it.
+//! The first line holds all registers, `Tracer::finish` writes the state after the last
+//! instruction. Supported are the x86 and x86-64 general purpose registers and the
+//! ARM and ARM64 core registers, named as Tenet expects them.
+//!
+//! ```rust,ignore
+//! let tracer = Tracer::start(&mut emu, |data: &[u8]| file.write_all(data).unwrap())?;
+//! emu.emu_start(0x1000, 0x2000, 0, 0)?;
+//! tracer.borrow_mut().finish(&mut emu)?;
+//! ```
+
+use crate::trace::{TraceSink, DEFAULT_BUFFER_SIZE};
+use crate::unicorn_const::{uc_error, Arch, HookType, Mode, Query};
+use crate::{ffi, RegisterARM, RegisterARM64, RegisterX86, Unicorn};
+use alloc::{rc::Rc, string::String, vec::Vec};
+use core::cell::RefCell;
+use core::fmt::Write;
+
+const X86_32: [(&str, RegisterX86); 9] = [
+    ("eax", RegisterX86::EAX),
+    ("ebx", RegisterX86::EBX),
+    ("ecx", RegisterX86::ECX),
+    ("edx", RegisterX86::EDX),
+    ("ebp", RegisterX86::EBP),
+    ("esp", RegisterX86::ESP),
+    ("esi", RegisterX86::ESI),
+    ("edi", RegisterX86::EDI),
+    ("eip", RegisterX86::EIP),
+];
+
+const X86_64: [(&str, RegisterX86); 17] = [
+    ("rax", RegisterX86::RAX),
+    ("rbx", RegisterX86::RBX),
+    ("rcx", RegisterX86::RCX),
+    ("rdx", RegisterX86::RDX),
+    ("rbp", RegisterX86::RBP),
+    ("rsp", RegisterX86::RSP),
+    ("rsi", RegisterX86::RSI),
+    ("rdi", RegisterX86::RDI),
+    ("r8", RegisterX86::R8),
+    ("r9", RegisterX86::R9),
+    ("r10", RegisterX86::R10),
+    ("r11", RegisterX86::R11),
+    ("r12", RegisterX86::R12),
+    ("r13", RegisterX86::R13),
+    ("r14", RegisterX86::R14),
+    ("r15", RegisterX86::R15),
+    ("rip", RegisterX86::RIP),
+];
+
+const ARM: [(&str, RegisterARM); 16] = [
+    ("r0", RegisterARM::R0),
+    ("r1", RegisterARM::R1),
+    ("r2", RegisterARM::R2),
+    ("r3", RegisterARM::R3),
+    ("r4", RegisterARM::R4),
+    ("r5", RegisterARM::R5),
+    ("r6", RegisterARM::R6),
+    ("r7", RegisterARM::R7),
+    ("r8", RegisterARM::R8),
+    ("r9", RegisterARM::R9),
+    ("r10", RegisterARM::R10),
+    ("r11", RegisterARM::R11),
+    ("r12", RegisterARM::R12),
+    ("sp", RegisterARM::SP),
+    ("lr", RegisterARM::LR),
+    ("pc", RegisterARM::PC),
+];
+
+const ARM64_NAMES: [&str; 29] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28",
+];
+
+/// Return the registers traced for the architecture and mode of `emu`.
+fn registers<D>(emu: &Unicorn<D>) -> Result<Vec<(&'static str, i32)>, uc_error> {
+    let mode = Mode::from_bits_truncate(emu.query(Query::MODE)? as i32);
+    let regs = match emu.get_arch() {
+        Arch::X86 if mode.contains(Mode::MODE_64) => {
+            X86_64.iter().map(|&(n, r)| (n, r.into())).collect()
+        }
+        Arch::X86 if mode.contains(Mode::MODE_32) => {
+            X86_32.iter().map(|&(n, r)| (n, r.into())).collect()
+        }
+        Arch::ARM => ARM.iter().map(|&(n, r)| (n, r.into())).collect(),
+        // X0 to X28 are numbered consecutively, X29 and X30 are not
+        Arch::ARM64 => ARM64_NAMES
+            .iter()
+            .zip(RegisterARM64::X0 as i32..)
+            .map(|(&n, r)| (n, r))
+            .chain([
+                ("x29", RegisterARM64::X29.into()),
+                ("x30", RegisterARM64::X30.into()),
+                ("sp", RegisterARM64::SP.into()),
+                ("pc", RegisterARM64::PC.into()),
+            ])
+            .collect(),
+        _ => return Err(uc_error::ARCH),
+    };
+    Ok(regs)
+}
+
+/// Writes a Tenet trace of an instance into a `TraceSink`.
+#[derive(Debug)]
+pub struct Tracer<S: TraceSink> {
+    sink: S,
+    line: String,
+    buffer: Vec<u8>,
+    registers: Vec<(&'static str, i32)>,
+    /// Values as of the last line, `None` before the first one.
+    values: Vec<Option<u64>>,
+    /// Memory accesses since the last line, already formatted.
+    accesses: String,
+    /// Writes since the last line, their data is read once they are done.
+    writes: Vec<(u64, usize)>,
+    hooks: Vec<ffi::uc_hook>,
+}
+
+impl<S: TraceSink> Tracer<S> {
+    /// Hook `emu` and trace every instruction it executes into `sink`.
+    ///
+    /// Fails with `uc_error::ARCH` for architectures other than x86, ARM and ARM64, and
+    /// for 16-bit x86.
+    pub fn start<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        sink: S,
+    ) -> Result<Rc<RefCell<Tracer<S>>>, uc_error>
+    where
+        S: 'a,
+    {
+        let registers = registers(emu)?;
+        let tracer = Rc::new(RefCell::new(Tracer {
+            sink,
+            line: String::new(),
+            buffer: Vec::with_capacity(DEFAULT_BUFFER_SIZE + 0x1000),
+            values: vec![None; registers.len()],
+            registers,
+            accesses: String::new(),
+            writes: Vec::new(),
+            hooks: Vec::new(),
+        }));
+
+        let mut hooks = Vec::new();
+        if let Err(err) = Tracer::hook(emu, &tracer, &mut hooks) {
+            for hook in hooks {
+                emu.remove_hook(hook)?;
+            }
+            return Err(err);
+        }
+        tracer.borrow_mut().hooks = hooks;
+        Ok(tracer)
+    }
+
+    fn hook<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        tracer: &Rc<RefCell<Tracer<S>>>,
+        hooks: &mut Vec<ffi::uc_hook>,
+    ) -> Result<(), uc_error>
+    where
+        S: 'a,
+    {
+        let t = tracer.clone();
+        hooks.push(emu.add_code_hook(1, 0, move |uc, _, _| {
+            t.borrow_mut().step(uc);
+        })?);
+        let t = tracer.clone();
+        hooks.push(emu.add_mem_hook(
+            HookType::MEM_READ,
+            1,
+            0,
+            move |uc, _, address, size, _| {
+                t.borrow_mut().read(uc, address, size);
+                true
+            },
+        )?);
+        let t = tracer.clone();
+        hooks.push(emu.add_mem_hook(
+            HookType::MEM_WRITE,
+            1,
+            0,
+            move |_, _, address, size, _| {
+                t.borrow_mut().writes.push((address, size));
+                true
+            },
+        )?);
+        Ok(())
+    }
+
+    /// Return the sink, holding everything flushed so far.
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Return the sink mutably.
+    pub fn sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    /// Hand the buffered lines to the sink.
+    pub fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            self.sink.write(&self.buffer);
+            self.buffer.clear();
+        }
+    }
+
+    /// Write the state after the last instruction, stop tracing and flush.
+    pub fn finish<D>(&mut self, emu: &mut Unicorn<D>) -> Result<(), uc_error> {
+        for hook in self.hooks.drain(..) {
+            emu.remove_hook(hook)?;
+        }
+        if self.values.iter().any(Option::is_some) {
+            self.step(emu);
+        }
+        self.flush();
+        Ok(())
+    }
+
+    fn step<D>(&mut self, uc: &Unicorn<D>) {
+        self.line.clear();
+        for ((name, reg), last) in self.registers.iter().zip(self.values.iter_mut()) {
+            let value = uc.reg_read(*reg).unwrap_or(0);
+            if *last != Some(value) {
+                *last = Some(value);
+                let _ = write!(self.line, "{}={:#x},", name, value);
+            }
+        }
+        for (address, size) in core::mem::take(&mut self.writes) {
+            if let Ok(data) = uc.mem_read_as_vec(address, size) {
+                access(&mut self.accesses, "mw", address, &data);
+            }
+        }
+        self.line.push_str(&self.accesses);
+        self.accesses.clear();
+        if self.line.is_empty() {
+            return;
+        }
+        self.line.pop();
+        self.buffer.extend_from_slice(self.line.as_bytes());
+        self.buffer.push(b'\n');
+        if self.buffer.len() >= DEFAULT_BUFFER_SIZE {
+            self.flush();
+        }
+    }
+
+    fn read<D>(&mut self, uc: &Unicorn<D>, address: u64, size: usize) {
+        // the hook runs before the read, memory holds what is about to be read
+        if let Ok(data) = uc.mem_read_as_vec(address, size) {
+            access(&mut self.accesses, "mr", address, &data);
+        }
+    }
+}
+
+impl<S: TraceSink> Drop for Tracer<S> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// Append a memory access `kind=address:data,` to `out`.
+fn access(out: &mut String, kind: &str, address: u64, data: &[u8]) {
+    let _ = write!(out, "{}={:#x}:", kind, address);
+    for byte in data {
+        let _ = write!(out, "{:02x}", byte);
+    }
+    out.push(',');
+}
@@ -0,0 +1,521 @@
+//! Execution trace recorder and reader.
+//!
+//! `Recorder::start` hooks an instance and records every executed block, and optionally
+//! every instruction along with the memory it reads and writes, into a compact binary
+//! log. Records are buffered and handed to a `TraceSink` in chunks, so a trace can be
+//! streamed to a file while emulation runs. `TraceReader` decodes a trace again.
+//!
+//! The log starts with a header, followed by records made of a tag byte and LEB128
+//! varints. Addresses are stored as zigzag encoded deltas to the previous address of
+//! the same kind, which keeps most records at three or four bytes:
+//!
+//! | Record  | Tag    | Fields                                                    |
+//! |---------|--------|-----------------------------------------------------------|
+//! | header  | -      | `b"UCTRACE"`, version `u8`, arch `u8`, mode `u32` LE, flags `u8` |
+//! | block   | `0x01` | delta to the previous block, size                         |
+//! | insn    | `0x02` | delta to the previous instruction or block, size          |
+//! | read    | `0x03` | delta to the previous memory access, size, value          |
+//! | write   | `0x04` | delta to the previous memory access, size, value          |
+//!
+//! ```rust,ignore
+//! let config = TraceConfig { instructions: true, memory: true, ..Default::default() };
+//! let recorder = Recorder::start(&mut emu, config, Vec::new())?;
+//! emu.emu_start(0x1000, 0x2000, 0, 0)?;
+//! recorder.borrow_mut().finish(&mut emu)?;
+//! for event in TraceReader::new(recorder.borrow().sink())? {
+//!     println!("{:x?}", event?);
+//! }
+//! ```
+
+use crate::unicorn_const::{uc_error, Arch, HookType, MemType, Mode, Query};
+use crate::{ffi, Unicorn};
+use alloc::{rc::Rc, vec::Vec};
+use bitflags::bitflags;
+use core::cell::RefCell;
+use core::ops::RangeInclusive;
+
+const MAGIC: &[u8; 7] = b"UCTRACE";
+
+/// Version of the format written by `Recorder`.
+pub const VERSION: u8 = 1;
+
+/// Bytes buffered before they are handed to the sink by default.
+pub const DEFAULT_BUFFER_SIZE: usize = 0x10000;
+
+const TAG_BLOCK: u8 = 0x01;
+const TAG_INSN: u8 = 0x02;
+const TAG_READ: u8 = 0x03;
+const TAG_WRITE: u8 = 0x04;
+
+bitflags! {
+    /// Records present in a trace.
+    pub struct TraceFlags: u8 {
+        const BLOCKS = 1;
+        const INSTRUCTIONS = 2;
+        const MEMORY = 4;
+    }
+}
+
+/// Destination of an encoded trace.
+///
+/// Implemented by `Vec<u8>` to keep the trace in memory, by `IoSink` to stream it to a
+/// file and by closures, e.g. `|data: &[u8]| socket.send(data)`.
+pub trait TraceSink {
+    fn write(&mut self, data: &[u8]);
+}
+
+impl TraceSink for Vec<u8> {
+    fn write(&mut self, data: &[u8]) {
+        self.extend_from_slice(data);
+    }
+}
+
+impl<F: FnMut(&[u8])> TraceSink for F {
+    fn write(&mut self, data: &[u8]) {
+        self(data);
+    }
+}
+
+/// Sink streaming the trace into a `std::io::Write`, e.g. a `File`.
+///
+/// Records are already buffered by the `Recorder`. The first error is kept, see
+/// `IoSink::error`, and everything after it is dropped.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoSink<W: std::io::Write> {
+    writer: W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoSink<W> {
+    pub fn new(writer: W) -> IoSink<W> {
+        IoSink {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Return the first error of the writer, if any.
+    #[must_use]
+    pub fn error(&self) -> Option<&std::io::Error> {
+        self.error.as_ref()
+    }
+
+    /// Flush the writer and return it, or the first error.
+    pub fn into_inner(mut self) -> std::io::Result<W> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.writer.flush().map(|()| self.writer),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> TraceSink for IoSink<W> {
+    fn write(&mut self, data: &[u8]) {
+        if self.error.is_none() {
+            self.error = self.writer.write_all(data).err();
+        }
+    }
+}
+
+/// What `Recorder::start` records.
+#[derive(PartialEq, Debug, Clone)]
+pub struct TraceConfig {
+    /// Code to record, everything if empty. Memory accesses are recorded for the
+    /// instructions in these ranges, wherever they go. With ranges, instructions are
+    /// hooked everywhere to tell where a block leaves them.
+    pub ranges: Vec<RangeInclusive<u64>>,
+    /// Record every instruction, not only blocks.
+    pub instructions: bool,
+    /// Record memory reads and writes with their values. Accesses follow the record of
+    /// the instruction performing them, so this records instructions as well.
+    pub memory: bool,
+    /// Bytes buffered before they are handed to the sink.
+    pub buffer_size: usize,
+}
+
+impl Default for TraceConfig {
+    fn default() -> Self {
+        TraceConfig {
+            ranges: Vec::new(),
+            instructions: false,
+            memory: false,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+}
+
+/// Records a trace of an instance into a `TraceSink`.
+#[derive(Debug)]
+pub struct Recorder<S: TraceSink> {
+    sink: S,
+    buffer: Vec<u8>,
+    buffer_size: usize,
+    ranges: Vec<RangeInclusive<u64>>,
+    /// Whether the code currently executing is recorded.
+    recording: bool,
+    last_block: u64,
+    last_pc: u64,
+    last_mem: u64,
+    hooks: Vec<ffi::uc_hook>,
+}
+
+impl<S: TraceSink> Recorder<S> {
+    /// Hook `emu` and record its execution into `sink` as configured by `config`.
+    pub fn start<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        config: TraceConfig,
+        sink: S,
+    ) -> Result<Rc<RefCell<Recorder<S>>>, uc_error>
+    where
+        S: 'a,
+    {
+        let mut flags = TraceFlags::BLOCKS;
+        if config.instructions || config.memory {
+            flags |= TraceFlags::INSTRUCTIONS;
+        }
+        if config.memory {
+            flags |= TraceFlags::MEMORY;
+        }
+        let mut buffer = Vec::with_capacity(config.buffer_size + 64);
+        buffer.extend_from_slice(MAGIC);
+        buffer.push(VERSION);
+        buffer.push(emu.get_arch() as u8);
+        buffer.extend_from_slice(&(emu.query(Query::MODE)? as u32).to_le_bytes());
+        buffer.push(flags.bits());
+
+        let recorder = Rc::new(RefCell::new(Recorder {
+            sink,
+            buffer,
+            buffer_size: config.buffer_size,
+            ranges: config.ranges,
+            recording: false,
+            last_block: 0,
+            last_pc: 0,
+            last_mem: 0,
+            hooks: Vec::new(),
+        }));
+
+        let mut hooks = Vec::new();
+        if let Err(err) = Recorder::hook(emu, &recorder, flags, &mut hooks) {
+            for hook in hooks {
+                emu.remove_hook(hook)?;
+            }
+            return Err(err);
+        }
+        recorder.borrow_mut().hooks = hooks;
+        Ok(recorder)
+    }
+
+    fn hook<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        recorder: &Rc<RefCell<Recorder<S>>>,
+        flags: TraceFlags,
+        hooks: &mut Vec<ffi::uc_hook>,
+    ) -> Result<(), uc_error>
+    where
+        S: 'a,
+    {
+        let r = recorder.clone();
+        hooks.push(emu.add_block_hook(move |_, address, size| {
+            r.borrow_mut().block(address, size);
+        })?);
+
+        if flags.contains(TraceFlags::INSTRUCTIONS) {
+            // blocks run in and out of the ranges, so every instruction is checked
+            let r = recorder.clone();
+            hooks.push(emu.add_code_hook(1, 0, move |_, address, size| {
+                r.borrow_mut().insn(address, size);
+            })?);
+        }
+
+        if flags.contains(TraceFlags::MEMORY) {
+            for hook_type in [HookType::MEM_READ_AFTER, HookType::MEM_WRITE] {
+                let r = recorder.clone();
+                hooks.push(emu.add_mem_hook(
+                    hook_type,
+                    1,
+                    0,
+                    move |_, access, address, size, value| {
+                        r.borrow_mut().access(access, address, size, value);
+                        true
+                    },
+                )?);
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the sink, holding everything flushed so far.
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Return the sink mutably.
+    pub fn sink_mut(&mut self) -> &mut S {
+        &mut self.sink
+    }
+
+    /// Hand the buffered records to the sink.
+    pub fn flush(&mut self) {
+        if !self.buffer.is_empty() {
+            self.sink.write(&self.buffer);
+            self.buffer.clear();
+        }
+    }
+
+    /// Stop recording and flush the buffered records.
+    pub fn finish<D>(&mut self, emu: &mut Unicorn<D>) -> Result<(), uc_error> {
+        for hook in self.hooks.drain(..) {
+            emu.remove_hook(hook)?;
+        }
+        self.flush();
+        Ok(())
+    }
+
+    fn block(&mut self, address: u64, size: u32) {
+        self.recording = self.in_ranges(address);
+        if !self.recording {
+            return;
+        }
+        self.buffer.push(TAG_BLOCK);
+        put_delta(&mut self.buffer, self.last_block, address);
+        put_varint(&mut self.buffer, u64::from(size));
+        self.last_block = address;
+        self.last_pc = address;
+        self.record_done();
+    }
+
+    fn insn(&mut self, address: u64, size: u32) {
+        // a block may start before a recorded range and run into it, or the other way
+        self.recording = self.in_ranges(address);
+        if !self.recording {
+            return;
+        }
+        self.buffer.push(TAG_INSN);
+        put_delta(&mut self.buffer, self.last_pc, address);
+        put_varint(&mut self.buffer, u64::from(size));
+        self.last_pc = address;
+        self.record_done();
+    }
+
+    fn access(&mut self, access: MemType, address: u64, size: usize, value: i64) {
+        if !self.recording {
+            return;
+        }
+        let tag = if access == MemType::WRITE {
+            TAG_WRITE
+        } else {
+            TAG_READ
+        };
+        let value = if size >= 8 {
+            value as u64
+        } else {
+            value as u64 & ((1 << (size * 8)) - 1)
+        };
+        self.buffer.push(tag);
+        put_delta(&mut self.buffer, self.last_mem, address);
+        put_varint(&mut self.buffer, size as u64);
+        put_varint(&mut self.buffer, value);
+        self.last_mem = address;
+        self.record_done();
+    }
+
+    fn in_ranges(&self, address: u64) -> bool {
+        self.ranges.is_empty() || self.ranges.iter().any(|r| r.contains(&address))
+    }
+
+    fn record_done(&mut self) {
+        if self.buffer.len() >= self.buffer_size {
+            self.flush();
+        }
+    }
+}
+
+impl<S: TraceSink> Drop for Recorder<S> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn put_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+fn put_delta(buffer: &mut Vec<u8>, from: u64, to: u64) {
+    let delta = to.wrapping_sub(from) as i64;
+    put_varint(buffer, ((delta << 1) ^ (delta >> 63)) as u64);
+}
+
+/// Header of a trace.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct TraceHeader {
+    pub version: u8,
+    pub arch: Arch,
+    pub mode: Mode,
+    pub flags: TraceFlags,
+}
+
+/// A decoded record.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TraceEvent {
+    Block {
+        address: u64,
+        size: u32,
+    },
+    Insn {
+        address: u64,
+        size: u32,
+    },
+    Read {
+        address: u64,
+        size: usize,
+        value: u64,
+    },
+    Write {
+        address: u64,
+        size: usize,
+        value: u64,
+    },
+}
+
+/// Malformed trace.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TraceError {
+    /// The header is missing, or written by an unknown version.
+    BadHeader,
+    /// The trace ends in the middle of the record at `offset`.
+    Truncated { offset: usize },
+    /// The record at `offset` has an unknown tag.
+    UnknownRecord { tag: u8, offset: usize },
+}
+
+/// Decodes the records of a trace.
+#[derive(Debug, Clone)]
+pub struct TraceReader<'t> {
+    data: &'t [u8],
+    pos: usize,
+    header: TraceHeader,
+    last_block: u64,
+    last_pc: u64,
+    last_mem: u64,
+}
+
+impl<'t> TraceReader<'t> {
+    /// Parse the header of the trace in `data`.
+    pub fn new(data: &'t [u8]) -> Result<TraceReader<'t>, TraceError> {
+        let header_len = MAGIC.len() + 7;
+        if data.len() < header_len || &data[..MAGIC.len()] != MAGIC {
+            return Err(TraceError::BadHeader);
+        }
+        let h = &data[MAGIC.len()..header_len];
+        if h[0] != VERSION {
+            return Err(TraceError::BadHeader);
+        }
+        let header = TraceHeader {
+            version: h[0],
+            arch: Arch::try_from(h[1] as usize).map_err(|_| TraceError::BadHeader)?,
+            mode: Mode::from_bits_truncate(u32::from_le_bytes(h[2..6].try_into().unwrap()) as i32),
+            flags: TraceFlags::from_bits_truncate(h[6]),
+        };
+        Ok(TraceReader {
+            data,
+            pos: header_len,
+            header,
+            last_block: 0,
+            last_pc: 0,
+            last_mem: 0,
+        })
+    }
+
+    /// Return the header of the trace.
+    #[must_use]
+    pub fn header(&self) -> &TraceHeader {
+        &self.header
+    }
+
+    fn varint(&mut self, offset: usize) -> Result<u64, TraceError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self
+                .data
+                .get(self.pos)
+                .ok_or(TraceError::Truncated { offset })?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(TraceError::Truncated { offset })
+    }
+
+    fn delta(&mut self, from: u64, offset: usize) -> Result<u64, TraceError> {
+        let zigzag = self.varint(offset)?;
+        let delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+        Ok(from.wrapping_add(delta as u64))
+    }
+
+    fn event(&mut self) -> Result<TraceEvent, TraceError> {
+        let offset = self.pos;
+        let tag = self.data[offset];
+        self.pos += 1;
+        match tag {
+            TAG_BLOCK => {
+                let address = self.delta(self.last_block, offset)?;
+                let size = self.varint(offset)? as u32;
+                self.last_block = address;
+                self.last_pc = address;
+                Ok(TraceEvent::Block { address, size })
+            }
+            TAG_INSN => {
+                let address = self.delta(self.last_pc, offset)?;
+                let size = self.varint(offset)? as u32;
+                self.last_pc = address;
+                Ok(TraceEvent::Insn { address, size })
+            }
+            TAG_READ | TAG_WRITE => {
+                let address = self.delta(self.last_mem, offset)?;
+                let size = self.varint(offset)? as usize;
+                let value = self.varint(offset)?;
+                self.last_mem = address;
+                if tag == TAG_READ {
+                    Ok(TraceEvent::Read {
+                        address,
+                        size,
+                        value,
+                    })
+                } else {
+                    Ok(TraceEvent::Write {
+                        address,
+                        size,
+                        value,
+                    })
+                }
+            }
+            _ => Err(TraceError::UnknownRecord { tag, offset }),
+        }
+    }
+}
+
+impl<'t> Iterator for TraceReader<'t> {
+    type Item = Result<TraceEvent, TraceError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let event = self.event();
+        if event.is_err() {
+            // nothing after a broken record can be trusted
+            self.pos = self.data.len();
+        }
+        Some(event)
+    }
+}
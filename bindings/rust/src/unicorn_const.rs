@@ -84,6 +84,8 @@ bitflags! {
 
         const INSN_INVALID = 0x4000;
 
+        const TCG_OPCODE = 0x10000;
+
         const MEM_READ_INVALID = Self::MEM_READ_UNMAPPED.bits | Self::MEM_READ_PROT.bits;
         const MEM_WRITE_INVALID = Self::MEM_WRITE_UNMAPPED.bits | Self::MEM_WRITE_PROT.bits;
         const MEM_FETCH_INVALID = Self::MEM_FETCH_UNMAPPED.bits | Self::MEM_FETCH_PROT.bits;
@@ -101,6 +103,164 @@ pub enum Query {
     PAGE_SIZE = 2,
     ARCH = 3,
     TIMEOUT = 4,
+    EXIT_REASON = 5,
+    FAULT_ADDR = 6,
+    INSN_LIMIT = 7,
+    INSN_COUNT = 8,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ExitReason {
+    NONE = 0,
+    UNTIL = 1,
+    EXITS = 2,
+    COUNT = 3,
+    TIMEOUT = 4,
+    STOP = 5,
+    FAULT = 6,
+}
+
+impl TryFrom<usize> for ExitReason {
+    type Error = uc_error;
+
+    fn try_from(v: usize) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::NONE as usize => Ok(Self::NONE),
+            x if x == Self::UNTIL as usize => Ok(Self::UNTIL),
+            x if x == Self::EXITS as usize => Ok(Self::EXITS),
+            x if x == Self::COUNT as usize => Ok(Self::COUNT),
+            x if x == Self::TIMEOUT as usize => Ok(Self::TIMEOUT),
+            x if x == Self::STOP as usize => Ok(Self::STOP),
+            x if x == Self::FAULT as usize => Ok(Self::FAULT),
+            _ => Err(uc_error::ARG),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum ControlType {
+    UC_MODE = 0,
+    UC_PAGE_SIZE = 1,
+    UC_ARCH = 2,
+    UC_TIMEOUT = 3,
+    UC_USE_EXITS = 4,
+    UC_EXITS_CNT = 5,
+    UC_EXITS = 6,
+    CPU_MODEL = 7,
+    TB_REQUEST_CACHE = 8,
+    TB_REMOVE_CACHE = 9,
+    TB_FLUSH = 10,
+    UC_TIMEOUT_MODE = 11,
+    UC_COUNT_MODE = 12,
+    UC_COVERAGE = 13,
+    UC_DETERMINISTIC = 14,
+    UC_VIRTUAL_CLOCK = 15,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TimeoutMode {
+    /// Spawn a timer thread for every `emu_start` with a timeout (default).
+    THREAD = 0,
+    /// Check the deadline at the start of translation blocks without spawning a thread.
+    INLINE = 1,
+}
+
+#[repr(C)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum CountMode {
+    /// Count every instruction with an internal code hook (default).
+    INSN = 0,
+    /// Charge whole blocks at their start, stopping at the first block boundary once
+    /// the budget is used up.
+    BLOCK = 1,
+}
+
+/// Configuration of deterministic emulation, see `Unicorn::ctl_set_deterministic`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Deterministic {
+    /// Nanoseconds the virtual clock advances per executed instruction.
+    pub ns_per_insn: u64,
+}
+
+impl Default for Deterministic {
+    fn default() -> Self {
+        Deterministic { ns_per_insn: 1 }
+    }
+}
+
+impl TryFrom<i32> for CountMode {
+    type Error = uc_error;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::INSN as i32 => Ok(Self::INSN),
+            x if x == Self::BLOCK as i32 => Ok(Self::BLOCK),
+            _ => Err(uc_error::ARG),
+        }
+    }
+}
+
+impl TryFrom<i32> for TimeoutMode {
+    type Error = uc_error;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == Self::THREAD as i32 => Ok(Self::THREAD),
+            x if x == Self::INLINE as i32 => Ok(Self::INLINE),
+            _ => Err(uc_error::ARG),
+        }
+    }
+}
+
+/// Build a `uc_ctl` control word from its type, number of arguments and
+/// direction (`CTL_IO_*`).
+#[must_use]
+pub const fn ctl(control: ControlType, nr: u32, rw: u32) -> u32 {
+    (control as u32) | (nr << 26) | (rw << 30)
+}
+
+pub const CTL_IO_NONE: u32 = 0;
+pub const CTL_IO_WRITE: u32 = 1;
+pub const CTL_IO_READ: u32 = 2;
+pub const CTL_IO_READ_WRITE: u32 = CTL_IO_WRITE | CTL_IO_READ;
+
+bitflags! {
+    #[repr(C)]
+    pub struct CoverageFlags: i32 {
+        const NONE = 0;
+        /// Classify hit counts into AFL's buckets after every `emu_start`. The map is
+        /// cleared when `emu_start` begins, so it holds the last run only.
+        const BUCKETS = 1;
+        /// Skip zero when a hit count wraps around.
+        const NEVER_ZERO = 2;
+    }
+}
+
+/// TCG opcodes that can be traced with `Unicorn::add_tcg_hook`.
+#[repr(C)]
+#[derive(PartialEq, Debug, Clone, Copy)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum TcgOpCode {
+    /// Both `sub_i32` and `sub_i64`.
+    SUB = 0,
+}
+
+bitflags! {
+    #[repr(C)]
+    pub struct TcgOpFlag: u32 {
+        const NONE = 0;
+        /// Only trace opcodes generated for compare instructions, e.g. x86 `CMP`.
+        const CMP = 1;
+        /// Only trace opcodes translated directly from a guest instruction, e.g. x86 `SUB`.
+        const DIRECT = 2;
+    }
 }
 
 bitflags! {
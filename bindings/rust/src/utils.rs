@@ -0,0 +1,417 @@
+//! Guest heap sanitizer.
+//!
+//! `Heap::install` hooks the entry points of the target's `malloc`, `free`, `realloc`
+//! and `calloc` and serves their allocations from an arena mapped by the sanitizer.
+//! Every chunk is surrounded by redzones and freed chunks are never handed out again,
+//! so a memory hook on the arena can tell out-of-bounds accesses and use-after-free
+//! apart from valid ones. Double and invalid frees are caught in the `free` hook.
+//!
+//! The first error stops emulation and is reported through `Unicorn::set_crash_pc`,
+//! the details are kept in `Heap::error`. A double or invalid free additionally makes
+//! `emu_start` return `DOUBLE_FREE` or `OOB_FREE`. Should a hook fail itself, e.g.
+//! to read the arguments of a call, `emu_start` returns that error.
+//!
+//! `Heap::install_in` maps the arena through a `VirtualMemory`, where it shows up as
+//! `[heap]`.
+
+use crate::memory::{Origin, VirtualMemory};
+use crate::unicorn_const::{uc_error, HookType, MemType, Permission};
+use crate::Unicorn;
+use alloc::{collections::BTreeMap, rc::Rc};
+use core::cell::RefCell;
+
+/// Bytes of redzone in front of and behind every chunk.
+pub const REDZONE: u64 = 0x10;
+
+/// Alignment of the chunks handed out to the target.
+pub const ALIGNMENT: u64 = 0x10;
+
+/// Addresses of the allocator functions of the target.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct HeapSymbols {
+    pub malloc: u64,
+    pub free: u64,
+    pub realloc: Option<u64>,
+    pub calloc: Option<u64>,
+}
+
+/// A chunk handed out to the target.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Chunk {
+    /// Address returned to the target.
+    pub address: u64,
+    /// Size requested by the target.
+    pub size: u64,
+    /// Return address of the allocating call.
+    pub alloc_site: u64,
+    /// Return address of the call freeing the chunk.
+    pub free_site: Option<u64>,
+}
+
+/// Kind of heap misuse detected by the sanitizer.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum HeapErrorKind {
+    /// Access to a redzone or to arena memory that was never handed out.
+    OutOfBounds { access: MemType },
+    /// Access to a chunk that was already freed.
+    UseAfterFree { access: MemType },
+    /// A chunk was freed twice.
+    DoubleFree,
+    /// A pointer that was never returned by the allocator was freed.
+    InvalidFree,
+}
+
+/// Heap misuse detected by the sanitizer.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct HeapError {
+    pub kind: HeapErrorKind,
+    /// PC of the access, or return address of the `free`/`realloc` call.
+    pub pc: u64,
+    /// Address accessed or freed.
+    pub address: u64,
+    /// Chunk closest to `address`, with its allocation and free sites.
+    pub chunk: Option<Chunk>,
+}
+
+impl HeapError {
+    /// Map the error onto the `uc_error` variants reserved for the heap.
+    #[must_use]
+    pub fn error(&self) -> uc_error {
+        match self.kind {
+            HeapErrorKind::OutOfBounds { access } | HeapErrorKind::UseAfterFree { access } => {
+                match access {
+                    MemType::WRITE => uc_error::WRITE_PROT,
+                    _ => uc_error::READ_PROT,
+                }
+            }
+            HeapErrorKind::DoubleFree => uc_error::DOUBLE_FREE,
+            HeapErrorKind::InvalidFree => uc_error::OOB_FREE,
+        }
+    }
+}
+
+/// State of the guest heap.
+#[derive(Debug)]
+pub struct Heap {
+    base: u64,
+    size: u64,
+    cursor: u64,
+    chunks: BTreeMap<u64, Chunk>,
+    error: Option<HeapError>,
+}
+
+impl Heap {
+    /// Map an arena of `size` bytes at `base` and hook the allocator functions in
+    /// `symbols` to serve allocations from it.
+    pub fn install<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        base: u64,
+        size: u64,
+        symbols: &HeapSymbols,
+    ) -> Result<Rc<RefCell<Heap>>, uc_error> {
+        emu.mem_map(base, size as usize, Permission::READ | Permission::WRITE)?;
+        let heap = Rc::new(RefCell::new(Heap::new(base, size)));
+        Heap::hook(emu, &heap, symbols)?;
+        Ok(heap)
+    }
+
+    /// Like `Heap::install`, but map the arena through `memory`.
+    pub fn install_in<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        memory: &mut VirtualMemory,
+        base: u64,
+        size: u64,
+        symbols: &HeapSymbols,
+    ) -> Result<Rc<RefCell<Heap>>, uc_error> {
+        let perms = Permission::READ | Permission::WRITE;
+        memory.sync(emu)?;
+        memory.map_fixed(emu, base, size, perms, Origin::Heap, false)?;
+        let heap = Rc::new(RefCell::new(Heap::new(base, size)));
+        Heap::hook(emu, &heap, symbols)?;
+        Ok(heap)
+    }
+
+    fn new(base: u64, size: u64) -> Heap {
+        Heap {
+            base,
+            size,
+            cursor: base + REDZONE,
+            chunks: BTreeMap::new(),
+            error: None,
+        }
+    }
+
+    fn hook<'a, D: 'a>(
+        emu: &mut Unicorn<'a, D>,
+        heap: &Rc<RefCell<Heap>>,
+        symbols: &HeapSymbols,
+    ) -> Result<(), uc_error> {
+        let (base, size) = {
+            let heap = heap.borrow();
+            (heap.base, heap.size)
+        };
+
+        let h = heap.clone();
+        emu.add_code_hook(symbols.malloc, symbols.malloc, move |uc, _, _| {
+            let result = (|| {
+                let size = uc.function_arg0_val()?;
+                let site = uc.func_return_addr()?;
+                let ptr = h.borrow_mut().alloc(size, site);
+                poison(uc, ptr, size);
+                return_from_call(uc, ptr)
+            })();
+            stop_on_error(uc, result);
+        })?;
+
+        let h = heap.clone();
+        emu.add_code_hook(symbols.free, symbols.free, move |uc, _, _| {
+            let result = (|| {
+                let ptr = uc.function_arg0_val()?;
+                let site = uc.func_return_addr()?;
+                match h.borrow_mut().free(ptr, site) {
+                    Ok(()) => return_from_call(uc, 0),
+                    Err(err) => report_free(uc, site, err),
+                }
+            })();
+            stop_on_error(uc, result);
+        })?;
+
+        if let Some(realloc) = symbols.realloc {
+            let h = heap.clone();
+            emu.add_code_hook(realloc, realloc, move |uc, _, _| {
+                let result = (|| {
+                    let ptr = uc.function_arg0_val()?;
+                    let size = uc.function_arg1_val()?;
+                    let site = uc.func_return_addr()?;
+                    let mut heap = h.borrow_mut();
+                    if ptr == 0 {
+                        let new = heap.alloc(size, site);
+                        drop(heap);
+                        poison(uc, new, size);
+                        return return_from_call(uc, new);
+                    }
+                    let old = heap.chunks.get(&ptr).copied();
+                    if let Err(err) = heap.free(ptr, site) {
+                        drop(heap);
+                        return report_free(uc, site, err);
+                    }
+                    let new = if size == 0 { 0 } else { heap.alloc(size, site) };
+                    drop(heap);
+                    poison(uc, new, size);
+                    if let (Some(old), true) = (old, new != 0) {
+                        let len = old.size.min(size);
+                        let data = uc.mem_read_as_vec(old.address, len as usize)?;
+                        uc.mem_write(new, &data)?;
+                        // the copy must not make uninitialized bytes of the old chunk look
+                        // initialized
+                        if let Some(shadow) = uc.shadow_mut() {
+                            shadow.copy(old.address, new, len);
+                        }
+                    }
+                    return_from_call(uc, new)
+                })();
+                stop_on_error(uc, result);
+            })?;
+        }
+
+        if let Some(calloc) = symbols.calloc {
+            let h = heap.clone();
+            emu.add_code_hook(calloc, calloc, move |uc, _, _| {
+                let result = (|| {
+                    let count = uc.function_arg0_val()?;
+                    let size = uc.function_arg1_val()?;
+                    let site = uc.func_return_addr()?;
+                    let ptr = match count.checked_mul(size) {
+                        Some(total) => h.borrow_mut().alloc(total, site),
+                        None => 0,
+                    };
+                    if ptr != 0 {
+                        uc.mem_write(ptr, &vec![0; (count * size) as usize])?;
+                    }
+                    return_from_call(uc, ptr)
+                })();
+                stop_on_error(uc, result);
+            })?;
+        }
+
+        let h = heap.clone();
+        emu.add_mem_hook(
+            HookType::MEM_READ | HookType::MEM_WRITE,
+            base,
+            base + size - 1,
+            move |uc, access, address, size, _| {
+                let pc = match uc.get_pc() {
+                    Ok(pc) => pc,
+                    Err(err) => {
+                        stop_on_error(uc, Err(err));
+                        return true;
+                    }
+                };
+                let error = h.borrow_mut().check_access(pc, access, address, size);
+                if let Some(pc) = error {
+                    uc.set_crash_pc(pc);
+                    let _ = uc.emu_stop();
+                }
+                true
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Return the first error detected since the last reset.
+    #[must_use]
+    pub fn error(&self) -> Option<HeapError> {
+        self.error
+    }
+
+    /// Return the chunk starting at `address`, freed or not.
+    #[must_use]
+    pub fn chunk(&self, address: u64) -> Option<Chunk> {
+        self.chunks.get(&address).copied()
+    }
+
+    /// Return the chunks that were allocated but not freed yet.
+    pub fn live_chunks(&self) -> impl Iterator<Item = &Chunk> {
+        self.chunks.values().filter(|c| c.free_site.is_none())
+    }
+
+    /// Forget all chunks and errors, e.g. between two fuzzing runs.
+    pub fn reset(&mut self) {
+        self.cursor = self.base + REDZONE;
+        self.chunks.clear();
+        self.error = None;
+    }
+
+    /// Carve a chunk of `size` bytes out of the arena, returns 0 once it is exhausted.
+    fn alloc(&mut self, size: u64, site: u64) -> u64 {
+        let address = self.cursor;
+        let end = match address
+            .checked_add(size)
+            .and_then(|end| end.checked_add(ALIGNMENT - 1))
+        {
+            Some(end) => (end & !(ALIGNMENT - 1)) + REDZONE,
+            None => return 0,
+        };
+        if end > self.base + self.size {
+            return 0;
+        }
+        self.cursor = end;
+        self.chunks.insert(
+            address,
+            Chunk {
+                address,
+                size,
+                alloc_site: site,
+                free_site: None,
+            },
+        );
+        address
+    }
+
+    /// Free the chunk at `address`, returns the error to stop with if the free was bogus.
+    fn free(&mut self, address: u64, site: u64) -> Result<(), uc_error> {
+        if address == 0 {
+            return Ok(());
+        }
+        let kind = match self.chunks.get_mut(&address) {
+            Some(chunk) if chunk.free_site.is_none() => {
+                chunk.free_site = Some(site);
+                return Ok(());
+            }
+            Some(_) => HeapErrorKind::DoubleFree,
+            None => HeapErrorKind::InvalidFree,
+        };
+        self.record(kind, site, address);
+        Err(match kind {
+            HeapErrorKind::DoubleFree => uc_error::DOUBLE_FREE,
+            _ => uc_error::OOB_FREE,
+        })
+    }
+
+    /// Check an access of the target to the arena, returns the pc to report on misuse.
+    fn check_access(&mut self, pc: u64, access: MemType, address: u64, size: usize) -> Option<u64> {
+        // only the first error is of interest, everything after may be collateral
+        if self.error.is_some() {
+            return None;
+        }
+        let end = address + size as u64;
+        let kind = match self.chunk_before(address) {
+            Some(c) if end <= c.address + c.size && c.free_site.is_none() => return None,
+            Some(c) if address < c.address + c.size && c.free_site.is_some() => {
+                HeapErrorKind::UseAfterFree { access }
+            }
+            _ => HeapErrorKind::OutOfBounds { access },
+        };
+        self.record(kind, pc, address);
+        Some(pc)
+    }
+
+    fn record(&mut self, kind: HeapErrorKind, pc: u64, address: u64) {
+        if self.error.is_some() {
+            return;
+        }
+        // an overflow is closer to the chunk in front, an underflow to the one behind
+        let chunk = match self.chunk_before(address) {
+            Some(c) if address < c.address + c.size + REDZONE => Some(c),
+            _ => self
+                .chunks
+                .range(address..)
+                .next()
+                .map(|(_, c)| *c)
+                .or_else(|| self.chunk_before(address)),
+        };
+        self.error = Some(HeapError {
+            kind,
+            pc,
+            address,
+            chunk,
+        });
+    }
+
+    fn chunk_before(&self, address: u64) -> Option<Chunk> {
+        self.chunks.range(..=address).next_back().map(|(_, c)| *c)
+    }
+}
+
+/// Create an instance whose data is a guest heap, see `Heap::install`.
+pub fn init_emu_with_heap<'a>(
+    arch: crate::unicorn_const::Arch,
+    mode: crate::unicorn_const::Mode,
+    base: u64,
+    size: u64,
+    symbols: &HeapSymbols,
+) -> Result<Unicorn<'a, Rc<RefCell<Heap>>>, uc_error> {
+    let heap = Rc::new(RefCell::new(Heap::new(base, size)));
+    let mut emu = Unicorn::new_with_data(arch, mode, heap.clone())?;
+    emu.mem_map(base, size as usize, Permission::READ | Permission::WRITE)?;
+    Heap::hook(&mut emu, &heap, symbols)?;
+    Ok(emu)
+}
+
+/// Return `value` from the hooked call, skipping the target's implementation.
+fn return_from_call<D>(uc: &mut Unicorn<D>, value: u64) -> Result<(), uc_error> {
+    let reg = uc.function_return_reg()?;
+    uc.reg_write(reg, value)?;
+    uc.simulate_return()
+}
+
+/// Mark a fresh chunk as uninitialized if the shadow memory is enabled.
+fn poison<D>(uc: &mut Unicorn<D>, ptr: u64, size: u64) {
+    if let (Some(shadow), true) = (uc.shadow_mut(), ptr != 0) {
+        shadow.poison(ptr, size);
+    }
+}
+
+/// Report a bogus free at `site` and have `emu_start` return `err`.
+fn report_free<D>(uc: &mut Unicorn<D>, site: u64, err: uc_error) -> Result<(), uc_error> {
+    uc.set_crash_pc(site);
+    uc.emu_stop_with_error(err)
+}
+
+/// Stop the emulation if a hook failed, errors must not unwind through the FFI boundary.
+fn stop_on_error<D>(uc: &mut Unicorn<D>, result: Result<(), uc_error>) {
+    if let Err(err) = result {
+        let _ = uc.emu_stop_with_error(err);
+    }
+}
@@ -345,8 +345,18 @@ struct uc_struct {
     // hook to count number of instructions for uc_emu_start()
     uc_hook count_hook;
 
+    // hook to check the deadline of uc_emu_start() in UC_TIMEOUT_MODE_INLINE
+    uc_hook deadline_hook;
+    uc_timeout_mode timeout_mode; // how the timeout of uc_emu_start() is
+                                  // enforced
+    int64_t deadline;             // host clock at which emulation times out
+    uint32_t deadline_ticks;      // blocks since the deadline was checked
+
     size_t emu_counter; // current counter of uc_emu_start()
     size_t emu_count;   // save counter of uc_emu_start()
+    uc_count_mode count_mode; // how the counter of uc_emu_start() is kept
+    bool block_count; // translated blocks charge the counter themselves
+                      // (UC_COUNT_MODE_BLOCK)
 
     int size_recur_mem; // size for mem access when in a recursive call
 
@@ -358,9 +368,27 @@ struct uc_struct {
     bool emulation_done; // emulation is done by uc_emu_start()
     bool timed_out;      // emulation timed out, that can retrieve via
                          // uc_query(UC_QUERY_TIMEOUT)
+    bool insn_limit;     // emulation ran out of instructions, that can
+                         // retrieve via uc_query(UC_QUERY_INSN_LIMIT)
     QemuThread timer;    // timer for emulation timeout
+    uc_exit_reason exit_reason; // why uc_emu_start() returned, that can
+                                // retrieve via uc_query(UC_QUERY_EXIT_REASON)
     uint64_t timeout;    // timeout for uc_emu_start()
 
+    uint8_t *cov_map;      // edge coverage map, see UC_CTL_UC_COVERAGE
+    uint64_t cov_mask;     // size of cov_map - 1
+    uint64_t cov_begin;    // only blocks in [cov_begin, cov_end] are recorded
+    uint64_t cov_end;
+    uint64_t cov_prev_loc; // location of the previous block, already shifted
+    int cov_flags;         // uc_cov_flag
+
+    bool deterministic;    // time follows the virtual clock, see
+                           // UC_CTL_UC_DETERMINISTIC
+    uint64_t vclock;       // instructions counted by the virtual clock
+    uint64_t vclock_ns;    // nanoseconds per instruction of the virtual clock
+    uint64_t vclock_limit; // virtual clock at which emulation times out, 0 if
+                           // there is no timeout
+
     uint64_t invalid_addr; // invalid address to be accessed
     int invalid_error;     // invalid memory code: 1 = READ, 2 = WRITE, 3 = CODE
 
@@ -430,6 +458,13 @@ static inline int uc_addr_is_exit(uc_engine *uc, uint64_t addr)
     }
 }
 
+// Location of the block at @pc in the coverage map, spreads nearby blocks
+// over the map like AFL's QEMU mode does.
+static inline uint64_t uc_cov_loc(uc_engine *uc, uint64_t pc)
+{
+    return ((pc >> 4) ^ (pc << 8)) & uc->cov_mask;
+}
+
 typedef struct HookedRegion {
     uint64_t start;
     uint64_t length;
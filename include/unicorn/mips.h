@@ -128,7 +128,7 @@ typedef enum UC_MIPS_REG {
     UC_MIPS_REG_CC6,
     UC_MIPS_REG_CC7,
 
-    //> FPU registers
+    //> FPU registers, 64 bits wide
     UC_MIPS_REG_F0,
     UC_MIPS_REG_F1,
     UC_MIPS_REG_F2,
@@ -220,6 +220,9 @@ typedef enum UC_MIPS_REG {
     UC_MIPS_REG_CP0_USERLOCAL,
     UC_MIPS_REG_CP0_STATUS,
 
+    //> FPU control and status register
+    UC_MIPS_REG_FCR31,
+
     UC_MIPS_REG_ENDING, // <-- mark the end of the list or registers
 
     // alias registers
@@ -456,9 +456,30 @@ typedef enum uc_query_type {
     UC_QUERY_PAGE_SIZE, // query pagesize of engine
     UC_QUERY_ARCH, // query architecture of engine (for ARM to query Thumb mode)
     UC_QUERY_TIMEOUT, // query if emulation stops due to timeout (indicated if
-                      // result = True)
+                      // result = True), not set if @count ran out
+    UC_QUERY_EXIT_REASON, // query why the last uc_emu_start() returned (see
+                          // uc_exit_reason)
+    UC_QUERY_FAULT_ADDR,  // query the faulting address if the last
+                          // uc_emu_start() returned with UC_EXIT_FAULT
+    UC_QUERY_INSN_LIMIT,  // query if emulation stops because the @count of
+                          // uc_emu_start() ran out (indicated if result = True)
+    UC_QUERY_INSN_COUNT,  // query the number of instructions executed by the
+                          // last uc_emu_start(), only kept if @count is set
 } uc_query_type;
 
+// Reason why uc_emu_start() returned, retrieved via
+// uc_query(UC_QUERY_EXIT_REASON)
+typedef enum uc_exit_reason {
+    UC_EXIT_NONE = 0, // the guest halted on its own (e.g. x86 hlt)
+    UC_EXIT_UNTIL,    // the @until address of uc_emu_start() was reached
+    UC_EXIT_EXITS,    // one of the exits set via uc_ctl_set_exits() was reached
+    UC_EXIT_COUNT,    // the instruction @count of uc_emu_start() was exhausted
+    UC_EXIT_TIMEOUT,  // the @timeout of uc_emu_start() elapsed
+    UC_EXIT_STOP,     // uc_emu_stop() was called, usually from a hook
+    UC_EXIT_FAULT,    // an error stopped the emulation, uc_emu_start() returns
+                      // it and uc_query(UC_QUERY_FAULT_ADDR) has the address
+} uc_exit_reason;
+
 // The implementation of uc_ctl is like what Linux ioctl does but slightly
 // different.
 //
@@ -537,10 +558,70 @@ typedef enum uc_control_type {
     UC_CTL_TB_REMOVE_CACHE,
     // Invalidate all translation blocks.
     // No arguments.
-    UC_CTL_TB_FLUSH
+    UC_CTL_TB_FLUSH,
+    // How the @timeout of uc_emu_start() is enforced, see uc_timeout_mode.
+    // Write: @args = (int)
+    // Read: @args = (int*)
+    UC_CTL_UC_TIMEOUT_MODE,
+    // How the @count of uc_emu_start() is enforced, see uc_count_mode.
+    // Write: @args = (int)
+    // Read: @args = (int*)
+    UC_CTL_UC_COUNT_MODE,
+    // Record AFL-style edge coverage of the blocks in [@begin, @end] into @map
+    // from the generated code. @size must be a power of two, a NULL @map
+    // disables recording.
+    // Write: @args = (uint8_t *map, size_t size, uint64_t begin, uint64_t end,
+    //                 int flags)
+    UC_CTL_UC_COVERAGE,
+    // Run deterministically: guest time sources (TSC, timers, cycle counters)
+    // follow a virtual clock that advances by the instructions executed, one
+    // tick and @ns_per_insn nanoseconds each, and the @timeout of
+    // uc_emu_start() is measured on that clock instead of the host's.
+    // Write: @args = (int enable, uint64_t ns_per_insn)
+    // Read: @args = (int *enable)
+    UC_CTL_UC_DETERMINISTIC,
+    // Instructions counted by the virtual clock of UC_CTL_UC_DETERMINISTIC,
+    // e.g. to rewind it along with a snapshot.
+    // Write: @args = (uint64_t)
+    // Read: @args = (uint64_t *)
+    UC_CTL_UC_VIRTUAL_CLOCK
 
 } uc_control_type;
 
+// Flags for UC_CTL_UC_COVERAGE
+typedef enum uc_cov_flag {
+    // Classify the hit counts of the map into AFL's buckets
+    // (1, 2, 3, 4-7, 8-15, 16-31, 32-127, 128+) after every uc_emu_start().
+    // The map is cleared when uc_emu_start() begins, so it always holds the
+    // classified coverage of the last run only.
+    UC_COV_BUCKETS = 1 << 0,
+    // Skip zero when a hit count wraps around, so a hot edge never looks
+    // unvisited.
+    UC_COV_NEVER_ZERO = 1 << 1,
+} uc_cov_flag;
+
+// How the @timeout of uc_emu_start() is enforced, set via
+// uc_ctl_set_timeout_mode()
+typedef enum uc_timeout_mode {
+    // Spawn a timer thread for every uc_emu_start() with a timeout (default).
+    UC_TIMEOUT_MODE_THREAD = 0,
+    // Check the deadline at the start of translation blocks, no thread is
+    // spawned. Cheaper for many short emulations, but a single block that
+    // never ends (e.g. a blocking helper) can't be interrupted.
+    UC_TIMEOUT_MODE_INLINE,
+} uc_timeout_mode;
+
+// How the @count of uc_emu_start() is enforced, set via
+// uc_ctl_set_count_mode()
+typedef enum uc_count_mode {
+    // Count every instruction with an internal UC_HOOK_CODE (default).
+    UC_COUNT_MODE_INSN = 0,
+    // Charge whole blocks at their start without a code hook. Much cheaper,
+    // but emulation only stops at the first block boundary after @count
+    // instructions, so a few more instructions may be executed.
+    UC_COUNT_MODE_BLOCK,
+} uc_count_mode;
+
 /*
 
 Exits Mechanism
@@ -613,6 +694,26 @@ See sample_ctl.c for a detailed example.
 #define uc_ctl_request_cache(uc, address, tb)                                  \
     uc_ctl(uc, UC_CTL_READ_WRITE(UC_CTL_TB_REQUEST_CACHE, 2), (address), (tb))
 #define uc_ctl_flush_tlb(uc) uc_ctl(uc, UC_CTL_WRITE(UC_CTL_TB_FLUSH, 0))
+#define uc_ctl_get_timeout_mode(uc, mode)                                      \
+    uc_ctl(uc, UC_CTL_READ(UC_CTL_UC_TIMEOUT_MODE, 1), (mode))
+#define uc_ctl_set_timeout_mode(uc, mode)                                      \
+    uc_ctl(uc, UC_CTL_WRITE(UC_CTL_UC_TIMEOUT_MODE, 1), (mode))
+#define uc_ctl_get_count_mode(uc, mode)                                        \
+    uc_ctl(uc, UC_CTL_READ(UC_CTL_UC_COUNT_MODE, 1), (mode))
+#define uc_ctl_set_count_mode(uc, mode)                                        \
+    uc_ctl(uc, UC_CTL_WRITE(UC_CTL_UC_COUNT_MODE, 1), (mode))
+#define uc_ctl_set_coverage(uc, map, size, begin, end, flags)                  \
+    uc_ctl(uc, UC_CTL_WRITE(UC_CTL_UC_COVERAGE, 5), (map), (size), (begin),     \
+           (end), (flags))
+#define uc_ctl_get_deterministic(uc, enable)                                   \
+    uc_ctl(uc, UC_CTL_READ(UC_CTL_UC_DETERMINISTIC, 1), (enable))
+#define uc_ctl_set_deterministic(uc, enable, ns_per_insn)                      \
+    uc_ctl(uc, UC_CTL_WRITE(UC_CTL_UC_DETERMINISTIC, 2), (enable),             \
+           (ns_per_insn))
+#define uc_ctl_get_virtual_clock(uc, insns)                                    \
+    uc_ctl(uc, UC_CTL_READ(UC_CTL_UC_VIRTUAL_CLOCK, 1), (insns))
+#define uc_ctl_set_virtual_clock(uc, insns)                                    \
+    uc_ctl(uc, UC_CTL_WRITE(UC_CTL_UC_VIRTUAL_CLOCK, 1), (insns))
 // Opaque storage for CPU context, used with uc_context_*()
 struct uc_context;
 typedef struct uc_context uc_context;
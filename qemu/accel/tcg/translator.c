@@ -39,7 +39,9 @@ void translator_loop(const TranslatorOps *ops, DisasContextBase *db,
     struct uc_struct *uc = (struct uc_struct *)cpu->uc;
     TCGContext *tcg_ctx = uc->tcg_ctx;
     TCGOp *prev_op = NULL;
+    TCGOp *budget_prev_op = NULL;
     bool block_hook = false;
+    bool insn_budget = false;
 
     /* Initialize DisasContext */
     db->tb = tb;
@@ -79,6 +81,20 @@ void translator_loop(const TranslatorOps *ops, DisasContextBase *db,
         gen_uc_tracecode(tcg_ctx, 0xf8f8f8f8, UC_HOOK_BLOCK_IDX, uc, db->pc_first);
     }
 
+    /* Unicorn: record the edge into this block in the coverage map */
+    if (uc->cov_map && tb->pc >= uc->cov_begin && tb->pc <= uc->cov_end) {
+        gen_uc_edge_cov(tcg_ctx, uc, uc_cov_loc(uc, tb->pc));
+    }
+
+    /* Unicorn: charge the instructions of this block to the budget of
+     * uc_emu_start() in UC_COUNT_MODE_BLOCK and to the virtual clock of
+     * UC_CTL_UC_DETERMINISTIC */
+    if (uc->block_count || uc->deterministic) {
+        budget_prev_op = tcg_last_op(tcg_ctx);
+        insn_budget = true;
+        gen_uc_insn_budget(tcg_ctx, 0xf8f8f8f8, uc);
+    }
+
     // tcg_dump_ops(tcg_ctx, false, "translator loop");
 
     /* Start translating.  */
@@ -167,4 +183,18 @@ _end_loop:
 
         tcg_op->args[1] = db->tb->size;
     }
+
+    if (insn_budget) {
+        TCGOp *tcg_op;
+
+        // Unicorn: patch the budget charge to the number of instructions,
+        // same as the block size above.
+        if (budget_prev_op) {
+            tcg_op = QTAILQ_NEXT(budget_prev_op, link);
+        } else {
+            tcg_op = QTAILQ_FIRST(&tcg_ctx->ops);
+        }
+
+        tcg_op->args[1] = db->num_insns;
+    }
 }
@@ -25,6 +25,7 @@
 /* Modified for Unicorn Engine by Chen Huitao<chenhuitao@hfmrit.com>, 2020 */
 
 #include "qemu/compiler.h"
+#include "qemu/timer.h"
 #include "sysemu/sysemu.h"
 #include "target/i386/cpu.h"
 
@@ -32,6 +33,6 @@
 /* TSC handling */
 uint64_t cpu_get_tsc(CPUX86State *env)
 {
-    return cpu_get_ticks();
+    return uc_get_ticks(env->uc);
 }
 
@@ -145,7 +145,7 @@ static void booke_update_fixed_timer(CPUPPCState         *env,
         return;
     }
 
-    now = qemu_clock_get_ns(QEMU_CLOCK_VIRTUAL);
+    now = uc_clock_get_ns(env->uc);
     tb  = cpu_ppc_get_tb(tb_env, now, tb_env->tb_offset);
     period = 1ULL << target_bit;
     delta_tick = period - (tb & (period - 1));
@@ -1005,4 +1005,9 @@ static inline int64_t cpu_get_host_ticks(void)
 
 void init_get_clock(void);
 
+/* Unicorn: guest time sources, virtual in UC_CTL_UC_DETERMINISTIC */
+struct uc_struct;
+int64_t uc_clock_get_ns(struct uc_struct *uc);
+int64_t uc_get_ticks(struct uc_struct *uc);
+
 #endif
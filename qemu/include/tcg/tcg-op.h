@@ -70,6 +70,29 @@ static inline void gen_uc_tracecode(TCGContext *tcg_ctx, int32_t size, int32_t t
     tcg_temp_free_i32(tcg_ctx, tsize);
 }
 
+static inline void gen_uc_insn_budget(TCGContext *tcg_ctx, uint32_t icount, void *uc)
+{
+    // icount must be the first constant, it is patched once the block is translated
+    TCGv_i32 ticount = tcg_const_i32(tcg_ctx, icount);
+    TCGv_ptr tuc = tcg_const_ptr(tcg_ctx, uc);
+
+    gen_helper_uc_insn_budget(tcg_ctx, tuc, ticount);
+
+    tcg_temp_free_ptr(tcg_ctx, tuc);
+    tcg_temp_free_i32(tcg_ctx, ticount);
+}
+
+static inline void gen_uc_edge_cov(TCGContext *tcg_ctx, void *uc, uint64_t cur_loc)
+{
+    TCGv_ptr tuc = tcg_const_ptr(tcg_ctx, uc);
+    TCGv_i64 tloc = tcg_const_i64(tcg_ctx, cur_loc);
+
+    gen_helper_uc_edge_cov(tcg_ctx, tuc, tloc);
+
+    tcg_temp_free_i64(tcg_ctx, tloc);
+    tcg_temp_free_ptr(tcg_ctx, tuc);
+}
+
 static inline void gen_uc_traceopcode(TCGContext *tcg_ctx, void* hook, TCGv_i64 arg1, TCGv_i64 arg2, uint32_t size, void *uc, uint64_t pc)
 {
     TCGv_ptr thook = tcg_const_ptr(tcg_ctx, hook);
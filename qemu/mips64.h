@@ -1423,6 +1423,10 @@
 #define helper_dvp helper_dvp_mips64
 #define helper_evp helper_evp_mips64
 #define cpu_mips_get_random cpu_mips_get_random_mips64
+#define cpu_mips_get_count cpu_mips_get_count_mips64
+#define cpu_mips_store_count cpu_mips_store_count_mips64
+#define cpu_mips_start_count cpu_mips_start_count_mips64
+#define cpu_mips_stop_count cpu_mips_stop_count_mips64
 #define cpu_mips_init cpu_mips_init_mips64
 #define helper_absq_s_ph helper_absq_s_ph_mips64
 #define helper_absq_s_qb helper_absq_s_qb_mips64
@@ -1423,6 +1423,10 @@
 #define helper_dvp helper_dvp_mipsel
 #define helper_evp helper_evp_mipsel
 #define cpu_mips_get_random cpu_mips_get_random_mipsel
+#define cpu_mips_get_count cpu_mips_get_count_mipsel
+#define cpu_mips_store_count cpu_mips_store_count_mipsel
+#define cpu_mips_start_count cpu_mips_start_count_mipsel
+#define cpu_mips_stop_count cpu_mips_stop_count_mipsel
 #define cpu_mips_init cpu_mips_init_mipsel
 #define helper_absq_s_ph helper_absq_s_ph_mipsel
 #define helper_absq_s_qb helper_absq_s_qb_mipsel
@@ -0,0 +1,135 @@
+/*
+ * AUTOMATICALLY GENERATED, DO NOT MODIFY HERE, EDIT
+ * SOURCE FILE "/home/me/projects/qemu/qemu-5.0.1/target/s390x/gen-features.c" INSTEAD.
+ *
+ * Copyright 2016 IBM Corp.
+ *
+ * This work is licensed under the terms of the GNU GPL, version 2 or (at
+ * your option) any later version. See the COPYING file in the top-level
+ * directory.
+ */
+
+#ifndef TARGET_S390X_GEN_FEATURES_H
+#define TARGET_S390X_GEN_FEATURES_H
+
+/* CPU model feature list data */
+#define S390_FEAT_LIST_GEN7_GA1_BASE	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA1_DEFAULT	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA1_FULL		0x0000000000000003ULL,0xfffffe3380000030ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA2_BASE	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA2_DEFAULT	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA2_FULL		0x0000000000008003ULL,0xfffffe3380000030ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA3_BASE	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA3_DEFAULT	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN7_GA3_FULL		0x0000000000028003ULL,0xfffffe3780000030ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA1_BASE	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA1_DEFAULT	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA1_FULL		0x00000000000f8007ULL,0xfffffe3788800030ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA2_BASE	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA2_DEFAULT	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA2_FULL		0x00000000000f8007ULL,0xfffffe3788800030ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA3_BASE	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA3_DEFAULT	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA3_FULL		0x00000000002f8027ULL,0xfffffe3788800030ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA4_BASE	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA4_DEFAULT	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA4_FULL		0x00000000002f8027ULL,0xfffffe3788800030ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA5_BASE	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA5_DEFAULT	0x00000000000f8007ULL,0xfffffe0000000000ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN8_GA5_FULL		0x00000000002f8027ULL,0xfffffe3788800030ULL,0x802000e007007001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA1_BASE	0x0000000019ff816fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA1_DEFAULT	0x4000000019ff816fULL,0xfffffec000000000ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA1_FULL		0x4000000019ff816fULL,0xfffffef798800030ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA2_BASE	0x0000000019ff816fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA2_DEFAULT	0x4000000019ff816fULL,0xfffffec000000000ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA2_FULL		0x400000c07dff816fULL,0xfffffef798800030ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA3_BASE	0x0000000019ff816fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA3_DEFAULT	0x4000000019ff816fULL,0xfffffec000000000ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN9_GA3_FULL		0x400002c07dff836fULL,0xfffffef798800030ULL,0x806008e04700710fULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA1_BASE	0x000003c3ffff836fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA1_DEFAULT	0x400003c3ffff83efULL,0xfffffec000000000ULL,0x80e038f1c700710fULL,0x0000000000000003ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA1_FULL		0x400003c3ffff87efULL,0xfffffeffb9800030ULL,0x80e038f1c700710fULL,0x0000000000000003ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA2_BASE	0x000003c3ffff836fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA2_DEFAULT	0x400003c3ffff83efULL,0xfffffec000000000ULL,0x80e038f1c700710fULL,0x0000000000000003ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA2_FULL		0x400003e3ffff87efULL,0xfffffefff9800030ULL,0x80e038f1c700710fULL,0x0000000000000003ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA3_BASE	0x000003c3ffff836fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA3_DEFAULT	0x400003c3ffff83efULL,0xfffffec000000000ULL,0x80e038f1c700710fULL,0x0000000000000003ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN10_GA3_FULL		0x400003e3ffff87efULL,0xfffffefff9800031ULL,0x80e1ffffff03f10fULL,0x0000000000003f03ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN11_GA1_BASE	0x00010fefffffa36fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN11_GA1_DEFAULT	0xc0010fefffffb3efULL,0xfffffec000000033ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN11_GA1_FULL		0xc0010fefffffb7efULL,0xfffffefff9800033ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN11_GA2_BASE	0x00010fefffffa36fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN11_GA2_DEFAULT	0xc0010fefffffb3efULL,0xfffffec000000033ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN11_GA2_FULL		0xc0010fefffffb7efULL,0xfffffefff9800033ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN12_GA1_BASE	0x0001bfefffffa36fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN12_GA1_DEFAULT	0xed01ffefffffb3efULL,0xfffffec001000137ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN12_GA1_FULL		0xff01ffefffffffefULL,0xfffffffff9800137ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN12_GA2_BASE	0x0001bfefffffa36fULL,0xfffffe4000000000ULL,0x802000e00700710fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN12_GA2_DEFAULT	0xed01ffefffffb3efULL,0xfffffec001000137ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000007fULL
+#define S390_FEAT_LIST_GEN12_GA2_FULL		0xff09ffefffffffefULL,0xfffffffff9800137ULL,0xc0fffffffffff10fULL,0xfffffffffff83f03ULL,0x000000000000407fULL
+#define S390_FEAT_LIST_GEN13_GA1_BASE	0x0003bfefffffa36fULL,0xfffffe4000000008ULL,0x802000e00700733fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN13_GA1_DEFAULT	0xed0bffefffffb3efULL,0xfffffec00100017fULL,0xc0fffffffffff33fULL,0xfffffffffff83f03ULL,0x000000000000407fULL
+#define S390_FEAT_LIST_GEN13_GA1_FULL		0xff0bffefffffffefULL,0xfffffffff980017fULL,0xc0fffffffffff33fULL,0xfffffffffff83f03ULL,0x000000000000407fULL
+#define S390_FEAT_LIST_GEN13_GA2_BASE	0x0003bfefffffa36fULL,0xfffffe4000000008ULL,0x802000e00700733fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN13_GA2_DEFAULT	0xed0bffefffffb3efULL,0xfffffec00100017fULL,0xc0fffffffffff33fULL,0xfffffffffff83f03ULL,0x000000000000407fULL
+#define S390_FEAT_LIST_GEN13_GA2_FULL		0xff0bffefffffffefULL,0xfffffffff980017fULL,0xc0fffffffffff33fULL,0xfffffffffff83f03ULL,0x000000000000407fULL
+#define S390_FEAT_LIST_GEN14_GA1_BASE	0x0077bfffffffa36fULL,0xfffffe4000000008ULL,0x802000e00700733fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN14_GA1_DEFAULT	0xed7fffffffffb3efULL,0xfffffec001009fffULL,0xffffffffffffffffULL,0xfffffffffff83fffULL,0x00000000007fc07fULL
+#define S390_FEAT_LIST_GEN14_GA1_FULL		0xff7fffffffffffefULL,0xffffffffffc1ffffULL,0xffffffffffffffffULL,0xfffffffffff83fffULL,0x00000000007fc07fULL
+#define S390_FEAT_LIST_GEN14_GA2_BASE	0x0077bfffffffa36fULL,0xfffffe4000000008ULL,0x802000e00700733fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN14_GA2_DEFAULT	0xed7fffffffffb3efULL,0xfffffec001009fffULL,0xffffffffffffffffULL,0xfffffffffff83fffULL,0x00000000007fc07fULL
+#define S390_FEAT_LIST_GEN14_GA2_FULL		0xff7fffffffffffefULL,0xffffffffffc1ffffULL,0xffffffffffffffffULL,0xfffffffffff83fffULL,0x00000000007fc07fULL
+#define S390_FEAT_LIST_GEN15_GA1_BASE	0x00f7bfffffffa16fULL,0xfffffe4000000008ULL,0x802000e00700733fULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_GEN15_GA1_DEFAULT	0xedffffffffffb1efULL,0xfffffec0017a9fdfULL,0xffffffffffffffffULL,0xffffffffffffffffULL,0x0000783fffffffffULL
+#define S390_FEAT_LIST_GEN15_GA1_FULL		0xffffffffffffffefULL,0xffffffffffffffffULL,0xffffffffffffffffULL,0xffffffffffffffffULL,0x00007fffffffffffULL
+
+/* CPU feature group list data */
+#define S390_FEAT_GROUP_LIST_PLO	0x0000000000000000ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_TOD_CLOCK_STEERING	0x0000000008000000ULL,0x0000000000000000ULL,0x000000000000010eULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_GEN13_PTFF	0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000230ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA	0x0000000000010000ULL,0x0000000000000000ULL,0x802000e007007000ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_1	0x0000000000000000ULL,0x0000000000000000ULL,0x0040080040000000ULL,0x0000000000000001ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_2	0x0000000000000000ULL,0x0000000000000000ULL,0x0080301180000000ULL,0x0000000000000002ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_3	0x0000000000000000ULL,0x0000000000000001ULL,0x0001c70e38038000ULL,0x0000000000003f00ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_4	0x0000000000000000ULL,0x0000000000000002ULL,0x401e000000fc0000ULL,0xfffffffffff80000ULL,0x000000000000007fULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_5	0x0008000000000000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000004000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_6	0x0000000000000000ULL,0x0000000000000000ULL,0x3f00000000000000ULL,0x00000000000000fcULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_7	0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000018000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_8	0x0000000000000000ULL,0x0000000000008000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x00000000007e0000ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_9	0x0000000000000000ULL,0x0000000000200000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000003fff803f80ULL
+#define S390_FEAT_GROUP_LIST_MSA_EXT_9_PCKMO	0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x000000000007c000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_MULTIPLE_EPOCH_PTFF	0x0000000000000000ULL,0x0000000000000000ULL,0x0000000000000cc0ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_GROUP_LIST_ENH_SORT	0x0000000000000000ULL,0x0000000000040000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x000007c000000000ULL
+#define S390_FEAT_GROUP_LIST_DEFLATE_CONVERSION	0x0000000000000000ULL,0x0000000000080000ULL,0x0000000000000000ULL,0x0000000000000000ULL,0x0000780000000000ULL
+
+/* QEMU (CPU model) feature list data */
+#define S390_FEAT_LIST_QEMU_V2_11	0x0000000000000003ULL,0xfffffe0000000000ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_QEMU_V3_1	0x1801a463f5b7814fULL,0xfffffe0000000003ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_QEMU_V4_0	0x1c01a46bf5b7814fULL,0xfffffe0000000003ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_QEMU_V4_1	0x1c03a46bf5b7814fULL,0xfffffe0000000043ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_QEMU_LATEST	0x9c03a46bf5b7814fULL,0xfffffe0001000143ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+#define S390_FEAT_LIST_QEMU_MAX	0x9c0ba46bf5b7814fULL,0xfffffe00010001c3ULL,0x0000000000000001ULL,0x0000000000000000ULL,0x0000000000000000ULL
+
+/* CPU feature group enum type */
+typedef enum {
+	S390_FEAT_GROUP_PLO,
+	S390_FEAT_GROUP_TOD_CLOCK_STEERING,
+	S390_FEAT_GROUP_GEN13_PTFF,
+	S390_FEAT_GROUP_MSA,
+	S390_FEAT_GROUP_MSA_EXT_1,
+	S390_FEAT_GROUP_MSA_EXT_2,
+	S390_FEAT_GROUP_MSA_EXT_3,
+	S390_FEAT_GROUP_MSA_EXT_4,
+	S390_FEAT_GROUP_MSA_EXT_5,
+	S390_FEAT_GROUP_MSA_EXT_6,
+	S390_FEAT_GROUP_MSA_EXT_7,
+	S390_FEAT_GROUP_MSA_EXT_8,
+	S390_FEAT_GROUP_MSA_EXT_9,
+	S390_FEAT_GROUP_MSA_EXT_9_PCKMO,
+	S390_FEAT_GROUP_MULTIPLE_EPOCH_PTFF,
+	S390_FEAT_GROUP_ENH_SORT,
+	S390_FEAT_GROUP_DEFLATE_CONVERSION,
+	S390_FEAT_GROUP_MAX,
+} S390FeatGroup;
+
+#endif
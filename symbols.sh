@@ -4549,6 +4549,10 @@ helper_evpe \
 helper_dvp \
 helper_evp \
 cpu_mips_get_random \
+cpu_mips_get_count \
+cpu_mips_store_count \
+cpu_mips_start_count \
+cpu_mips_stop_count \
 cpu_mips_init \
 helper_absq_s_ph \
 helper_absq_s_qb \
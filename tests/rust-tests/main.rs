@@ -2,10 +2,41 @@ extern crate alloc;
 
 use alloc::rc::Rc;
 use core::cell::RefCell;
+use unicorn_engine::callstack::CallFrame;
+use unicorn_engine::cmplog::{CmpKind, Routine, DEFAULT_WIDTH};
+use unicorn_engine::crash::BUCKET_FRAMES;
+use unicorn_engine::drcov::{BasicBlock, Coverage, Module};
+use unicorn_engine::fuzz::{CrashKind, Harness, InputPlacement, Verdict};
+use unicorn_engine::linux::stack::{
+    StackBuilder, AT_ENTRY, AT_NULL, AT_PAGESZ, AT_PHDR, AT_RANDOM,
+};
+use unicorn_engine::linux::syscall::{EFBIG, ENOSYS};
+use unicorn_engine::linux::vfs::{FileSystem, MemFs, OpenFlags, MEM_FILE_MAX};
+use unicorn_engine::linux::{Abi, Kernel, Sysno};
+use unicorn_engine::loader::elf::coredump::{
+    self, Core, NT_ARM_TLS, NT_ARM_VFP, NT_AUXV, NT_FILE, NT_FPREGSET, NT_PRSTATUS,
+};
+use unicorn_engine::loader::elf::link::{LinkError, Linker, EXE_BASE, LIBRARY_BASE, STUB_BASE};
+use unicorn_engine::loader::elf::{self, Elf, ElfError, SymbolKind};
+use unicorn_engine::loader::firmware::{FirmwareError, MemoryMap, Region};
+use unicorn_engine::loader::hex::{
+    self, HexError, Image, UF2_FLAG_FAMILY_ID, UF2_FLAG_NOT_MAIN_FLASH,
+};
+use unicorn_engine::loader::pe::link::{LinkError as PeLinkError, THUNK_BASE};
+use unicorn_engine::loader::pe::{self, Linker as PeLinker};
+use unicorn_engine::memory::{Origin, VirtualMemory};
+use unicorn_engine::shadow::UninitRead;
+use unicorn_engine::tenet::Tracer;
+use unicorn_engine::trace::{Recorder, TraceConfig, TraceEvent, TraceFlags, TraceReader};
 use unicorn_engine::unicorn_const::{
-    uc_error, Arch, HookType, MemType, Mode, Permission, SECOND_SCALE,
+    uc_error, Arch, CountMode, CoverageFlags, Deterministic, HookType, MemType, Mode, Permission,
+    TimeoutMode, SECOND_SCALE,
+};
+use unicorn_engine::utils::{init_emu_with_heap, Chunk, Heap, HeapErrorKind, HeapSymbols};
+use unicorn_engine::{
+    EmuExit, InsnSysX86, RegisterARM, RegisterARM64, RegisterMIPS, RegisterPPC, RegisterRISCV,
+    RegisterX86, Unicorn,
 };
-use unicorn_engine::{InsnSysX86, RegisterARM, RegisterMIPS, RegisterPPC, RegisterX86, Unicorn};
 
 pub static X86_REGISTERS: [RegisterX86; 125] = [
     RegisterX86::AH,
@@ -167,7 +198,7 @@ fn emulate_x86() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(emu.reg_read(RegisterX86::ECX), Ok(11));
     assert_eq!(emu.reg_read(RegisterX86::EDX), Ok(49));
@@ -199,7 +230,7 @@ fn x86_code_callback() {
         .expect("failed to add code hook");
     assert_eq!(
         emu.emu_start(0x1000, 0x1002, 10 * SECOND_SCALE, 1000),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expects, *codes_cell.borrow());
     assert_eq!(emu.remove_hook(hook), Ok(()));
@@ -235,7 +266,7 @@ fn x86_intr_callback() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expect, *intr_cell.borrow());
     assert_eq!(emu.remove_hook(hook), Ok(()));
@@ -292,7 +323,7 @@ fn x86_mem_callback() {
             10 * SECOND_SCALE,
             0x1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
 
     assert_eq!(expects, *mems_cell.borrow());
@@ -330,7 +361,7 @@ fn x86_insn_in_callback() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expect, *insn_cell.borrow());
     assert_eq!(emu.reg_read(RegisterX86::EAX), Ok(42));
@@ -367,7 +398,7 @@ fn x86_insn_out_callback() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expect, *insn_cell.borrow());
     assert_eq!(emu.remove_hook(hook), Ok(()));
@@ -408,7 +439,7 @@ fn x86_insn_sys_callback() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expect, *insn_cell.borrow());
     assert_eq!(emu.remove_hook(hook), Ok(()));
@@ -461,7 +492,7 @@ fn x86_mmio() {
                 10 * SECOND_SCALE,
                 1000
             ),
-            Ok(())
+            Ok(EmuExit::ReachedUntil)
         );
 
         assert_eq!(read_expect, *read_cell.borrow());
@@ -492,7 +523,7 @@ fn x86_mmio() {
                 10 * SECOND_SCALE,
                 1000
             ),
-            Ok(())
+            Ok(EmuExit::ReachedUntil)
         );
 
         assert_eq!(read_expect, *read_cell.borrow());
@@ -523,7 +554,7 @@ fn x86_mmio() {
                 10 * SECOND_SCALE,
                 1000
             ),
-            Ok(())
+            Ok(EmuExit::ReachedUntil)
         );
 
         assert_eq!(write_expect, *write_cell.borrow());
@@ -566,7 +597,7 @@ fn emulate_arm() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::InstructionLimit(1000))
     );
     assert_eq!(emu.reg_read(RegisterARM::SP), Ok(0));
     assert_eq!(emu.reg_read(RegisterARM::R0), Ok(10));
@@ -592,7 +623,7 @@ fn emulate_mips() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(emu.reg_read(RegisterMIPS::AT), Ok(0x3456));
 }
@@ -618,7 +649,7 @@ fn emulate_ppc() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(emu.reg_read(RegisterPPC::R26), Ok(1379));
 }
@@ -666,7 +697,7 @@ fn mem_map_ptr() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(emu.reg_read(RegisterX86::ECX), Ok(11));
     assert_eq!(emu.reg_read(RegisterX86::EDX), Ok(49));
@@ -702,7 +733,7 @@ fn mem_map_ptr() {
             10 * SECOND_SCALE,
             1000
         ),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(emu.reg_read(RegisterX86::ECX), Ok(11));
     assert_eq!(emu.reg_read(RegisterX86::EDX), Ok(49));
@@ -767,8 +798,2561 @@ fn x86_block_callback() {
         .expect("failed to add block hook");
     assert_eq!(
         emu.emu_start(0x1000, 0x1002, 10 * SECOND_SCALE, 1000),
-        Ok(())
+        Ok(EmuExit::ReachedUntil)
     );
     assert_eq!(expects, *blocks_cell.borrow());
     assert_eq!(emu.remove_hook(hook), Ok(()));
 }
+
+#[test]
+fn x86_emu_exit_reasons() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+
+    // mov eax, [0x10000];
+    let x86_code32: Vec<u8> = vec![0xA1, 0x00, 0x00, 0x01, 0x00];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(
+        emu.emu_start(0x1000, 0x1005, 10 * SECOND_SCALE, 1000),
+        Ok(EmuExit::Fault {
+            address: 0x10000,
+            access: MemType::READ_UNMAPPED,
+            pc: 0x1000
+        })
+    );
+
+    // INC ecx; DEC edx
+    let x86_code32: Vec<u8> = vec![0x41, 0x4a];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    let hook = emu
+        .add_code_hook(0x1001, 0x1001, |uc, _, _| {
+            uc.emu_stop().unwrap();
+        })
+        .expect("failed to add code hook");
+    assert_eq!(
+        emu.emu_start(0x1000, 0x1002, 10 * SECOND_SCALE, 1000),
+        Ok(EmuExit::StoppedByHook)
+    );
+    assert_eq!(emu.remove_hook(hook), Ok(()));
+
+    // a nested run reaching its end does not hide a stop of the outer one
+    let entered = core::cell::Cell::new(false);
+    let hook = emu
+        .add_code_hook(0x1001, 0x1001, move |uc, _, _| {
+            if !entered.replace(true) {
+                let _ = uc.emu_start(0x1000, 0x1001, 0, 0);
+            }
+            uc.emu_stop().unwrap();
+        })
+        .expect("failed to add code hook");
+    assert_eq!(emu.emu_start(0x1000, 0x1002, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(emu.remove_hook(hook), Ok(()));
+
+    //   cmp eax, 0;
+    //   jg lb;
+    //   inc eax;
+    //   nop;       <---- exit1
+    // lb:
+    //   inc ebx;
+    //   nop;       <---- exit2
+    let x86_code32: Vec<u8> = vec![0x83, 0xf8, 0x00, 0x7f, 0x02, 0x40, 0x90, 0x43, 0x90];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.ctl_exits_enable(), Ok(()));
+    assert_eq!(emu.ctl_set_exits(&[0x1006, 0x1008]), Ok(()));
+    assert_eq!(emu.ctl_get_exits(), Ok(vec![0x1006, 0x1008]));
+    assert_eq!(emu.reg_write(RegisterX86::EAX, 0), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0, 0, 0), Ok(EmuExit::Exit(0x1006)));
+    assert_eq!(emu.emu_start(0x1000, 0, 0, 0), Ok(EmuExit::Exit(0x1008)));
+    assert_eq!(emu.ctl_exits_disable(), Ok(()));
+}
+
+#[test]
+fn x86_inline_timeout() {
+    let x86_code32: Vec<u8> = vec![0xeb, 0xfe]; // JMP $
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    assert_eq!(emu.ctl_get_timeout_mode(), Ok(TimeoutMode::THREAD));
+    assert_eq!(emu.ctl_set_timeout_mode(TimeoutMode::INLINE), Ok(()));
+    assert_eq!(emu.ctl_get_timeout_mode(), Ok(TimeoutMode::INLINE));
+
+    for _ in 0..3 {
+        assert_eq!(
+            emu.emu_start(0x1000, 0x1002, SECOND_SCALE / 100, 0),
+            Ok(EmuExit::Timeout)
+        );
+        assert!(emu.check_timeout());
+    }
+
+    // a nested run without a timeout still stops at the deadline of the outer one
+    let nested = Rc::new(RefCell::new(vec![]));
+    let exits = nested.clone();
+    let entered = core::cell::Cell::new(false);
+    emu.add_block_hook(move |uc, _, _| {
+        if !entered.replace(true) {
+            let exit = uc.emu_start(0x1000, 0x1002, 0, 0);
+            exits.borrow_mut().push(exit);
+        }
+    })
+    .expect("failed to add block hook");
+    assert_eq!(
+        emu.emu_start(0x1000, 0x1002, SECOND_SCALE / 100, 0),
+        Ok(EmuExit::Timeout)
+    );
+    assert_eq!(*nested.borrow(), vec![Ok(EmuExit::Timeout)]);
+}
+
+#[test]
+fn x86_insn_budget() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+
+    let x86_code32: Vec<u8> = vec![0x41, 0x4a]; // INC ecx; DEC edx
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.reg_write(RegisterX86::ECX, 10), Ok(()));
+    assert_eq!(emu.reg_write(RegisterX86::EDX, 50), Ok(()));
+    assert_eq!(
+        emu.emu_start(0x1000, 0x1002, 10 * SECOND_SCALE, 1),
+        Ok(EmuExit::InstructionLimit(1))
+    );
+    assert!(!emu.check_timeout());
+    assert_eq!(emu.check_insn_limit(), Ok(true));
+    assert_eq!(emu.insn_count(), Ok(1));
+    assert_eq!(emu.reg_read(RegisterX86::ECX), Ok(11));
+    assert_eq!(emu.reg_read(RegisterX86::EDX), Ok(50));
+
+    let x86_code32: Vec<u8> = vec![0xeb, 0xfe]; // JMP $
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.ctl_set_count_mode(CountMode::BLOCK), Ok(()));
+    assert_eq!(emu.ctl_get_count_mode(), Ok(CountMode::BLOCK));
+    assert_eq!(
+        emu.emu_start(0x1000, 0x1002, 10 * SECOND_SCALE, 100),
+        Ok(EmuExit::InstructionLimit(100))
+    );
+    assert!(!emu.check_timeout());
+    assert_eq!(emu.check_insn_limit(), Ok(true));
+    assert_eq!(emu.insn_count(), Ok(100));
+}
+
+#[test]
+fn x86_edge_coverage() {
+    let mut map = vec![0u8; 1 << 16];
+    let mut odd_map = vec![0u8; 1000];
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+
+    // MOV ecx, 5; loop: DEC ecx; JNZ loop
+    let x86_code32: Vec<u8> = vec![0xb9, 0x05, 0x00, 0x00, 0x00, 0x49, 0x75, 0xfd];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    assert_eq!(
+        emu.coverage_enable(&mut odd_map, 0, u64::MAX, CoverageFlags::NONE),
+        Err(uc_error::ARG)
+    );
+    assert_eq!(
+        emu.coverage_enable(&mut map, 0, u64::MAX, CoverageFlags::NONE),
+        Ok(())
+    );
+    assert_eq!(emu.emu_start(0x1000, 0x1008, 0, 0), Ok(EmuExit::ReachedUntil));
+    let cov = emu.coverage_map().unwrap();
+    // one entry into each block, the loop edge is taken three times
+    assert_eq!(cov.iter().map(|&hits| hits as u32).sum::<u32>(), 5);
+    assert_eq!(cov.iter().filter(|&&hits| hits != 0).count(), 3);
+    assert_eq!(cov.iter().max(), Some(&3));
+
+    emu.coverage_map_mut().unwrap().fill(0);
+    let map = emu.coverage_disable().unwrap().unwrap();
+    assert_eq!(emu.coverage_map(), None);
+    assert_eq!(
+        emu.coverage_enable(map, 0x1000, 0x1004, CoverageFlags::BUCKETS),
+        Ok(())
+    );
+    assert_eq!(emu.emu_start(0x1000, 0x1008, 0, 0), Ok(EmuExit::ReachedUntil));
+    // only the first block is in range
+    let cov = emu.coverage_map().unwrap();
+    assert_eq!(cov.iter().map(|&hits| hits as u32).sum::<u32>(), 1);
+
+    // bucketed counts are not classified again by the next run
+    let map = emu.coverage_disable().unwrap().unwrap();
+    assert_eq!(
+        emu.coverage_enable(map, 0, u64::MAX, CoverageFlags::BUCKETS),
+        Ok(())
+    );
+    assert_eq!(emu.emu_start(0x1000, 0x1008, 0, 0), Ok(EmuExit::ReachedUntil));
+    let first = emu.coverage_map().unwrap().to_vec();
+    assert_eq!(emu.emu_start(0x1000, 0x1008, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(emu.coverage_map().unwrap(), &first[..]);
+}
+
+fn x86_fuzz_harness_target() -> Harness<'static, ()> {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x8000, 0x1000, Permission::ALL), Ok(()));
+
+    let x86_code32: Vec<u8> = vec![
+        0x80, 0x3d, 0x00, 0x80, 0x00, 0x00, 0x41, // CMP byte ptr [0x8000], 'A'
+        0x74, 0x11, // JE crash
+        0x80, 0x3d, 0x00, 0x80, 0x00, 0x00, 0x42, // CMP byte ptr [0x8000], 'B'
+        0x74, 0x0f, // JE hang
+        0xff, 0x05, 0x00, 0x30, 0x00, 0x00, // INC dword ptr [0x3000]
+        0xeb, 0x09, // JMP exit
+        0xc6, 0x05, 0x00, 0x00, 0x00, 0x00, 0x01, // crash: MOV byte ptr [0], 1
+        0xeb, 0xfe, // hang: JMP $
+    ];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    let mut harness = Harness::new(
+        emu,
+        0x1000,
+        &[0x1023],
+        InputPlacement::Buffer {
+            address: 0x8000,
+            max_len: 0x10,
+            len_reg: RegisterX86::ECX.into(),
+        },
+    )
+    .expect("failed to create harness");
+    harness.set_count(1000);
+    harness
+}
+
+#[test]
+fn x86_fuzz_harness() {
+    let mut harness = x86_fuzz_harness_target();
+
+    for _ in 0..3 {
+        assert_eq!(harness.run(b"xyz"), Ok(Verdict::Ok));
+        // the counter and the input buffer are back to the snapshot
+        assert_eq!(harness.emu().mem_read_as_vec(0x3000, 4), Ok(vec![0, 0, 0, 0]));
+        assert_eq!(harness.emu().mem_read_as_vec(0x8000, 3), Ok(vec![0, 0, 0]));
+        assert_eq!(harness.emu().reg_read(RegisterX86::ECX), Ok(0));
+    }
+    assert_eq!(
+        harness.run(b"A"),
+        Ok(Verdict::Crash {
+            pc: 0x101a,
+            kind: CrashKind::Fault {
+                access: MemType::WRITE_UNMAPPED,
+                address: 0
+            }
+        })
+    );
+    assert_eq!(harness.run(b"B"), Ok(Verdict::Timeout));
+    assert_eq!(harness.run(b""), Ok(Verdict::Ok));
+}
+
+#[test]
+fn x86_fuzz_harness_host_writes() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.shadow_enable(false), Ok(()));
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x8000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    // MOV eax, dword ptr [0x20000]
+    assert_eq!(emu.mem_write(0x1000, &[0xa1, 0x00, 0x00, 0x02, 0x00]), Ok(()));
+
+    let place = |uc: &mut Unicorn<'_, ()>, input: &[u8]| {
+        uc.mem_map(0x20000, 0x1000, Permission::READ | Permission::WRITE)?;
+        uc.mem_write(0x20000, input)?;
+        uc.mem_write(0x8000, input)
+    };
+    let mut harness = Harness::new(emu, 0x1000, &[0x1005], InputPlacement::Callback(Box::new(place)))
+        .expect("failed to create harness");
+    for _ in 0..2 {
+        assert_eq!(harness.run(b"abcd"), Ok(Verdict::Ok));
+        let emu = harness.emu();
+        assert_eq!(emu.mem_read_as_vec(0x8000, 4), Ok(vec![0, 0, 0, 0]));
+        assert_eq!(emu.shadow().unwrap().first_uninit(0x8000, 4), Some(0x8000));
+        assert!(emu.mem_regions().unwrap().iter().all(|r| r.begin != 0x20000));
+    }
+
+    // permissions and mappings of the snapshot come back as well
+    let emu = harness.emu_mut();
+    assert_eq!(emu.mem_write(0x8000, &[1]), Ok(()));
+    assert_eq!(emu.mem_protect(0x8000, 0x1000, Permission::READ), Ok(()));
+    assert_eq!(emu.mem_unmap(0x1000, 0x1000), Ok(()));
+    assert_eq!(harness.reset(), Ok(()));
+    let emu = harness.emu();
+    let regions: Vec<_> = emu.mem_regions().unwrap().iter().map(|r| (r.begin, r.perms)).collect();
+    assert_eq!(regions, vec![(0x1000, Permission::ALL), (0x8000, Permission::READ | Permission::WRITE)]);
+    assert_eq!(emu.mem_read_as_vec(0x1000, 5), Ok(vec![0xa1, 0x00, 0x00, 0x02, 0x00]));
+    assert_eq!(emu.mem_read_as_vec(0x8000, 1), Ok(vec![0]));
+    assert_eq!(harness.run(b"abcd"), Ok(Verdict::Ok));
+}
+
+#[cfg(feature = "libfuzzer")]
+#[test]
+fn x86_libfuzzer_input() {
+    use unicorn_engine::fuzz::libfuzzer::{test_one_input, Corpus, Finding};
+
+    let mut harness = x86_fuzz_harness_target();
+    assert_eq!(test_one_input(&mut harness, b"xyz"), Ok(Corpus::Keep));
+    assert_eq!(
+        test_one_input(&mut harness, b"A"),
+        Err(Finding::Crash {
+            pc: 0x101a,
+            kind: CrashKind::Fault {
+                access: MemType::WRITE_UNMAPPED,
+                address: 0
+            }
+        })
+    );
+    assert_eq!(harness.last_crash().map(|r| r.pc), Some(0x101a));
+    assert_eq!(test_one_input(&mut harness, b"B"), Ok(Corpus::Reject));
+    assert_eq!(test_one_input(&mut harness, b""), Ok(Corpus::Keep));
+}
+
+fn x86_heap_emu(code: &[u8]) -> Unicorn<'static, Rc<RefCell<Heap>>> {
+    let symbols = HeapSymbols {
+        malloc: 0x2000,
+        free: 0x2010,
+        realloc: Some(0x2020),
+        ..Default::default()
+    };
+    let mut emu = init_emu_with_heap(Arch::X86, Mode::MODE_64, 0x10000, 0x10000, &symbols)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x2000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, code), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, &[0xc3]), Ok(())); // RET
+    assert_eq!(emu.mem_write(0x2010, &[0xc3]), Ok(())); // RET
+    assert_eq!(emu.mem_write(0x2020, &[0xc3]), Ok(())); // RET
+    assert_eq!(emu.reg_write(RegisterX86::RSP, 0x7f00), Ok(()));
+    emu
+}
+
+#[test]
+fn x86_heap_sanitizer() {
+    // MOV edi, 0x10; CALL malloc; MOV rbx, rax; MOV byte ptr [rbx + 0x10], 1
+    let mut emu = x86_heap_emu(&[
+        0xbf, 0x10, 0x00, 0x00, 0x00, 0xe8, 0xf6, 0x0f, 0x00, 0x00, 0x48, 0x89, 0xc3, 0xc6, 0x43,
+        0x10, 0x01,
+    ]);
+    assert_eq!(emu.emu_start(0x1000, 0x1011, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(emu.crash_pc(), 0x100d);
+    let chunk = Chunk {
+        address: 0x10010,
+        size: 0x10,
+        alloc_site: 0x100a,
+        free_site: None,
+    };
+    let error = emu.get_data().borrow().error().expect("overflow not detected");
+    assert_eq!(
+        error.kind,
+        HeapErrorKind::OutOfBounds {
+            access: MemType::WRITE
+        }
+    );
+    assert_eq!(error.address, 0x10020);
+    assert_eq!(error.chunk, Some(chunk));
+    assert_eq!(error.error(), uc_error::WRITE_PROT);
+
+    // MOV edi, 0x10; CALL malloc; MOV rbx, rax; MOV rdi, rbx; CALL free; MOV rdi, rbx; CALL free
+    let mut emu = x86_heap_emu(&[
+        0xbf, 0x10, 0x00, 0x00, 0x00, 0xe8, 0xf6, 0x0f, 0x00, 0x00, 0x48, 0x89, 0xc3, 0x48, 0x89,
+        0xdf, 0xe8, 0xfb, 0x0f, 0x00, 0x00, 0x48, 0x89, 0xdf, 0xe8, 0xf3, 0x0f, 0x00, 0x00,
+    ]);
+    assert_eq!(emu.emu_start(0x1000, 0x101d, 0, 0), Err(uc_error::DOUBLE_FREE));
+    assert_eq!(emu.crash_pc(), 0x101d);
+    let error = emu.get_data().borrow().error().expect("double free not detected");
+    assert_eq!(error.kind, HeapErrorKind::DoubleFree);
+    assert_eq!(
+        error.chunk,
+        Some(Chunk {
+            free_site: Some(0x1015),
+            ..chunk
+        })
+    );
+    assert_eq!(error.error(), uc_error::DOUBLE_FREE);
+    assert_eq!(emu.get_data().borrow().live_chunks().count(), 0);
+
+    // MOV edi, 0x10; CALL malloc; MOV byte ptr [rax], 1; MOV rdi, rax; MOV esi, 0x20;
+    // CALL realloc
+    let mut emu = x86_heap_emu(&[
+        0xbf, 0x10, 0x00, 0x00, 0x00, 0xe8, 0xf6, 0x0f, 0x00, 0x00, 0xc6, 0x00, 0x01, 0x48, 0x89,
+        0xc7, 0xbe, 0x20, 0x00, 0x00, 0x00, 0xe8, 0x06, 0x10, 0x00, 0x00,
+    ]);
+    assert_eq!(emu.shadow_enable(false), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0x101a, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(emu.reg_read(RegisterX86::RAX), Ok(0x10030));
+    assert_eq!(emu.mem_read_as_vec(0x10030, 1), Ok(vec![1]));
+    // the moved chunk is only as initialized as the old one was
+    assert_eq!(emu.shadow().unwrap().first_uninit(0x10030, 0x20), Some(0x10031));
+}
+
+#[test]
+fn x86_shadow_uninit_read() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.shadow_enable(true), Ok(()));
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x3000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+
+    let x86_code32: Vec<u8> = vec![
+        0xc7, 0x05, 0x04, 0x30, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // MOV dword ptr [0x3004], 1
+        0x8b, 0x1d, 0x04, 0x30, 0x00, 0x00, // MOV ebx, dword ptr [0x3004]
+        0xa1, 0x00, 0x30, 0x00, 0x00, // MOV eax, dword ptr [0x3000]
+    ];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0x1015, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(emu.crash_pc(), 0x1010);
+    assert_eq!(emu.reg_read(RegisterX86::EBX), Ok(1));
+    assert_eq!(
+        emu.shadow().unwrap().reads(),
+        &[UninitRead {
+            pc: 0x1010,
+            address: 0x3000,
+            size: 4
+        }]
+    );
+
+    // initialized from the host, the read is fine now
+    assert_eq!(emu.mem_write(0x3002, &[0, 0]), Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(0x3000, 4), Some(0x3000));
+    assert_eq!(emu.mem_write(0x3000, &[0, 0]), Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(0x3000, 8), None);
+    emu.shadow_mut().unwrap().clear_reads();
+    emu.set_crash_pc(0);
+    assert_eq!(emu.emu_start(0x1000, 0x1015, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert!(emu.shadow().unwrap().reads().is_empty());
+
+    // host memory is tracked like memory mapped by the engine
+    let mut page = vec![0u8; 0x1000];
+    let ptr = page.as_mut_ptr().cast();
+    assert_eq!(unsafe { emu.mem_map_ptr(0x5000, 0x1000, Permission::READ, ptr) }, Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(0x5000, 4), Some(0x5000));
+    assert_eq!(emu.mem_unmap(0x5000, 0x1000), Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(0x5000, 4), None);
+    assert!(emu.shadow_disable().unwrap().is_some());
+
+    // memory ending at the top of the address space
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.shadow_enable(true), Ok(()));
+    let top = 0xffff_ffff_ffff_f000;
+    assert_eq!(emu.mem_map(top, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(top + 0xffc, 4), Some(top + 0xffc));
+    assert_eq!(emu.mem_write(top + 0xffc, &[0; 4]), Ok(()));
+    assert_eq!(emu.shadow().unwrap().first_uninit(top + 0xffc, 4), None);
+    assert_eq!(emu.shadow().unwrap().first_uninit(top, 0x1000), Some(top));
+}
+
+#[test]
+fn x86_crash_report() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    emu.crash_reports_enable();
+
+    let x86_code32: Vec<u8> = vec![
+        0x55, // PUSH ebp
+        0x89, 0xe5, // MOV ebp, esp
+        0xe8, 0x08, 0x00, 0x00, 0x00, // CALL 0x1010
+        0x90, 0x90, 0x90, 0x90, 0x90, 0x90, 0x90, 0x90, // NOP
+        0x55, // 0x1010: PUSH ebp
+        0x89, 0xe5, // MOV ebp, esp
+        0xa3, 0x00, 0x00, 0x00, 0x00, // MOV dword ptr [0], eax
+    ];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    let mut buckets = vec![];
+    for eax in [1, 2] {
+        assert_eq!(emu.reg_write(RegisterX86::ESP, 0x7f00), Ok(()));
+        assert_eq!(emu.reg_write(RegisterX86::EBP, 0), Ok(()));
+        assert_eq!(emu.reg_write(RegisterX86::EAX, eax), Ok(()));
+        assert_eq!(
+            emu.emu_start(0x1000, 0x1008, 0, 0),
+            Ok(EmuExit::Fault {
+                address: 0,
+                access: MemType::WRITE_UNMAPPED,
+                pc: 0x1013
+            })
+        );
+        let report = emu.take_crash_report().expect("no crash report recorded");
+        assert_eq!(report.pc, 0x1013);
+        assert_eq!(
+            report.kind,
+            CrashKind::Fault {
+                access: MemType::WRITE_UNMAPPED,
+                address: 0
+            }
+        );
+        assert_eq!(report.backtrace, vec![0x1013, 0x1008]);
+        assert!(report.region.is_none());
+        assert!(report.registers.contains(&("eax", eax)));
+        assert_eq!(report.bucket, report.bucket_hash(BUCKET_FRAMES));
+        buckets.push(report.bucket);
+    }
+    // same bug, same bucket
+    assert_eq!(buckets[0], buckets[1]);
+    assert!(emu.crash_report().is_none());
+
+    emu.set_crash_pc(0x1010);
+    let report = emu.crash_report().expect("no crash report recorded");
+    assert_eq!(report.kind, CrashKind::Reported);
+    assert_eq!(report.region.as_ref().map(|r| r.begin), Some(0x1000));
+    assert_ne!(report.bucket, buckets[0]);
+
+    // a report does not outlive the next run
+    assert_eq!(emu.emu_start(0x1008, 0x1009, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert!(emu.crash_report().is_none());
+
+    emu.crash_reports_disable();
+    assert_eq!(emu.reg_write(RegisterX86::ESP, 0x7f00), Ok(()));
+    assert!(matches!(
+        emu.emu_start(0x1000, 0x1008, 0, 0),
+        Ok(EmuExit::Fault { .. })
+    ));
+    assert!(emu.crash_report().is_none());
+}
+
+#[test]
+fn arm_crash_backtrace() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::LITTLE_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    emu.crash_reports_enable();
+
+    let arm_code32: Vec<u8> = vec![
+        0x00, 0x48, 0x2d, 0xe9, // PUSH {fp, lr}
+        0x04, 0xb0, 0x8d, 0xe2, // ADD fp, sp, #4
+        0x00, 0x00, 0x00, 0xeb, // BL 0x1010
+        0x00, 0x00, 0xa0, 0xe1, // NOP
+        0x00, 0x48, 0x2d, 0xe9, // 0x1010: PUSH {fp, lr}
+        0x04, 0xb0, 0x8d, 0xe2, // ADD fp, sp, #4
+        0x00, 0x00, 0x80, 0xe5, // STR r0, [r0]
+    ];
+    assert_eq!(emu.mem_write(0x1000, &arm_code32), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::SP, 0x7f00), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::R0, 0), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::R11, 0), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::LR, 0), Ok(()));
+    assert!(matches!(
+        emu.emu_start(0x1000, 0x100c, 0, 0),
+        Ok(EmuExit::Fault { pc: 0x1018, .. })
+    ));
+    let report = emu.crash_report().expect("no crash report recorded");
+    assert_eq!(report.backtrace, vec![0x1018, 0x100c]);
+}
+
+#[test]
+fn x86_shadow_call_stack() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.call_stack_enable(true), Ok(()));
+    emu.crash_reports_enable();
+
+    assert_eq!(emu.mem_write(0x1020, &[0xc3]), Ok(())); // RET
+    assert_eq!(emu.mem_write(0x1030, &[0xe8, 0xeb, 0xff, 0xff, 0xff]), Ok(())); // CALL 0x1020
+    let frames = Rc::new(RefCell::new(vec![]));
+    let seen = frames.clone();
+    let hook = emu
+        .add_code_hook(0x1020, 0x1020, move |uc, _, _| {
+            *seen.borrow_mut() = uc.call_stack().unwrap().to_vec();
+        })
+        .expect("failed to add code hook");
+    assert_eq!(emu.reg_write(RegisterX86::ESP, 0x7f00), Ok(()));
+    assert_eq!(emu.emu_start(0x1030, 0x1035, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(
+        *frames.borrow(),
+        vec![CallFrame {
+            call_site: 0x1030,
+            return_address: 0x1035
+        }]
+    );
+    assert_eq!(emu.call_stack(), Some(&[][..]));
+    assert_eq!(emu.remove_hook(hook), Ok(()));
+
+    let x86_code32: Vec<u8> = vec![
+        0xe8, 0x0b, 0x00, 0x00, 0x00, // CALL 0x1010
+        0x90, // NOP
+    ];
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    // MOV dword ptr [esp], 0x1050; RET
+    let x86_code32: Vec<u8> = vec![0xc7, 0x04, 0x24, 0x50, 0x10, 0x00, 0x00, 0xc3];
+    assert_eq!(emu.mem_write(0x1010, &x86_code32), Ok(()));
+    assert_eq!(emu.reg_write(RegisterX86::ESP, 0x7f00), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0x1006, 0, 0), Ok(EmuExit::StoppedByHook));
+    let report = emu.crash_report().expect("stack smash not detected");
+    assert_eq!(report.pc, 0x1017);
+    assert_eq!(
+        report.kind,
+        CrashKind::StackSmash {
+            expected: 0x1005,
+            actual: 0x1050
+        }
+    );
+    assert_eq!(report.backtrace, vec![0x1017, 0x1005]);
+    assert_eq!(emu.call_stack_disable(), Ok(()));
+    assert_eq!(emu.call_stack(), None);
+}
+
+#[test]
+fn arm_shadow_call_stack_conditions() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::LITTLE_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.call_stack_enable(true), Ok(()));
+    emu.crash_reports_enable();
+
+    // BL 0x1100; NOP
+    assert_eq!(emu.mem_write(0x1000, &[0x3e, 0x00, 0x00, 0xeb, 0x00, 0x00, 0xa0, 0xe1]), Ok(()));
+    let arm_code32: Vec<u8> = vec![
+        0x00, 0x00, 0x50, 0xe1, // CMP r0, r0
+        0x1e, 0xff, 0x2f, 0x11, // BXNE lr
+        0x00, 0x80, 0xbd, 0x18, // POPNE {pc}
+        0x3c, 0x00, 0x00, 0x1b, // BLNE 0x1200
+        0x1e, 0xff, 0x2f, 0xe1, // BX lr
+    ];
+    assert_eq!(emu.mem_write(0x1100, &arm_code32), Ok(()));
+    assert_eq!(emu.mem_write(0x7f00, &0xdead_0000u32.to_le_bytes()), Ok(()));
+    let frames = Rc::new(RefCell::new(vec![]));
+    let seen = frames.clone();
+    emu.add_code_hook(0x1110, 0x1110, move |uc, _, _| {
+        *seen.borrow_mut() = uc.call_stack().unwrap().to_vec();
+    })
+    .expect("failed to add code hook");
+    assert_eq!(emu.reg_write(RegisterARM::SP, 0x7f00), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0x1008, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert!(emu.crash_report().is_none());
+    assert_eq!(
+        *frames.borrow(),
+        vec![CallFrame {
+            call_site: 0x1000,
+            return_address: 0x1004
+        }]
+    );
+    assert_eq!(emu.call_stack(), Some(&[][..]));
+
+    // thumb: BLX r1; NOP, then CMP r0, r0; IT NE; POPNE {pc}; BX lr
+    assert_eq!(emu.mem_write(0x1200, &[0x88, 0x47, 0x00, 0xbf]), Ok(()));
+    assert_eq!(emu.mem_write(0x1300, &[0x80, 0x42, 0x18, 0xbf, 0x00, 0xbd, 0x70, 0x47]), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::R1, 0x1301), Ok(()));
+    assert_eq!(emu.reg_write(RegisterARM::SP, 0x7f00), Ok(()));
+    assert_eq!(emu.emu_start(0x1201, 0x1204, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert!(emu.crash_report().is_none());
+    assert_eq!(emu.call_stack(), Some(&[][..]));
+}
+
+#[test]
+fn mips_shadow_call_stack_conditions() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.call_stack_enable(true), Ok(()));
+
+    let mips_code32: Vec<u8> = vec![
+        0x24, 0x08, 0x00, 0x01, // ADDIU t0, zero, 1
+        0x05, 0x10, 0x00, 0x3e, // BLTZAL t0, 0x1100
+        0x00, 0x00, 0x00, 0x00, // NOP
+        0x05, 0x11, 0x00, 0x3c, // BGEZAL t0, 0x1100
+        0x00, 0x00, 0x00, 0x00, // NOP
+    ];
+    assert_eq!(emu.mem_write(0x1000, &mips_code32), Ok(()));
+    // JR ra; NOP
+    assert_eq!(emu.mem_write(0x1100, &[0x03, 0xe0, 0x00, 0x08, 0, 0, 0, 0]), Ok(()));
+    let frames = Rc::new(RefCell::new(vec![]));
+    let seen = frames.clone();
+    emu.add_code_hook(0x1100, 0x1100, move |uc, _, _| {
+        *seen.borrow_mut() = uc.call_stack().unwrap().to_vec();
+    })
+    .expect("failed to add code hook");
+    assert_eq!(emu.emu_start(0x1000, 0x1014, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(
+        *frames.borrow(),
+        vec![CallFrame {
+            call_site: 0x100c,
+            return_address: 0x1014
+        }]
+    );
+    assert_eq!(emu.call_stack(), Some(&[][..]));
+}
+
+#[test]
+fn x86_cmplog() {
+    let x86_code: Vec<u8> = vec![
+        0xb8, 0x34, 0x12, 0x00, 0x00, // MOV eax, 0x1234
+        0x3d, 0xef, 0xbe, 0xad, 0xde, // CMP eax, 0xdeadbeef
+        0xbf, 0x00, 0x30, 0x00, 0x00, // MOV edi, 0x3000
+        0xbe, 0x10, 0x30, 0x00, 0x00, // MOV esi, 0x3010
+        0xba, 0x04, 0x00, 0x00, 0x00, // MOV edx, 4
+        0xe8, 0xe2, 0x00, 0x00, 0x00, // CALL 0x1100
+        0x90, // NOP
+    ];
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x3000, 0x1000, Permission::READ), Ok(()));
+    assert_eq!(emu.mem_map(0x7000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code), Ok(()));
+    assert_eq!(emu.mem_write(0x1100, &[0x31, 0xc0, 0xc3]), Ok(())); // XOR eax, eax; RET
+    assert_eq!(emu.mem_write(0x3000, b"ABCD"), Ok(()));
+    assert_eq!(emu.mem_write(0x3010, b"WXYZ"), Ok(()));
+
+    assert_eq!(emu.cmplog_add_routine(0x1100, Routine::Memcmp), Err(uc_error::ARG));
+    assert_eq!(emu.cmplog_enable(3, 0x1000, 0x1fff), Err(uc_error::ARG));
+    assert_eq!(emu.cmplog_enable(DEFAULT_WIDTH, 0x1000, 0x1fff), Ok(()));
+    assert_eq!(emu.cmplog_add_routine(0x1100, Routine::Memcmp), Ok(()));
+
+    for _ in 0..2 {
+        assert_eq!(emu.reg_write(RegisterX86::RSP, 0x7f00), Ok(()));
+        assert_eq!(emu.emu_start(0x1000, 0x101e, 0, 0), Ok(EmuExit::ReachedUntil));
+    }
+    let log = emu.cmplog().expect("cmplog not enabled");
+    assert_eq!(log.entries().count(), 2);
+
+    let cmp = log.entry(0x1005).expect("compare not logged");
+    assert_eq!(cmp.kind, CmpKind::Insn);
+    assert_eq!(cmp.hits, 2);
+    assert_eq!(cmp.operands()[0].v0(), &0x1234u32.to_le_bytes());
+    assert_eq!(cmp.operands()[0].v1(), &0xdeadbeefu32.to_le_bytes());
+
+    let call = log.entry(0x101e).expect("memcmp not logged");
+    assert_eq!(call.kind, CmpKind::Routine);
+    assert_eq!(call.operands().len(), 2);
+    assert_eq!(call.operands()[1].v0(), b"ABCD");
+    assert_eq!(call.operands()[1].v1(), b"WXYZ");
+
+    emu.cmplog_mut().unwrap().reset();
+    assert_eq!(emu.cmplog().unwrap().entries().count(), 0);
+    let log = emu.cmplog_disable().expect("failed to disable cmplog");
+    assert_eq!(log.map(|log| log.width()), Some(DEFAULT_WIDTH));
+    assert!(emu.cmplog().is_none());
+}
+
+#[test]
+fn x86_deterministic() {
+    let x86_code32: Vec<u8> = vec![
+        0x90, // NOP
+        0x0f, 0x31, // RDTSC
+        0xeb, 0xfe, // JMP $
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    assert_eq!(emu.ctl_get_deterministic(), Ok(false));
+    assert_eq!(
+        emu.ctl_set_deterministic(Some(Deterministic { ns_per_insn: 0 })),
+        Err(uc_error::ARG)
+    );
+    assert_eq!(emu.ctl_set_deterministic(Some(Deterministic::default())), Ok(()));
+    assert_eq!(emu.ctl_get_deterministic(), Ok(true));
+
+    let mut tsc = vec![];
+    for _ in 0..2 {
+        assert_eq!(emu.ctl_set_virtual_clock(0), Ok(()));
+        // 10 microseconds are 10000 instructions of the virtual clock
+        assert_eq!(emu.emu_start(0x1000, 0x1005, 10, 0), Ok(EmuExit::Timeout));
+        let clock = emu.ctl_get_virtual_clock().unwrap();
+        assert!((10_000..10_010).contains(&clock));
+        tsc.push((emu.reg_read(RegisterX86::EAX).unwrap(), clock));
+    }
+    assert_ne!(tsc[0].0, 0);
+    assert_eq!(tsc[0], tsc[1]);
+
+    assert_eq!(emu.ctl_set_deterministic(None), Ok(()));
+    assert_eq!(emu.ctl_get_deterministic(), Ok(false));
+}
+
+#[test]
+fn mips_deterministic_count() {
+    let mips_code32el: Vec<u8> = vec![
+        0x00, 0x00, 0x00, 0x00, // nop
+        0x00, 0x00, 0x00, 0x00, // nop
+        0x00, 0x48, 0x02, 0x40, // mfc0 $v0, $9 (Count)
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::MIPS, Mode::MODE_32 | Mode::LITTLE_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x4000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &mips_code32el), Ok(()));
+    assert_eq!(
+        emu.ctl_set_deterministic(Some(Deterministic { ns_per_insn: 100 })),
+        Ok(())
+    );
+
+    let mut counts = vec![];
+    for _ in 0..2 {
+        assert_eq!(emu.ctl_set_virtual_clock(1000), Ok(()));
+        assert_eq!(emu.emu_start(0x1000, 0x100c, 0, 0), Ok(EmuExit::ReachedUntil));
+        counts.push(emu.reg_read(RegisterMIPS::V0).unwrap());
+    }
+    // Count ticks every 10 ns of the virtual clock
+    assert!(counts[0] >= 100);
+    assert_eq!(counts[0], counts[1]);
+
+    // without deterministic mode Count does not leak the host clock
+    assert_eq!(emu.ctl_set_deterministic(None), Ok(()));
+    assert_eq!(emu.reg_write(RegisterMIPS::V0, 1), Ok(()));
+    assert_eq!(emu.emu_start(0x1000, 0x100c, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(emu.reg_read(RegisterMIPS::V0), Ok(0));
+}
+
+#[test]
+fn x86_trace_recorder() {
+    let x86_code32: Vec<u8> = vec![
+        0xa1, 0x00, 0x20, 0x00, 0x00, // MOV eax, [0x2000]
+        0xa3, 0x04, 0x20, 0x00, 0x00, // MOV [0x2004], eax
+        0x90, // NOP
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x2000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, &0x11223344u32.to_le_bytes()), Ok(()));
+
+    // flush after every record, the chunks must add up to the same trace
+    let chunks = Rc::new(RefCell::new(vec![]));
+    let sink = chunks.clone();
+    let config = TraceConfig {
+        memory: true,
+        buffer_size: 1,
+        ..Default::default()
+    };
+    let recorder = Recorder::start(&mut emu, config, move |data: &[u8]| {
+        sink.borrow_mut().push(data.to_vec());
+    })
+    .expect("failed to start recording");
+    assert_eq!(emu.emu_start(0x1000, 0x100b, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(recorder.borrow_mut().finish(&mut emu), Ok(()));
+    assert!(chunks.borrow().len() > 1);
+    let trace = chunks.borrow().concat();
+
+    let reader = TraceReader::new(&trace).expect("bad trace header");
+    assert_eq!(reader.header().arch, Arch::X86);
+    assert_eq!(reader.header().mode, Mode::MODE_32);
+    assert_eq!(reader.header().flags, TraceFlags::all());
+    let events: Result<Vec<_>, _> = reader.collect();
+    assert_eq!(
+        events,
+        Ok(vec![
+            TraceEvent::Block { address: 0x1000, size: 11 },
+            TraceEvent::Insn { address: 0x1000, size: 5 },
+            TraceEvent::Read { address: 0x2000, size: 4, value: 0x11223344 },
+            TraceEvent::Insn { address: 0x1005, size: 5 },
+            TraceEvent::Write { address: 0x2004, size: 4, value: 0x11223344 },
+            TraceEvent::Insn { address: 0x100a, size: 1 },
+        ])
+    );
+
+    // the block runs out of the range, the write after it is not recorded
+    let config = TraceConfig {
+        ranges: vec![0x1000..=0x1004],
+        memory: true,
+        ..Default::default()
+    };
+    let recorder = Recorder::start(&mut emu, config, vec![]).expect("failed to start recording");
+    assert_eq!(emu.emu_start(0x1000, 0x100b, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(recorder.borrow_mut().finish(&mut emu), Ok(()));
+    let events: Result<Vec<_>, _> = TraceReader::new(recorder.borrow().sink())
+        .expect("bad trace header")
+        .collect();
+    assert_eq!(
+        events,
+        Ok(vec![
+            TraceEvent::Block { address: 0x1000, size: 11 },
+            TraceEvent::Insn { address: 0x1000, size: 5 },
+            TraceEvent::Read { address: 0x2000, size: 4, value: 0x11223344 },
+        ])
+    );
+
+    // nothing but the header outside of the recorded ranges
+    let config = TraceConfig {
+        ranges: vec![0x3000..=0x3fff],
+        instructions: true,
+        ..Default::default()
+    };
+    let recorder = Recorder::start(&mut emu, config, vec![]).expect("failed to start recording");
+    assert_eq!(emu.emu_start(0x1000, 0x100b, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(recorder.borrow_mut().finish(&mut emu), Ok(()));
+    let recorder = recorder.borrow();
+    let reader = TraceReader::new(recorder.sink()).expect("bad trace header");
+    assert_eq!(reader.header().flags, TraceFlags::BLOCKS | TraceFlags::INSTRUCTIONS);
+    assert_eq!(reader.count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn x86_trace_io_sink() {
+    use unicorn_engine::trace::IoSink;
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &[0x90, 0x90]), Ok(())); // NOP; NOP
+
+    let path = std::env::temp_dir().join(format!("unicorn-trace-{}", std::process::id()));
+    let file = std::fs::File::create(&path).expect("failed to create trace file");
+    let recorder = Recorder::start(&mut emu, TraceConfig::default(), IoSink::new(file))
+        .expect("failed to start recording");
+    assert_eq!(emu.emu_start(0x1000, 0x1002, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(recorder.borrow_mut().finish(&mut emu), Ok(()));
+    assert!(recorder.borrow().sink().error().is_none());
+    let trace = std::fs::read(&path).expect("failed to read trace file");
+    std::fs::remove_file(&path).expect("failed to remove trace file");
+    let events: Result<Vec<_>, _> = TraceReader::new(&trace).expect("bad trace header").collect();
+    assert_eq!(events, Ok(vec![TraceEvent::Block { address: 0x1000, size: 2 }]));
+}
+
+#[test]
+fn x86_drcov_coverage() {
+    let x86_code32: Vec<u8> = vec![
+        0x40, // INC eax
+        0xeb, 0x01, // JMP 0x1004
+        0x90, // NOP
+        0x40, // INC eax
+        0x90, // NOP
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+
+    let modules = vec![Module::new("code.bin", 0x1000, 0x1fff)];
+    let first = Coverage::start(&mut emu, modules).expect("failed to start coverage");
+    assert_eq!(emu.emu_start(0x1000, 0x1006, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(first.borrow_mut().finish(&mut emu), Ok(()));
+    assert_eq!(
+        first.borrow().blocks().copied().collect::<Vec<_>>(),
+        vec![
+            BasicBlock { module: 0, offset: 0, size: 3 },
+            BasicBlock { module: 0, offset: 4, size: 2 },
+        ]
+    );
+
+    // without modules, every region is one
+    let second = Coverage::start(&mut emu, vec![]).expect("failed to start coverage");
+    assert_eq!(emu.emu_start(0x1003, 0x1006, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(second.borrow_mut().finish(&mut emu), Ok(()));
+    assert_eq!(second.borrow().modules(), &[Module::new("region_0x1000", 0x1000, 0x1fff)]);
+    assert_eq!(second.borrow().len(), 1);
+
+    let mut merged = first.borrow().clone();
+    merged.merge(&second.borrow());
+    assert_eq!(merged.modules().len(), 2);
+    assert_eq!(merged.len(), 3);
+
+    // the same module in another run merges into the existing blocks
+    let mut again = Coverage::new(vec![Module::new("code.bin", 0x8000, 0x8fff)]);
+    assert!(again.add_block(0x8000, 3));
+    assert!(!again.add_block(0x8000, 3));
+    assert!(!again.add_block(0x9000, 1));
+    merged.merge(&again);
+    assert_eq!(merged.len(), 3);
+
+    let data = merged.to_bytes();
+    assert!(data.starts_with(b"DRCOV VERSION: 2\n"));
+    let parsed = Coverage::parse(&data).expect("failed to parse coverage");
+    assert_eq!(parsed.modules(), merged.modules());
+    assert!(parsed.blocks().eq(merged.blocks()));
+    assert!(Coverage::parse(&data[..data.len() - 1]).is_err());
+
+    // without modules the block table follows the column header
+    let empty = Coverage::parse(&Coverage::new(vec![]).to_bytes()).expect("failed to parse coverage");
+    assert!(empty.modules().is_empty());
+    assert_eq!(empty.len(), 0);
+
+    // offsets are 32 bits in drcov files
+    let mut large = Coverage::new(vec![Module::new("large.bin", 0, 0x1_ffff_ffff)]);
+    assert!(large.add_block(0xffff_0000, 4));
+    assert!(!large.add_block(0x1_0000_0000, 4));
+    assert_eq!(large.len(), 1);
+}
+
+#[test]
+fn x86_tenet_trace() {
+    let x86_code32: Vec<u8> = vec![
+        0xa1, 0x00, 0x20, 0x00, 0x00, // MOV eax, [0x2000]
+        0xa3, 0x04, 0x20, 0x00, 0x00, // MOV [0x2004], eax
+        0x90, // NOP
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x1000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_map(0x2000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &x86_code32), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, &0x11223344u32.to_le_bytes()), Ok(()));
+
+    let tracer = Tracer::start(&mut emu, vec![]).expect("failed to start tracing");
+    assert_eq!(emu.emu_start(0x1000, 0x100b, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(tracer.borrow_mut().finish(&mut emu), Ok(()));
+    let tracer = tracer.borrow();
+    let trace = core::str::from_utf8(tracer.sink()).expect("trace is not text");
+    assert_eq!(
+        trace.lines().collect::<Vec<_>>(),
+        vec![
+            "eax=0x0,ebx=0x0,ecx=0x0,edx=0x0,ebp=0x0,esp=0x0,esi=0x0,edi=0x0,eip=0x1000",
+            "eax=0x11223344,eip=0x1005,mr=0x2000:44332211",
+            "eip=0x100a,mw=0x2004:44332211",
+            "eip=0x100b",
+        ]
+    );
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::MIPS, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert!(matches!(Tracer::start(&mut emu, vec![]), Err(uc_error::ARCH)));
+}
+
+/// A hand-made i386 executable: code at 0x1080 copying the word at 0x2000 to 0x2004,
+/// a data segment at 0x2000 with 12 bytes of .bss and a symbol `main` at the entry.
+fn x86_elf32() -> Vec<u8> {
+    let mut elf = vec![0u8; 0x200];
+    let mut put = |offset: usize, bytes: &[u8]| elf[offset..offset + bytes.len()].copy_from_slice(bytes);
+    put(0, b"\x7fELF\x01\x01\x01");
+    put(16, &2u16.to_le_bytes()); // ET_EXEC
+    put(18, &3u16.to_le_bytes()); // EM_386
+    put(20, &1u32.to_le_bytes());
+    put(24, &0x1080u32.to_le_bytes()); // e_entry
+    put(28, &52u32.to_le_bytes()); // e_phoff
+    put(32, &0x160u32.to_le_bytes()); // e_shoff
+    put(40, &[52, 0, 32, 0, 2, 0, 40, 0, 4, 0, 3, 0]);
+    for (i, (offset, vaddr, filesz, memsz, flags)) in
+        [(0u32, 0x1000u32, 0x100u32, 0x100u32, 5u32), (0x100, 0x2000, 4, 0x10, 6)].iter().enumerate()
+    {
+        let ph = 52 + i * 32;
+        put(ph, &1u32.to_le_bytes()); // PT_LOAD
+        put(ph + 4, &offset.to_le_bytes());
+        put(ph + 8, &vaddr.to_le_bytes());
+        put(ph + 16, &filesz.to_le_bytes());
+        put(ph + 20, &memsz.to_le_bytes());
+        put(ph + 24, &flags.to_le_bytes());
+        put(ph + 28, &0x1000u32.to_le_bytes());
+    }
+    put(0x80, &[0xa1, 0x00, 0x20, 0x00, 0x00, 0xa3, 0x04, 0x20, 0x00, 0x00]);
+    put(0x100, &0x11223344u32.to_le_bytes());
+    put(0x104, b"\0main\0");
+    put(0x10c, b"\0.symtab\0.strtab\0.shstrtab\0");
+    // main: GLOBAL FUNC in section 1
+    put(0x150, &[1, 0, 0, 0, 0x80, 0x10, 0, 0, 10, 0, 0, 0, 0x12, 0, 1, 0]);
+    for (i, (name, kind, offset, size, link, entsize)) in [
+        (1u32, 2u32, 0x140u32, 32u32, 2u32, 16u32),
+        (9, 3, 0x104, 6, 0, 0),
+        (17, 3, 0x10c, 27, 0, 0),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let sh = 0x160 + (i + 1) * 40;
+        put(sh, &name.to_le_bytes());
+        put(sh + 4, &kind.to_le_bytes());
+        put(sh + 16, &offset.to_le_bytes());
+        put(sh + 20, &size.to_le_bytes());
+        put(sh + 24, &link.to_le_bytes());
+        put(sh + 36, &entsize.to_le_bytes());
+    }
+    elf
+}
+
+#[test]
+fn x86_elf_loader() {
+    let data = x86_elf32();
+    let parsed = Elf::parse(&data).expect("failed to parse elf");
+    assert_eq!(parsed.arch_mode(), Ok((Arch::X86, Mode::MODE_32)));
+    assert_eq!(parsed.section_headers[1].name, ".symtab");
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let image = elf::load(&mut emu, &data, 0).expect("failed to load elf");
+    assert_eq!(image.entry, 0x1080);
+    assert_eq!(image.bias, 0);
+    assert_eq!(image.phdr, Some(0x1034));
+    assert_eq!(image.bounds(), Some((0x1000, 0x200f)));
+    let main = image.symbol("main").expect("main is missing");
+    assert_eq!((main.address, main.size, main.kind), (0x1080, 10, SymbolKind::Func));
+    assert_eq!(emu.get_pc(), Ok(0x1080));
+
+    let regions = emu.mem_regions().expect("failed to read regions");
+    assert_eq!(regions.len(), 2);
+    assert_eq!((regions[0].begin, regions[0].perms), (0x1000, Permission::READ | Permission::EXEC));
+    assert_eq!((regions[1].begin, regions[1].perms), (0x2000, Permission::READ | Permission::WRITE));
+
+    assert_eq!(emu.emu_start(0x1080, 0x108a, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(emu.mem_read_as_vec(0x2000, 16), Ok(vec![0x44, 0x33, 0x22, 0x11, 0x44, 0x33, 0x22, 0x11, 0, 0, 0, 0, 0, 0, 0, 0]));
+
+    // position independent images move to the base
+    let mut pie = data.clone();
+    pie[16] = 3;
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let image = elf::map(&mut emu, &pie, 0x40_0000).expect("failed to load elf");
+    assert_eq!(image.bias, 0x3f_f000);
+    assert_eq!(image.entry, 0x40_0080);
+    assert_eq!(image.symbol("main").map(|s| s.address), Some(0x40_0080));
+    assert_eq!(emu.mem_read_as_vec(0x40_1000, 4), Ok(vec![0x44, 0x33, 0x22, 0x11]));
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(
+        elf::load(&mut emu, &data, 0),
+        Err(ElfError::ArchMismatch { arch: Arch::X86, mode: Mode::MODE_32 })
+    );
+    assert_eq!(Elf::parse(&data[..40]).err(), Some(ElfError::Truncated));
+}
+
+/// A hand-made executable for `machine` without section headers: the file mapped as one
+/// RWX segment at 0x10000, `code` at 0x10100 and the entry there, or `entry` bits above
+/// it, e.g. 1 for Thumb.
+fn elf_image(
+    machine: u16,
+    is_64bit: bool,
+    big_endian: bool,
+    flags: u32,
+    entry: u64,
+    code: &[u8],
+) -> Vec<u8> {
+    let mut elf = vec![0u8; 0x200];
+    let mut put = |offset: usize, value: u64, size: usize| {
+        let mut bytes = value.to_le_bytes()[..size].to_vec();
+        if big_endian {
+            bytes.reverse();
+        }
+        elf[offset..offset + size].copy_from_slice(&bytes);
+    };
+    let w = if is_64bit { 8 } else { 4 };
+    put(16, 2, 2); // ET_EXEC
+    put(18, u64::from(machine), 2);
+    put(20, 1, 4);
+    put(24, 0x10100 + entry, w);
+    put(24 + w, 24 + 3 * w as u64 + 16, w); // e_phoff, right after the header
+    put(24 + 3 * w, u64::from(flags), 4);
+    put(24 + 3 * w + 4, 24 + 3 * w as u64 + 16, 2);
+    put(24 + 3 * w + 6, if is_64bit { 56 } else { 32 }, 2);
+    put(24 + 3 * w + 8, 1, 2);
+    let ph = 24 + 3 * w + 16;
+    put(ph, 1, 4); // PT_LOAD
+    if is_64bit {
+        put(ph + 4, 7, 4);
+        put(ph + 16, 0x10000, 8);
+        put(ph + 32, 0x200, 8);
+        put(ph + 40, 0x1000, 8);
+        put(ph + 48, 0x1000, 8);
+    } else {
+        put(ph + 8, 0x10000, 4);
+        put(ph + 16, 0x200, 4);
+        put(ph + 20, 0x1000, 4);
+        put(ph + 24, 7, 4);
+        put(ph + 28, 0x1000, 4);
+    }
+    elf[..7].copy_from_slice(&[
+        0x7f,
+        b'E',
+        b'L',
+        b'F',
+        if is_64bit { 2 } else { 1 },
+        if big_endian { 2 } else { 1 },
+        1,
+    ]);
+    elf[0x100..0x100 + code.len()].copy_from_slice(code);
+    elf
+}
+
+#[test]
+fn elf_arch_modes() {
+    const EF_ARM_BE8: u32 = 0x0080_0000;
+    let cases = [
+        (40, false, false, 0, 0, (Arch::ARM, Mode::ARM)),
+        (40, false, false, 0, 1, (Arch::ARM, Mode::THUMB)),
+        (40, false, true, EF_ARM_BE8, 0, (Arch::ARM, Mode::ARM | Mode::ARMBE8)),
+        (40, false, true, 0, 0, (Arch::ARM, Mode::ARM | Mode::BIG_ENDIAN)),
+        (183, true, false, 0, 0, (Arch::ARM64, Mode::ARM)),
+        (8, false, true, 0, 0, (Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN)),
+        (8, false, false, 0, 0, (Arch::MIPS, Mode::MODE_32 | Mode::LITTLE_ENDIAN)),
+        (8, true, true, 0, 0, (Arch::MIPS, Mode::MODE_64 | Mode::BIG_ENDIAN)),
+        (20, false, true, 0, 0, (Arch::PPC, Mode::MODE_32 | Mode::BIG_ENDIAN)),
+        (21, true, true, 0, 0, (Arch::PPC, Mode::MODE_64 | Mode::BIG_ENDIAN)),
+        (243, true, false, 0, 0, (Arch::RISCV, Mode::MODE_64)),
+    ];
+    for (machine, is_64bit, big_endian, flags, entry, arch_mode) in cases {
+        let data = elf_image(machine, is_64bit, big_endian, flags, entry, &[]);
+        let parsed = Elf::parse(&data).expect("failed to parse elf");
+        assert_eq!((parsed.is_64bit, parsed.big_endian), (is_64bit, big_endian));
+        assert_eq!(parsed.entry, 0x10100 + entry);
+        assert_eq!(parsed.arch_mode(), Ok(arch_mode), "machine {}", machine);
+    }
+}
+
+#[test]
+fn elf_load_per_arch() {
+    /// `e_machine`, 64-bit, big endian, `e_flags`, entry offset, code, mode and the
+    /// register the code sets to 42.
+    type Case = (u16, bool, bool, u32, u64, &'static [u8], Arch, Mode, i32);
+    let cases: [Case; 6] = [
+        // mov r0, #42
+        (40, false, false, 0, 0, &[0x2a, 0x00, 0xa0, 0xe3], Arch::ARM, Mode::ARM, RegisterARM::R0 as i32),
+        // movs r0, #42
+        (40, false, false, 0, 1, &[0x2a, 0x20], Arch::ARM, Mode::THUMB, RegisterARM::R0 as i32),
+        // mov r0, #42, code stays little endian in BE8 images
+        (
+            40,
+            false,
+            true,
+            0x0080_0000,
+            0,
+            &[0x2a, 0x00, 0xa0, 0xe3],
+            Arch::ARM,
+            Mode::ARM | Mode::ARMBE8,
+            RegisterARM::R0 as i32,
+        ),
+        // addiu $v0, $zero, 42
+        (
+            8,
+            false,
+            true,
+            0,
+            0,
+            &[0x24, 0x02, 0x00, 0x2a],
+            Arch::MIPS,
+            Mode::MODE_32 | Mode::BIG_ENDIAN,
+            RegisterMIPS::V0 as i32,
+        ),
+        // li r3, 42
+        (
+            20,
+            false,
+            true,
+            0,
+            0,
+            &[0x38, 0x60, 0x00, 0x2a],
+            Arch::PPC,
+            Mode::MODE_32 | Mode::BIG_ENDIAN,
+            RegisterPPC::R3 as i32,
+        ),
+        // li r3, 42
+        (
+            21,
+            true,
+            true,
+            0,
+            0,
+            &[0x38, 0x60, 0x00, 0x2a],
+            Arch::PPC,
+            Mode::MODE_64 | Mode::BIG_ENDIAN,
+            RegisterPPC::R3 as i32,
+        ),
+    ];
+    for (machine, is_64bit, big_endian, flags, entry, code, arch, mode, reg) in cases {
+        let data = elf_image(machine, is_64bit, big_endian, flags, entry, code);
+        let mut emu = unicorn_engine::Unicorn::new(arch, mode)
+            .expect("failed to initialize unicorn instance");
+        let image = elf::load(&mut emu, &data, 0).expect("failed to load elf");
+        assert_eq!((image.arch, image.mode), (arch, mode));
+        assert_eq!(image.entry, 0x10100 + entry);
+        assert_eq!(image.phdr, Some(0x10000 + if is_64bit { 64 } else { 52 }));
+        assert_eq!(image.bounds(), Some((0x10000, 0x10fff)));
+        assert_eq!(
+            emu.emu_start(image.entry, 0x10100 + code.len() as u64, 0, 0),
+            Ok(EmuExit::ReachedUntil)
+        );
+        assert_eq!(emu.reg_read(reg), Ok(42), "machine {}", machine);
+    }
+
+    // a segment wrapping around the address space
+    let mut data = elf_image(21, true, true, 0, 0, &[]);
+    data[64 + 16..64 + 24].copy_from_slice(&0xffff_ffff_ffff_f800u64.to_be_bytes());
+    let mut emu = unicorn_engine::Unicorn::new(Arch::PPC, Mode::MODE_64 | Mode::BIG_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(elf::map(&mut emu, &data, 0).err(), Some(ElfError::Truncated));
+    // a program header table beyond the end of the address space
+    data[32..40].copy_from_slice(&u64::MAX.to_be_bytes());
+    assert_eq!(Elf::parse(&data).err(), Some(ElfError::Truncated));
+}
+
+/// A hand-made dynamic i386 object without section headers, mapped as one RWX segment
+/// at `base`: code at +0x80, data at +0x100, `symbols` as `(name, value, defined)` and
+/// `relocs` as `(offset, type, symbol)`.
+fn x86_dyn_elf32(
+    kind: u16,
+    base: u32,
+    code: &[u8],
+    data: &[u8],
+    needed: &[&str],
+    symbols: &[(&str, u32, bool)],
+    relocs: &[(u32, u8, u32)],
+) -> Vec<u8> {
+    dyn_elf32(3, false, kind, base, code, data, needed, symbols, relocs)
+}
+
+/// Like `x86_dyn_elf32`, for any 32-bit `machine` with `Elf32_Rel` relocations.
+#[allow(clippy::too_many_arguments)]
+fn dyn_elf32(
+    machine: u16,
+    big_endian: bool,
+    kind: u16,
+    base: u32,
+    code: &[u8],
+    data: &[u8],
+    needed: &[&str],
+    symbols: &[(&str, u32, bool)],
+    relocs: &[(u32, u8, u32)],
+) -> Vec<u8> {
+    let mut elf = vec![0u8; 0x340];
+    let mut put = |offset: usize, bytes: &[u8]| elf[offset..offset + bytes.len()].copy_from_slice(bytes);
+    let u16b = |value: u16| if big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+    let u32b = |value: u32| if big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+    put(0, &[0x7f, b'E', b'L', b'F', 1, if big_endian { 2 } else { 1 }, 1]);
+    put(16, &u16b(kind));
+    put(18, &u16b(machine));
+    put(20, &u32b(1));
+    put(24, &u32b(base + 0x80));
+    put(28, &u32b(52));
+    put(40, &u16b(52));
+    put(42, &u16b(32));
+    put(44, &u16b(2));
+    put(46, &u16b(40));
+    // PT_LOAD of the whole file, PT_DYNAMIC
+    for (i, (ty, offset, size)) in [(1u32, 0u32, 0x340u32), (2, 0x280, 0x80)].iter().enumerate() {
+        let ph = 52 + i * 32;
+        put(ph, &u32b(*ty));
+        put(ph + 4, &u32b(*offset));
+        put(ph + 8, &u32b(base + offset));
+        put(ph + 16, &u32b(*size));
+        put(ph + 20, &u32b(*size));
+        put(ph + 24, &u32b(7));
+        put(ph + 28, &u32b(0x1000));
+    }
+    put(0x80, code);
+    put(0x100, data);
+
+    let mut strtab = vec![0u8];
+    let mut string = |s: &str| {
+        strtab.extend_from_slice(s.as_bytes());
+        strtab.push(0);
+        (strtab.len() - s.len() - 1) as u32
+    };
+    let needed: Vec<u32> = needed.iter().map(|n| string(n)).collect();
+    let names: Vec<u32> = symbols.iter().map(|(n, _, _)| string(n)).collect();
+    put(0x140, &strtab);
+    for (i, ((_, value, defined), name)) in symbols.iter().zip(names).enumerate() {
+        let sym = 0x1c0 + (i + 1) * 16;
+        put(sym, &u32b(name));
+        put(sym + 4, &u32b(*value));
+        put(sym + 12, &[0x10, 0]); // STB_GLOBAL
+        put(sym + 14, &u16b(*defined as u16));
+    }
+    for (i, (offset, ty, sym)) in relocs.iter().enumerate() {
+        put(0x240 + i * 8, &u32b(*offset));
+        put(0x244 + i * 8, &u32b((sym << 8) | u32::from(*ty)));
+    }
+    // DT_HASH with a single empty bucket, only its nchain matters
+    put(0x300, &u32b(1));
+    put(0x304, &u32b(symbols.len() as u32 + 1));
+
+    let mut dynamic: Vec<(u32, u32)> = needed.iter().map(|&n| (1, n)).collect();
+    dynamic.extend([
+        (4, base + 0x300),           // DT_HASH
+        (5, base + 0x140),           // DT_STRTAB
+        (10, strtab.len() as u32),   // DT_STRSZ
+        (6, base + 0x1c0),           // DT_SYMTAB
+        (17, base + 0x240),          // DT_REL
+        (18, relocs.len() as u32 * 8), // DT_RELSZ
+    ]);
+    for (i, (tag, value)) in dynamic.iter().enumerate() {
+        put(0x280 + i * 8, &u32b(*tag));
+        put(0x284 + i * 8, &u32b(*value));
+    }
+    elf
+}
+
+#[test]
+fn x86_elf_dynamic_linking() {
+    let lib = x86_dyn_elf32(3, 0, &[], &42u32.to_le_bytes(), &[], &[("answer", 0x100, true)], &[]);
+    let code = [
+        0xa1, 0x04, 0x11, 0x00, 0x00, // MOV eax, [0x1104]
+        0x8b, 0x00, // MOV eax, [eax]
+        0xff, 0x15, 0x00, 0x11, 0x00, 0x00, // CALL [0x1100]
+        0x90, // NOP
+    ];
+    let exe = x86_dyn_elf32(
+        2,
+        0x1000,
+        &code,
+        &[],
+        &["libanswer.so"],
+        &[("add_one", 0, false), ("answer", 0, false)],
+        &[(0x1100, 7, 1), (0x1104, 1, 2)], // R_386_JMP_SLOT, R_386_32
+    );
+    let resolve = |name: &str| if name == "libanswer.so" { Some(lib.clone()) } else { None };
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x8000, 0x1000, Permission::READ | Permission::WRITE), Ok(()));
+    assert_eq!(emu.reg_write(RegisterX86::ESP, 0x8800), Ok(()));
+    let mut linker = Linker::new(resolve);
+    linker.bind("add_one", |uc| uc.reg_read(RegisterX86::EAX).unwrap() + 1);
+    let image = linker.link(&mut emu, &exe).expect("failed to link");
+    assert_eq!(image.libraries.len(), 1);
+    assert_eq!(image.libraries[0].0, "libanswer.so");
+    assert_eq!(image.imports, vec![("add_one".to_string(), STUB_BASE)]);
+    assert_eq!(image.symbol("answer"), Some(LIBRARY_BASE + 0x100));
+    assert!(image.unsupported.is_empty());
+    assert_eq!(emu.get_pc(), Ok(0x1080));
+
+    assert_eq!(emu.emu_start(0x1080, 0x108d, 0, 0), Ok(EmuExit::ReachedUntil));
+    assert_eq!(emu.reg_read(RegisterX86::EAX), Ok(43));
+    assert_eq!(emu.reg_read(RegisterX86::ESP), Ok(0x8800));
+
+    // unbound imports no library defines fail the link
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let linker: Linker<()> = Linker::new(resolve);
+    assert_eq!(linker.link(&mut emu, &exe), Err(LinkError::Unresolved(vec!["add_one".to_string()])));
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let linker: Linker<()> = Linker::new(|_: &str| None);
+    assert_eq!(linker.link(&mut emu, &exe), Err(LinkError::MissingLibrary("libanswer.so".to_string())));
+}
+
+#[test]
+fn elf_dynamic_relocations() {
+    // the relocation types an absolute word, a PC relative word, a PLT slot and a
+    // relative word use, 0 for none
+    let cases = [
+        (3, false, Arch::X86, Mode::MODE_32, [1, 2, 7, 8]),
+        (40, false, Arch::ARM, Mode::ARM, [2, 3, 22, 23]),
+        (8, true, Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN, [2, 0, 127, 3]),
+        (20, true, Arch::PPC, Mode::MODE_32 | Mode::BIG_ENDIAN, [1, 26, 21, 22]),
+        (243, false, Arch::RISCV, Mode::RISCV32, [1, 0, 5, 3]),
+    ];
+    for (machine, big_endian, arch, mode, [abs, pc, slot, relative]) in cases {
+        let word = |value: u32| if big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+        let answer = if big_endian { 42u32.to_be_bytes() } else { 42u32.to_le_bytes() };
+        let lib = dyn_elf32(machine, big_endian, 3, 0, &[], &answer, &[], &[("answer", 0x100, true)], &[]);
+        // the implicit addends: 4 for the absolute word, 0x80 for the relative one and
+        // a lazy binding address the PLT slot ignores
+        let mut data = Vec::new();
+        for addend in [4, 0, 0x1234, 0x80] {
+            data.extend_from_slice(&word(addend));
+        }
+        let mut relocs = vec![(0x100, abs, 1), (0x108, slot, 2), (0x10c, relative, 0)];
+        if pc != 0 {
+            relocs.push((0x104, pc, 1));
+        }
+        let exe = dyn_elf32(
+            machine,
+            big_endian,
+            3,
+            0,
+            &[],
+            &data,
+            &["libanswer.so"],
+            &[("answer", 0, false), ("bound", 0, false)],
+            &relocs,
+        );
+
+        let mut emu = unicorn_engine::Unicorn::new(arch, mode).expect("failed to initialize unicorn instance");
+        let mut linker = Linker::new(|name: &str| if name == "libanswer.so" { Some(lib.clone()) } else { None });
+        linker.bind("bound", |_| 0);
+        let image = linker.link(&mut emu, &exe).expect("failed to link");
+        assert!(image.unsupported.is_empty(), "machine {}", machine);
+        assert_eq!(image.executable.bias, EXE_BASE);
+        assert_eq!(image.error(), None);
+
+        let read = |address: u64| {
+            let data = emu.mem_read_as_vec(address, 4).expect("failed to read relocation");
+            let data: [u8; 4] = data.try_into().unwrap();
+            u64::from(if big_endian { u32::from_be_bytes(data) } else { u32::from_le_bytes(data) })
+        };
+        let answer = LIBRARY_BASE + 0x100;
+        assert_eq!(read(EXE_BASE + 0x100), answer + 4, "machine {}", machine);
+        if pc != 0 {
+            assert_eq!(read(EXE_BASE + 0x104), answer - (EXE_BASE + 0x104), "machine {}", machine);
+        }
+        assert_eq!(read(EXE_BASE + 0x108), STUB_BASE, "machine {}", machine);
+        assert_eq!(read(EXE_BASE + 0x10c), EXE_BASE + 0x80, "machine {}", machine);
+    }
+
+    // R_386_COPY of a word the library relocates itself, like glibc's stdout
+    let lib = x86_dyn_elf32(3, 0, &[], &0x10u32.to_le_bytes(), &[], &[("stdout", 0x100, true)], &[(0x100, 8, 0)]);
+    let mut exe = x86_dyn_elf32(3, 0, &[], &[0; 4], &["libc.so"], &[("stdout", 0, false)], &[(0x100, 5, 1)]);
+    exe[0x1d0 + 8..0x1d0 + 12].copy_from_slice(&4u32.to_le_bytes()); // st_size
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let linker: Linker<()> = Linker::new(|name: &str| if name == "libc.so" { Some(lib.clone()) } else { None });
+    let image = linker.link(&mut emu, &exe).expect("failed to link");
+    assert!(image.unsupported.is_empty());
+    let copied = emu.mem_read_as_vec(EXE_BASE + 0x100, 4).expect("failed to read the copy");
+    assert_eq!(u64::from(u32::from_le_bytes(copied.try_into().unwrap())), LIBRARY_BASE + 0x10);
+}
+
+#[test]
+fn x86_linux_initial_stack() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let image = elf::load(&mut emu, &x86_elf32(), 0).expect("failed to load elf");
+    let layout = StackBuilder::new()
+        .image(&image)
+        .args(["prog", "-v"])
+        .env("HOME=/root")
+        .random([7; 16])
+        .map(0x8000_0000, 0x1_0000)
+        .build(&mut emu)
+        .expect("failed to build the stack");
+    assert_eq!(layout.sp % 16, 0);
+    assert_eq!(emu.get_sp(), Ok(layout.sp));
+    assert_eq!(emu.reg_read(RegisterX86::ESP), Ok(layout.sp));
+
+    let word = |address: u64| {
+        let data = emu.mem_read_as_vec(address, 4).expect("failed to read stack");
+        u64::from(u32::from_le_bytes(data.try_into().unwrap()))
+    };
+    let string = |address: u64| {
+        let data = emu.mem_read_as_vec(address, 16).expect("failed to read string");
+        let len = data.iter().position(|&b| b == 0).unwrap();
+        String::from_utf8(data[..len].to_vec()).unwrap()
+    };
+    assert_eq!(word(layout.sp), 2);
+    assert_eq!((layout.argv, layout.envp, layout.auxv), (layout.sp + 4, layout.sp + 16, layout.sp + 24));
+    assert_eq!(string(word(layout.argv)), "prog");
+    assert_eq!(string(word(layout.argv + 4)), "-v");
+    assert_eq!(word(layout.argv + 8), 0);
+    assert_eq!(string(word(layout.envp)), "HOME=/root");
+    assert_eq!(word(layout.envp + 4), 0);
+
+    let mut auxv = Vec::new();
+    let mut entry = layout.auxv;
+    loop {
+        let (kind, value) = (word(entry), word(entry + 4));
+        auxv.push((kind, value));
+        if kind == AT_NULL {
+            break;
+        }
+        entry += 8;
+    }
+    let aux = |kind: u64| auxv.iter().find(|(k, _)| *k == kind).map(|(_, v)| *v);
+    assert_eq!(aux(AT_PHDR), Some(0x1034));
+    assert_eq!(aux(AT_ENTRY), Some(0x1080));
+    assert_eq!(aux(AT_PAGESZ), Some(0x1000));
+    assert_eq!(aux(AT_RANDOM), Some(layout.random));
+    assert_eq!(emu.mem_read_as_vec(layout.random, 16), Ok(vec![7; 16]));
+}
+
+#[test]
+fn linux_initial_stack_layouts() {
+    let layouts = [
+        (Arch::X86, Mode::MODE_64, 8, false),
+        (Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN, 4, true),
+    ];
+    for (arch, mode, word_size, big_endian) in layouts {
+        let mut emu =
+            unicorn_engine::Unicorn::new(arch, mode).expect("failed to initialize unicorn instance");
+        let layout = StackBuilder::new()
+            .arg("prog")
+            .env("A=1")
+            .map(0x8000_0000, 0x1_0000)
+            .build(&mut emu)
+            .expect("failed to build the stack");
+        assert_eq!(layout.sp % 16, 0);
+        assert_eq!(emu.get_sp(), Ok(layout.sp));
+
+        let word = |address: u64| {
+            let data = emu.mem_read_as_vec(address, word_size).expect("failed to read stack");
+            data.iter().enumerate().fold(0u64, |value, (i, &b)| {
+                if big_endian {
+                    value << 8 | u64::from(b)
+                } else {
+                    value | u64::from(b) << (8 * i)
+                }
+            })
+        };
+        let string = |address: u64| {
+            let data = emu.mem_read_as_vec(address, 8).expect("failed to read string");
+            let len = data.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(data[..len].to_vec()).unwrap()
+        };
+        let w = word_size as u64;
+        assert_eq!(word(layout.sp), 1, "{:?}", arch);
+        assert_eq!((layout.argv, layout.envp, layout.auxv), (layout.sp + w, layout.sp + 3 * w, layout.sp + 5 * w));
+        assert_eq!(string(word(layout.argv)), "prog", "{:?}", arch);
+        assert_eq!(word(layout.argv + w), 0);
+        assert_eq!(string(word(layout.envp)), "A=1", "{:?}", arch);
+        assert_eq!(word(layout.envp + w), 0);
+        assert_eq!(word(layout.auxv), AT_PAGESZ, "{:?}", arch);
+        assert_eq!(word(layout.auxv + w), 0x1000, "{:?}", arch);
+    }
+
+    // no room below the top for the strings
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    let builder = StackBuilder::new().arg("prog").env("A=1").top(0x10);
+    assert_eq!(builder.build(&mut emu), Err(uc_error::ARG));
+}
+
+#[test]
+fn x86_linux_syscalls() {
+    let code: Vec<u8> = vec![
+        0xb8, 0x01, 0x00, 0x00, 0x00, // mov eax, 1 (write)
+        0xbf, 0x01, 0x00, 0x00, 0x00, // mov edi, 1
+        0xbe, 0x00, 0x20, 0x00, 0x00, // mov esi, 0x2000
+        0xba, 0x03, 0x00, 0x00, 0x00, // mov edx, 3
+        0x0f, 0x05, // syscall
+        0xb8, 0x09, 0x00, 0x00, 0x00, // mov eax, 9 (mmap)
+        0x31, 0xff, // xor edi, edi
+        0xbe, 0x00, 0x30, 0x00, 0x00, // mov esi, 0x3000
+        0xba, 0x03, 0x00, 0x00, 0x00, // mov edx, PROT_READ | PROT_WRITE
+        0x41, 0xba, 0x22, 0x00, 0x00, 0x00, // mov r10d, MAP_PRIVATE | MAP_ANONYMOUS
+        0x49, 0xc7, 0xc0, 0xff, 0xff, 0xff, 0xff, // mov r8, -1
+        0x45, 0x31, 0xc9, // xor r9d, r9d
+        0x0f, 0x05, // syscall
+        0x48, 0x89, 0xc3, // mov rbx, rax
+        0xb8, 0x0c, 0x00, 0x00, 0x00, // mov eax, 12 (brk)
+        0xbf, 0x00, 0x50, 0x00, 0x00, // mov edi, 0x5000
+        0x0f, 0x05, // syscall
+        0x49, 0x89, 0xc6, // mov r14, rax
+        0xb8, 0x27, 0x00, 0x00, 0x00, // mov eax, 39 (getpid)
+        0x0f, 0x05, // syscall
+        0x49, 0x89, 0xc4, // mov r12, rax
+        0xb8, 0xff, 0x01, 0x00, 0x00, // mov eax, 0x1ff
+        0x0f, 0x05, // syscall
+        0x49, 0x89, 0xc5, // mov r13, rax
+        0xb8, 0xe7, 0x00, 0x00, 0x00, // mov eax, 231 (exit_group)
+        0xbf, 0x03, 0x00, 0x00, 0x00, // mov edi, 3
+        0x0f, 0x05, // syscall
+        0xf4, // hlt
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x2000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &code), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, b"hi\n"), Ok(()));
+
+    let kernel = Kernel::start(&mut emu).expect("failed to start the kernel");
+    assert_eq!(kernel.borrow().abi(), Abi::X86_64);
+    kernel.borrow_mut().set_brk(0x3000);
+    kernel
+        .borrow_mut()
+        .hook(Sysno::Getpid, |_, _| Some(42))
+        .expect("failed to hook getpid");
+
+    assert_eq!(emu.emu_start(0x1000, 0x2000, 0, 0), Ok(EmuExit::StoppedByHook));
+    let kernel = kernel.borrow();
+    assert_eq!(kernel.exit_status(), Some(3));
+    assert_eq!(kernel.stdout(), b"hi\n");
+    assert_eq!(kernel.unknown(), &[0x1ff]);
+    assert_eq!(kernel.brk(), 0x5000);
+
+    assert_eq!(emu.reg_read(RegisterX86::RBX), Ok(0x7f00_0000_0000));
+    assert_eq!(emu.reg_read(RegisterX86::R14), Ok(0x5000));
+    assert_eq!(emu.reg_read(RegisterX86::R12), Ok(42));
+    assert_eq!(emu.reg_read(RegisterX86::R13), Ok(-(ENOSYS as i64) as u64));
+    let regions = emu.mem_regions().expect("failed to read regions");
+    assert!(regions.iter().any(|r| r.begin == 0x3000 && r.end == 0x4fff));
+    assert!(regions
+        .iter()
+        .any(|r| r.begin == 0x7f00_0000_0000 && r.end == 0x7f00_0000_2fff && r.perms == Permission::READ | Permission::WRITE));
+}
+
+#[test]
+fn x86_linux_vfs() {
+    let code: Vec<u8> = vec![
+        0xb8, 0x01, 0x01, 0x00, 0x00, // mov eax, 257 (openat)
+        0xbf, 0x9c, 0xff, 0xff, 0xff, // mov edi, AT_FDCWD
+        0xbe, 0x00, 0x20, 0x00, 0x00, // mov esi, 0x2000
+        0x31, 0xd2, // xor edx, edx
+        0x0f, 0x05, // syscall
+        0x89, 0xc3, // mov ebx, eax
+        0x31, 0xc0, // xor eax, eax (read)
+        0x89, 0xdf, // mov edi, ebx
+        0xbe, 0x00, 0x21, 0x00, 0x00, // mov esi, 0x2100
+        0xba, 0x40, 0x00, 0x00, 0x00, // mov edx, 64
+        0x0f, 0x05, // syscall
+        0x41, 0x89, 0xc4, // mov r12d, eax
+        0xb8, 0x01, 0x00, 0x00, 0x00, // mov eax, 1 (write)
+        0xbf, 0x01, 0x00, 0x00, 0x00, // mov edi, 1
+        0xbe, 0x00, 0x21, 0x00, 0x00, // mov esi, 0x2100
+        0x44, 0x89, 0xe2, // mov edx, r12d
+        0x0f, 0x05, // syscall
+        0xb8, 0x02, 0x00, 0x00, 0x00, // mov eax, 2 (open)
+        0xbf, 0x08, 0x20, 0x00, 0x00, // mov edi, 0x2008
+        0xbe, 0x41, 0x02, 0x00, 0x00, // mov esi, O_WRONLY | O_CREAT | O_TRUNC
+        0x0f, 0x05, // syscall
+        0x89, 0xc7, // mov edi, eax
+        0xb8, 0x01, 0x00, 0x00, 0x00, // mov eax, 1 (write)
+        0xbe, 0x00, 0x21, 0x00, 0x00, // mov esi, 0x2100
+        0x44, 0x89, 0xe2, // mov edx, r12d
+        0x0f, 0x05, // syscall
+        0xb8, 0x05, 0x00, 0x00, 0x00, // mov eax, 5 (fstat)
+        0x89, 0xdf, // mov edi, ebx
+        0xbe, 0x00, 0x22, 0x00, 0x00, // mov esi, 0x2200
+        0x0f, 0x05, // syscall
+        0xb8, 0x3c, 0x00, 0x00, 0x00, // mov eax, 60 (exit)
+        0x31, 0xff, // xor edi, edi
+        0x0f, 0x05, // syscall
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x2000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &code), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, b"/input\0\0/out\0"), Ok(()));
+
+    let fs = MemFs::new();
+    fs.add_file("/input", b"fuzz input");
+    let kernel = Kernel::start(&mut emu).expect("failed to start the kernel");
+    kernel.borrow_mut().vfs_mut().mount("/", fs.clone());
+
+    assert_eq!(emu.emu_start(0x1000, 0x2000, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(kernel.borrow().exit_status(), Some(0));
+    assert_eq!(kernel.borrow().stdout(), b"fuzz input");
+    assert_eq!(fs.file("/out"), Some(b"fuzz input".to_vec()));
+    assert_eq!(emu.reg_read(RegisterX86::RBX), Ok(3));
+
+    // st_mode and st_size of the input
+    let stat = emu.mem_read_as_vec(0x2200, 144).expect("failed to read stat");
+    assert_eq!(u32::from_le_bytes(stat[24..28].try_into().unwrap()), 0o100_644);
+    assert_eq!(u64::from_le_bytes(stat[48..56].try_into().unwrap()), 10);
+
+    // files do not grow past the cap, nor wrap around
+    let mut fs = fs;
+    let flags = OpenFlags { write: true, ..Default::default() };
+    let mut out = fs.open("out", flags).unwrap_or_else(|_| panic!("failed to open /out"));
+    assert_eq!(out.write_at(MEM_FILE_MAX, b"x"), Err(EFBIG));
+    assert_eq!(out.write_at(u64::MAX, b"x"), Err(EFBIG));
+    assert_eq!(out.write_at(10, b"!"), Ok(1));
+    assert_eq!(fs.file("/out"), Some(b"fuzz input!".to_vec()));
+
+    // inode numbers of removed files are not handed out again
+    let mut fs = MemFs::new();
+    fs.add_file("/a", b"a");
+    fs.add_file("/b", b"b");
+    fs.remove("/a");
+    fs.add_file("/c", b"c");
+    let b = fs.stat("b").expect("failed to stat /b");
+    let c = fs.stat("c").expect("failed to stat /c");
+    assert_ne!(b.ino, c.ino);
+}
+
+#[test]
+fn x86_linux_set_thread_area() {
+    let code: Vec<u8> = vec![
+        0xb8, 0xf3, 0x00, 0x00, 0x00, // mov eax, 243 (set_thread_area)
+        0xbb, 0x00, 0x20, 0x00, 0x00, // mov ebx, 0x2000
+        0xcd, 0x80, // int 0x80
+        0x89, 0xc7, // mov edi, eax
+        0xb8, 0x33, 0x00, 0x00, 0x00, // mov eax, 0x33
+        0x8e, 0xe8, // mov gs, eax
+        0x65, 0xa1, 0x00, 0x00, 0x00, 0x00, // mov eax, gs:[0]
+        0x89, 0xc6, // mov esi, eax
+        0xb8, 0x91, 0x00, 0x00, 0x00, // mov eax, 145 (readv)
+        0x31, 0xdb, // xor ebx, ebx
+        0xb9, 0x00, 0x22, 0x00, 0x00, // mov ecx, 0x2200
+        0xba, 0x01, 0x04, 0x00, 0x00, // mov edx, 1025
+        0xcd, 0x80, // int 0x80
+        0x89, 0xc5, // mov ebp, eax
+        0xb8, 0x01, 0x00, 0x00, 0x00, // mov eax, 1 (exit)
+        0x31, 0xdb, // xor ebx, ebx
+        0xcd, 0x80, // int 0x80
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x2000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &code), Ok(()));
+    // user_desc: any free entry, base 0x2100, 4 GiB limit, seg_32bit | limit_in_pages | useable
+    let desc: Vec<u8> = [u32::MAX, 0x2100, 0xfffff, 0x51]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    assert_eq!(emu.mem_write(0x2000, &desc), Ok(()));
+    assert_eq!(emu.mem_write(0x2100, &0xcafe_babeu32.to_le_bytes()), Ok(()));
+
+    let kernel = Kernel::start(&mut emu).expect("failed to start the kernel");
+    assert_eq!(emu.emu_start(0x1000, 0x2000, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(kernel.borrow().exit_status(), Some(0));
+    assert_eq!(emu.reg_read(RegisterX86::EDI), Ok(0));
+    assert_eq!(emu.mem_read_as_vec(0x2000, 4), Ok(6u32.to_le_bytes().to_vec()));
+    assert_eq!(emu.reg_read(RegisterX86::ESI), Ok(0xcafe_babe));
+    assert_eq!(emu.reg_read(RegisterX86::EBP), Ok(-22i32 as u32 as u64));
+}
+
+#[test]
+fn mips_linux_stat() {
+    let code: Vec<u8> = vec![
+        0x24, 0x02, 0x10, 0x75, // addiu v0, zero, 4213 (stat64)
+        0x24, 0x04, 0x20, 0x00, // addiu a0, zero, 0x2000
+        0x24, 0x05, 0x21, 0x00, // addiu a1, zero, 0x2100
+        0x00, 0x00, 0x00, 0x0c, // syscall
+        0x00, 0x40, 0x80, 0x25, // or s0, v0, zero
+        0x24, 0x02, 0x0f, 0xa1, // addiu v0, zero, 4001 (exit)
+        0x24, 0x04, 0x00, 0x00, // addiu a0, zero, 0
+        0x00, 0x00, 0x00, 0x0c, // syscall
+    ];
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x1000, 0x2000, Permission::ALL), Ok(()));
+    assert_eq!(emu.mem_write(0x1000, &code), Ok(()));
+    assert_eq!(emu.mem_write(0x2000, b"/input\0"), Ok(()));
+
+    let fs = MemFs::new();
+    fs.add_file("/input", b"fuzz input");
+    let kernel = Kernel::start(&mut emu).expect("failed to start the kernel");
+    kernel.borrow_mut().vfs_mut().mount("/", fs);
+    assert_eq!(emu.emu_start(0x1000, 0x2000, 0, 0), Ok(EmuExit::StoppedByHook));
+    assert_eq!(kernel.borrow().exit_status(), Some(0));
+    assert_eq!(emu.reg_read(RegisterMIPS::S0), Ok(0));
+
+    // st_mode and st_size of the o32 stat64
+    let stat = emu.mem_read_as_vec(0x2100, 104).expect("failed to read stat");
+    assert_eq!(u32::from_be_bytes(stat[24..28].try_into().unwrap()), 0o100_644);
+    assert_eq!(u64::from_be_bytes(stat[56..64].try_into().unwrap()), 10);
+}
+
+#[test]
+fn virtual_memory() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(emu.mem_map(0x40_0000, 0x2000, Permission::READ | Permission::EXEC), Ok(()));
+    let mut memory = VirtualMemory::new(&emu).expect("failed to manage memory");
+    memory.set_mmap_base(0x1000_0000);
+    memory.set_heap_start(0x40_2000);
+
+    let first = memory
+        .map(&mut emu, 0, 0x3000, Permission::READ | Permission::WRITE, Origin::Anonymous)
+        .expect("failed to map");
+    assert_eq!(first, 0x1000_0000);
+    let origin = Origin::File {
+        path: "/lib/libc.so.6".into(),
+        offset: 0x1000,
+    };
+    let second = memory
+        .map(&mut emu, 0, 0x1000, Permission::READ, origin)
+        .expect("failed to map");
+    assert_eq!(second, 0x1000_3000);
+    assert_eq!(
+        memory.map_fixed(&mut emu, second, 0x1000, Permission::READ, Origin::Anonymous, false),
+        Err(uc_error::MAP)
+    );
+
+    // punch a hole in the middle and take away the write permission after it
+    assert_eq!(memory.unmap(&mut emu, first + 0x1000, 0x1000), Ok(()));
+    assert_eq!(memory.protect(&mut emu, first + 0x2000, 1, Permission::READ), Ok(()));
+    assert_eq!(
+        memory.protect(&mut emu, first, 0x2000, Permission::READ),
+        Err(uc_error::NOMEM)
+    );
+    assert_eq!(memory.find_free(0, 0x1000), Some(first + 0x1000));
+
+    assert_eq!(memory.set_brk(&mut emu, 0x40_2800), 0x40_2800);
+    assert_eq!(memory.set_brk(&mut emu, 0x40_1000), 0x40_2800);
+    assert_eq!(memory.mapping(0x40_2000).map(|m| &m.origin), Some(&Origin::Heap));
+
+    // the libc page stays apart from its read-only neighbour and shows its file offset
+    let maps = memory.maps();
+    let lines: Vec<&str> = maps.lines().collect();
+    assert_eq!(lines.len(), 5);
+    assert!(lines[0].starts_with("00400000-00402000 r-xp 00000000 00:00 0"));
+    assert!(lines[1].starts_with("00402000-00403000 rw-p 00000000 00:00 0"));
+    assert!(lines[1].ends_with(" [heap]"));
+    assert!(lines[2].starts_with("10000000-10001000 rw-p"));
+    assert!(lines[3].starts_with("10002000-10003000 r--p"));
+    assert!(lines[4].starts_with("10003000-10004000 r--p 00001000"));
+    assert!(lines[4].ends_with(" /lib/libc.so.6"));
+
+    // memory mapped behind its back is picked up
+    assert_eq!(emu.mem_unmap(0x40_2000, 0x1000), Ok(()));
+    assert_eq!(memory.sync(&emu), Ok(()));
+    assert!(memory.is_free(0x40_2000, 0x1000));
+    assert_eq!(memory.mappings().count(), 4);
+}
+
+#[test]
+fn x86_virtual_memory_origins() {
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let mut memory = VirtualMemory::new(&emu).expect("failed to manage memory");
+    let image = elf::load(&mut emu, &x86_elf32(), 0).expect("failed to load elf");
+    assert_eq!(image.track(&emu, &mut memory, "/bin/prog"), Ok(()));
+    StackBuilder::new()
+        .image(&image)
+        .arg("/bin/prog")
+        .map(0x8000_0000, 0x1_0000)
+        .build_in(&mut emu, &mut memory)
+        .expect("failed to build the stack");
+    let symbols = HeapSymbols {
+        malloc: 0x1080,
+        free: 0x1084,
+        ..Default::default()
+    };
+    Heap::install_in(&mut emu, &mut memory, 0x10_0000, 0x1_0000, &symbols)
+        .expect("failed to install the heap");
+
+    let maps = memory.maps();
+    let lines: Vec<&str> = maps.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("00001000-00002000 r-xp 00000000"));
+    assert!(lines[0].ends_with(" /bin/prog"));
+    assert!(lines[1].starts_with("00002000-00003000 rw-p 00000100"));
+    assert!(lines[1].ends_with(" /bin/prog"));
+    assert!(lines[2].starts_with("00100000-00110000 rw-p"));
+    assert!(lines[2].ends_with(" [heap]"));
+    assert!(lines[3].starts_with("7fff0000-80000000 rw-p"));
+    assert!(lines[3].ends_with(" [stack]"));
+
+    // the stack is taken in place when it is already mapped
+    assert_eq!(memory.unmap(&mut emu, 0x7fff_0000, 0x1_0000), Ok(()));
+    assert_eq!(emu.mem_map(0x7fff_0000, 0x1_0000, Permission::READ | Permission::WRITE), Ok(()));
+    StackBuilder::new()
+        .arg("/bin/prog")
+        .top(0x8000_0000)
+        .build_in(&mut emu, &mut memory)
+        .expect("failed to build the stack");
+    assert_eq!(memory.mapping(0x7fff_fff0).map(|m| &m.origin), Some(&Origin::Stack));
+
+    // firmware regions are named after themselves
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::THUMB)
+        .expect("failed to initialize unicorn instance");
+    let mut memory = VirtualMemory::new(&emu).expect("failed to manage memory");
+    let map = MemoryMap::new()
+        .region(Region::flash("flash", 0x0800_0000, 0x1_0000))
+        .region(Region::ram("sram", 0x2000_0000, 0x1000));
+    assert_eq!(map.materialize_with(&mut emu, |_| None), Ok(()));
+    assert_eq!(map.track(&emu, &mut memory), Ok(()));
+    assert_eq!(
+        memory.mapping(0x0800_0000).map(|m| &m.origin),
+        Some(&Origin::Named("flash".into()))
+    );
+    assert!(memory.maps().lines().nth(1).is_some_and(|line| line.ends_with(" sram")));
+}
+
+/// Build a PE32 image for x86 importing `Square` from `math.dll` and exporting `Run`.
+fn x86_pe_image() -> Vec<u8> {
+    fn put(image: &mut [u8], at: usize, bytes: &[u8]) {
+        image[at..at + bytes.len()].copy_from_slice(bytes);
+    }
+    fn put32(image: &mut [u8], at: usize, value: u32) {
+        put(image, at, &value.to_le_bytes());
+    }
+
+    let mut image = vec![0u8; 0x800];
+    put(&mut image, 0, b"MZ");
+    put32(&mut image, 0x3c, 0x40);
+    put(&mut image, 0x40, b"PE\0\0");
+    // file header: i386, 2 sections, optional header of 0xe0 bytes, executable
+    put(&mut image, 0x44, &0x14cu16.to_le_bytes());
+    put(&mut image, 0x46, &2u16.to_le_bytes());
+    put(&mut image, 0x54, &0xe0u16.to_le_bytes());
+    put(&mut image, 0x56, &0x0102u16.to_le_bytes());
+    // optional header
+    let opt = 0x58;
+    put(&mut image, opt, &0x10bu16.to_le_bytes());
+    put32(&mut image, opt + 16, 0x1000);
+    put32(&mut image, opt + 28, 0x40_0000);
+    put32(&mut image, opt + 32, 0x1000);
+    put32(&mut image, opt + 36, 0x200);
+    put32(&mut image, opt + 56, 0x3000);
+    put32(&mut image, opt + 60, 0x200);
+    put32(&mut image, opt + 92, 16);
+    put32(&mut image, opt + 96, 0x2080); // exports
+    put32(&mut image, opt + 100, 0x60);
+    put32(&mut image, opt + 104, 0x2000); // imports
+    put32(&mut image, opt + 108, 0x28);
+    put32(&mut image, opt + 136, 0x2100); // base relocations
+    put32(&mut image, opt + 140, 12);
+    // section table
+    for (i, (name, rva, raw, flags)) in [
+        (b".text\0\0\0", 0x1000, 0x400, 0x6000_0020),
+        (b".rdata\0\0", 0x2000, 0x600, 0x4000_0040),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let at = 0x138 + 40 * i;
+        put(&mut image, at, *name);
+        put32(&mut image, at + 8, 0x200);
+        put32(&mut image, at + 12, *rva);
+        put32(&mut image, at + 16, 0x200);
+        put32(&mut image, at + 20, *raw);
+        put32(&mut image, at + 36, *flags);
+    }
+
+    let code = [
+        0x6a, 0x07, // push 7
+        0xff, 0x15, 0x50, 0x20, 0x40, 0x00, // call [Square]
+        0x89, 0xc3, // mov ebx, eax
+        0x64, 0xa1, 0x18, 0x00, 0x00, 0x00, // mov eax, fs:[0x18] (TEB)
+        0x8b, 0x48, 0x30, // mov ecx, [eax + 0x30] (PEB)
+        0x8b, 0x49, 0x08, // mov ecx, [ecx + 8] (ImageBaseAddress)
+        0xc3, // ret
+    ];
+    put(&mut image, 0x400, &code);
+
+    // .rdata at file offset 0x600 holds rva 0x2000
+    let rdata = |rva: usize| rva - 0x2000 + 0x600;
+    put32(&mut image, rdata(0x2000), 0x2040); // import lookup table
+    put32(&mut image, rdata(0x200c), 0x2070); // DLL name
+    put32(&mut image, rdata(0x2010), 0x2050); // import address table
+    put32(&mut image, rdata(0x2040), 0x2060);
+    put32(&mut image, rdata(0x2050), 0x2060);
+    put(&mut image, rdata(0x2062), b"Square\0");
+    put(&mut image, rdata(0x2070), b"math.dll\0");
+    put32(&mut image, rdata(0x2080 + 0x0c), 0x20d0);
+    put32(&mut image, rdata(0x2080 + 0x10), 1);
+    put32(&mut image, rdata(0x2080 + 0x14), 1);
+    put32(&mut image, rdata(0x2080 + 0x18), 1);
+    put32(&mut image, rdata(0x2080 + 0x1c), 0x20b0);
+    put32(&mut image, rdata(0x2080 + 0x20), 0x20b8);
+    put32(&mut image, rdata(0x2080 + 0x24), 0x20c0);
+    put32(&mut image, rdata(0x20b0), 0x1000);
+    put32(&mut image, rdata(0x20b8), 0x20c8);
+    put(&mut image, rdata(0x20c8), b"Run\0");
+    put(&mut image, rdata(0x20d0), b"test.exe\0");
+    // one HIGHLOW relocation, for the operand of the call
+    put32(&mut image, rdata(0x2100), 0x1000);
+    put32(&mut image, rdata(0x2104), 12);
+    put(&mut image, rdata(0x2108), &0x3004u16.to_le_bytes());
+    image
+}
+
+#[test]
+fn x86_pe_link() {
+    let image = x86_pe_image();
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(
+        PeLinker::new().link(&mut emu, &image).map(|_| ()),
+        Err(PeLinkError::Unresolved(vec![String::from("math.dll!Square")]))
+    );
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let mut linker = PeLinker::new();
+    linker.base = Some(0x50_0000);
+    linker.bind("MATH.DLL!Square", 1, |uc| {
+        let x = pe::link::arg(uc, 0).unwrap();
+        x * x
+    });
+    let process = linker.link(&mut emu, &image).expect("failed to link");
+    assert_eq!(process.image.base, 0x50_0000);
+    assert_eq!(process.image.entry, Some(0x50_1000));
+    assert_eq!(process.image.export("Run").map(|e| e.address), Some(0x50_1000));
+    assert_eq!(
+        process.image.section(".text").map(|s| s.perms),
+        Some(Permission::READ | Permission::EXEC)
+    );
+    let thunk = THUNK_BASE + 0x10;
+    assert_eq!(process.thunk("Square"), Some(thunk));
+    let slot = emu.mem_read_as_vec(0x50_2050, 4).expect("failed to read IAT");
+    assert_eq!(u32::from_le_bytes(slot.try_into().unwrap()) as u64, thunk);
+
+    assert_eq!(
+        emu.emu_start(0x50_1000, process.exit, 0, 0),
+        Ok(EmuExit::ReachedUntil)
+    );
+    assert_eq!(emu.reg_read(RegisterX86::EBX), Ok(49));
+    assert_eq!(emu.reg_read(RegisterX86::ECX), Ok(0x50_0000));
+    // the entry returned, Square removed its argument
+    assert_eq!(emu.reg_read(RegisterX86::ESP), Ok(process.stack.1 - 12));
+    assert_eq!(process.error(), None);
+}
+
+#[test]
+fn pe_malformed() {
+    let mut image = x86_pe_image();
+    // NumberOfFunctions far beyond the export directory
+    image[0x600 + 0x94..0x600 + 0x98].copy_from_slice(&0x4000_0000u32.to_le_bytes());
+    let parsed = pe::Pe::parse(&image).expect("failed to parse");
+    assert_eq!(parsed.exports(0), Err(pe::PeError::Truncated));
+
+    let mut image = x86_pe_image();
+    image[0x44..0x46].copy_from_slice(&0xaa64u16.to_le_bytes());
+    let parsed = pe::Pe::parse(&image).expect("failed to parse");
+    assert_eq!(parsed.arch_mode(), Err(pe::PeError::UnsupportedMachine(0xaa64)));
+
+    // a base at the top of the address space
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(
+        pe::map(&mut emu, &x86_pe_image(), Some(0xffff_ffff_ffff_0000)).err(),
+        Some(pe::PeError::BadHeader)
+    );
+    assert!(emu.mem_regions().expect("failed to list regions").is_empty());
+}
+
+#[test]
+fn arm_firmware_memory_map() {
+    // initial stack pointer, reset handler, movs r0, #42; ldr r1, [r2]
+    let vectors = [
+        0x00, 0x10, 0x00, 0x20, 0x09, 0x00, 0x00, 0x08, 0x2a, 0x20, 0x11, 0x68,
+    ];
+    let map = MemoryMap::new()
+        .region(Region::flash("flash", 0x0800_0000, 0x1000).data(&vectors))
+        .region(Region::ram("sram", 0x2000_0000, 0x1000).file("sram.bin", 2).offset(0x10))
+        .region(Region::mmio("gpio", 0x4000_0000, 0x400))
+        .vector_table(0x0800_0000)
+        .register("r1", 5)
+        .register("r2", 0x4000_0000);
+    let files = |path: &str| (path == "sram.bin").then(|| vec![1, 2, 3, 4]);
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::THUMB)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(map.materialize_with(&mut emu, files), Ok(()));
+    assert_eq!(emu.reg_read(RegisterARM::SP), Ok(0x2000_1000));
+    assert_eq!(emu.reg_read(RegisterARM::R1), Ok(5));
+    assert_eq!(emu.mem_read_as_vec(0x0800_000c, 2), Ok(vec![0xff, 0xff]));
+    assert_eq!(emu.mem_read_as_vec(0x2000_000f, 4), Ok(vec![0, 3, 4, 0]));
+    let regions = emu.mem_regions().expect("failed to list regions");
+    assert_eq!(regions[0].perms, Permission::READ | Permission::EXEC);
+
+    let pc = emu.reg_read(RegisterARM::PC).expect("failed to read pc");
+    assert_eq!(pc, 0x0800_0008);
+    assert_eq!(
+        emu.emu_start(pc | 1, 0x0800_000c, 0, 0),
+        Ok(EmuExit::ReachedUntil)
+    );
+    assert_eq!(emu.reg_read(RegisterARM::R0), Ok(42));
+    // the placeholder of the peripheral reads as zero
+    assert_eq!(emu.reg_read(RegisterARM::R1), Ok(0));
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::THUMB)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(
+        map.clone().register("x9", 0).materialize_with(&mut emu, files),
+        Err(FirmwareError::UnknownRegister(String::from("x9")))
+    );
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::THUMB)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(
+        map.materialize_with(&mut emu, |_| None),
+        Err(FirmwareError::MissingFile(String::from("sram.bin")))
+    );
+
+    // regions and contents running past the end of the address space
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM64, Mode::ARM)
+        .expect("failed to initialize unicorn instance");
+    let map = MemoryMap::new().region(Region::mmio("gpio", 0xffff_ffff_ffff_f000, 0x2000));
+    assert_eq!(
+        map.materialize_with(&mut emu, |_| None),
+        Err(FirmwareError::BadContents(String::from("gpio")))
+    );
+    let map = MemoryMap::new().region(Region::ram("sram", 0x1000, 0x1000).data(&[1]).offset(u64::MAX));
+    assert_eq!(
+        map.materialize_with(&mut emu, |_| None),
+        Err(FirmwareError::BadContents(String::from("sram")))
+    );
+    // a huge region is handed to mem_map at once instead of page by page
+    let map = MemoryMap::new().region(Region::ram("huge", 0, 1 << 60));
+    assert!(map.materialize_with(&mut emu, |_| None).is_err());
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM64, Mode::ARM)
+        .expect("failed to initialize unicorn instance");
+    let map = MemoryMap::new()
+        .region(Region::flash("flash", 0, 0x1800))
+        .region(Region::ram("sram", 0x1800, 0x1800).perms(Permission::READ | Permission::WRITE));
+    assert_eq!(map.materialize_with(&mut emu, |_| None), Ok(()));
+    let regions = emu.mem_regions().expect("failed to list regions");
+    let perms: Vec<_> = regions.iter().map(|r| (r.begin, r.end, r.perms)).collect();
+    assert_eq!(
+        perms,
+        vec![
+            (0, 0xfff, Permission::READ | Permission::EXEC),
+            (0x1000, 0x1fff, Permission::ALL),
+            (0x2000, 0x2fff, Permission::READ | Permission::WRITE),
+        ]
+    );
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn firmware_memory_map_config() {
+    let map = MemoryMap::from_toml(
+        r#"
+        entry = 0x100
+        [[regions]]
+        name = "rom"
+        kind = "rom"
+        address = 0
+        size = 0x1000
+        perms = "rwx"
+        data = [0x90]
+        [registers]
+        r0 = 7
+        "#,
+    )
+    .expect("failed to parse TOML");
+    assert_eq!(
+        map,
+        MemoryMap::new()
+            .region(Region::rom("rom", 0, 0x1000).perms(Permission::ALL).data(&[0x90]))
+            .register("r0", 7)
+            .entry(0x100)
+    );
+    let json = r#"{"regions": [{"name": "rom", "kind": "rom", "address": 0, "size": 4096,
+        "perms": "rwx", "data": [144]}], "registers": {"r0": 7}, "entry": 256}"#;
+    assert_eq!(MemoryMap::from_json(json), Ok(map));
+    assert!(matches!(
+        MemoryMap::from_json(r#"{"regions": [{"kind": "disk"}]}"#),
+        Err(FirmwareError::Parse(_))
+    ));
+}
+
+fn uf2_block(flags: u32, address: u32, family: u32, data: &[u8]) -> Vec<u8> {
+    let mut block = vec![0u8; 512];
+    for (offset, value) in [
+        (0, hex::UF2_MAGIC_START0),
+        (4, hex::UF2_MAGIC_START1),
+        (8, flags),
+        (12, address),
+        (16, data.len() as u32),
+        (28, family),
+        (508, hex::UF2_MAGIC_END),
+    ] {
+        block[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+    block[32..32 + data.len()].copy_from_slice(data);
+    block
+}
+
+#[test]
+fn x86_hex_images() {
+    // mov eax, 42; nop
+    let code = [0xb8, 0x2a, 0x00, 0x00, 0x00, 0x90];
+    let ihex = ":020000040001F9\n:06100000B82A0000009078\n:0400000500011000E6\n:00000001FF\n";
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    let image = hex::load(&mut emu, ihex.as_bytes(), Permission::ALL).expect("failed to load");
+    assert_eq!(image.entry, Some(0x1_1000));
+    assert_eq!(image.bounds(), Some((0x1_1000, 0x1_1006)));
+    assert_eq!(emu.reg_read(RegisterX86::EIP), Ok(0x1_1000));
+    assert_eq!(
+        emu.emu_start(0x1_1000, 0x1_1006, 0, 0),
+        Ok(EmuExit::ReachedUntil)
+    );
+    assert_eq!(emu.reg_read(RegisterX86::EAX), Ok(42));
+    assert_eq!(
+        Image::parse_ihex(&ihex.replace("78", "79")),
+        Err(HexError::Checksum(2))
+    );
+    assert_eq!(
+        Image::parse_ihex(":020000040001F9\n"),
+        Err(HexError::MissingEnd)
+    );
+
+    // records at consecutive addresses end up in one chunk
+    let srec = "S0060000686578B4\nS1082000B82A000000F5\nS10420059046\nS9032000DC\n";
+    let image = Image::parse(srec.as_bytes()).expect("failed to parse S-records");
+    assert_eq!(image.entry, Some(0x2000));
+    assert_eq!(image.chunks.len(), 1);
+    assert_eq!(image.chunks[0].address, 0x2000);
+    assert_eq!(image.chunks[0].data, code);
+    assert_eq!(
+        Image::parse_srec("S4032000DC"),
+        Err(HexError::UnsupportedRecord(1))
+    );
+
+    let mut uf2 = uf2_block(UF2_FLAG_FAMILY_ID, 0x3000, 0xe48b_ff56, &code);
+    uf2.extend(uf2_block(UF2_FLAG_NOT_MAIN_FLASH, 0x4000, 0, &[1, 2, 3]));
+    uf2.extend(uf2_block(UF2_FLAG_FAMILY_ID, 0x5000, 0x1234_5678, &[4]));
+    let image = Image::parse(&uf2).expect("failed to parse UF2");
+    assert_eq!(image.entry, None);
+    assert_eq!(image.bounds(), Some((0x3000, 0x5001)));
+    let image = Image::parse_uf2(&uf2, Some(0xe48b_ff56)).expect("failed to parse UF2");
+    assert_eq!(image.chunks.len(), 1);
+    assert_eq!(image.chunks[0].data, code);
+    assert_eq!(
+        Image::parse_uf2(&uf2[..700], None),
+        Err(HexError::BadBlock(1))
+    );
+}
+
+fn x86_64_core() -> Vec<u8> {
+    fn note(kind: u32, desc: &[u8]) -> Vec<u8> {
+        let mut note = Vec::new();
+        for field in [5, desc.len() as u32, kind] {
+            note.extend(field.to_le_bytes());
+        }
+        note.extend(b"CORE\0\0\0\0");
+        note.extend(desc);
+        note.resize(note.len().next_multiple_of(4), 0);
+        note
+    }
+    let words = |words: &[u64]| words.iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>();
+
+    let mut prstatus = vec![0u8; 336];
+    prstatus[12] = 11; // SIGSEGV
+    prstatus[32..36].copy_from_slice(&1234u32.to_le_bytes());
+    for (index, value) in [(10, 41), (16, 0x40_0000), (18, 0x202), (19, 0x7fff_0000)] {
+        prstatus[112 + 8 * index..120 + 8 * index].copy_from_slice(&u64::to_le_bytes(value));
+    }
+    let mut fpregs = vec![0u8; 512];
+    fpregs[24..28].copy_from_slice(&0x1f80u32.to_le_bytes());
+    fpregs[160..176].copy_from_slice(&core::array::from_fn::<u8, 16, _>(|i| i as u8 + 1));
+    let mut file = words(&[1, 0x1000, 0x40_0000, 0x40_1000, 2]);
+    file.extend(b"/bin/crash\0");
+    let mut notes = note(NT_PRSTATUS, &prstatus);
+    notes.extend(note(NT_FPREGSET, &fpregs));
+    notes.extend(note(NT_AUXV, &words(&[6, 0x1000, 9, 0x40_0000, 0, 0])));
+    notes.extend(note(NT_FILE, &file));
+
+    // inc rax; nop
+    let code = [0x48, 0xff, 0xc0, 0x90];
+    let notes_offset = 64 + 3 * 56;
+    let code_offset = notes_offset + notes.len();
+    let mut data = vec![0u8; 64];
+    data[..8].copy_from_slice(b"\x7fELF\x02\x01\x01\x00");
+    data[16..18].copy_from_slice(&4u16.to_le_bytes()); // ET_CORE
+    data[18..20].copy_from_slice(&62u16.to_le_bytes()); // EM_X86_64
+    data[20..24].copy_from_slice(&1u32.to_le_bytes());
+    data[32..40].copy_from_slice(&64u64.to_le_bytes());
+    data[52..54].copy_from_slice(&64u16.to_le_bytes());
+    data[54..56].copy_from_slice(&56u16.to_le_bytes());
+    data[56..58].copy_from_slice(&3u16.to_le_bytes());
+    // PT_NOTE, PT_LOAD r-x with the code, PT_LOAD rw- of the stack, not dumped
+    for (kind, flags, offset, vaddr, filesz, memsz) in [
+        (4u32, 4u32, notes_offset, 0, notes.len(), 0),
+        (1, 5, code_offset, 0x40_0000, code.len(), 0x1000),
+        (1, 6, 0, 0x7ffe_f000, 0, 0x1000),
+    ] {
+        data.extend(kind.to_le_bytes());
+        data.extend(flags.to_le_bytes());
+        data.extend(words(&[offset as u64, vaddr, 0, filesz as u64, memsz, 0x1000]));
+    }
+    data.extend(notes);
+    data.extend(code);
+    data
+}
+
+/// A core file of `machine` with a single thread, whose `elf_gregset_t` holds
+/// `registers`, followed by the notes `extra`.
+fn core_file(
+    machine: u16,
+    is_64bit: bool,
+    big_endian: bool,
+    registers: &[u64],
+    extra: &[(u32, Vec<u8>)],
+) -> Vec<u8> {
+    let put = |data: &mut Vec<u8>, value: u64, size: usize| {
+        if big_endian {
+            data.extend(&value.to_be_bytes()[8 - size..]);
+        } else {
+            data.extend(&value.to_le_bytes()[..size]);
+        }
+    };
+    let w = if is_64bit { 8 } else { 4 };
+
+    let mut notes = Vec::new();
+    let mut prstatus = Vec::new();
+    put(&mut prstatus, 0, 4);
+    put(&mut prstatus, 0, 4);
+    put(&mut prstatus, 0, 4);
+    put(&mut prstatus, 11, 2); // SIGSEGV
+    prstatus.resize(16 + 2 * w, 0);
+    put(&mut prstatus, 1234, 4);
+    prstatus.resize(16 + 2 * w + 16 + 8 * w, 0);
+    for &value in registers {
+        put(&mut prstatus, value, w);
+    }
+    put(&mut prstatus, 0, w);
+    for (kind, desc) in [(NT_PRSTATUS, prstatus)].iter().chain(extra) {
+        for field in [5, desc.len() as u64, u64::from(*kind)] {
+            put(&mut notes, field, 4);
+        }
+        notes.extend(b"CORE\0\0\0\0");
+        notes.extend(desc);
+        notes.resize(notes.len().next_multiple_of(4), 0);
+    }
+
+    let (header_size, ph_size) = if is_64bit { (64, 56) } else { (52, 32) };
+    let mut data = vec![0x7f, b'E', b'L', b'F', w as u8 / 4, 1 + big_endian as u8, 1];
+    data.resize(16, 0);
+    put(&mut data, 4, 2); // ET_CORE
+    put(&mut data, u64::from(machine), 2);
+    put(&mut data, 1, 4);
+    put(&mut data, 0, w);
+    put(&mut data, header_size, w);
+    put(&mut data, 0, w);
+    put(&mut data, 0, 4);
+    put(&mut data, header_size, 2);
+    put(&mut data, ph_size, 2);
+    put(&mut data, 1, 2);
+    data.resize(header_size as usize, 0);
+    // PT_NOTE
+    let offset = header_size + ph_size;
+    put(&mut data, 4, 4);
+    if is_64bit {
+        put(&mut data, 4, 4);
+    }
+    put(&mut data, offset, w);
+    put(&mut data, 0, w);
+    put(&mut data, 0, w);
+    put(&mut data, notes.len() as u64, w);
+    put(&mut data, 0, w);
+    if !is_64bit {
+        put(&mut data, 4, 4);
+    }
+    put(&mut data, 4, w);
+    data.extend(notes);
+    data
+}
+
+#[test]
+fn core_restore_per_arch() {
+    // ARM64: x0 to x30, sp, pc, pstate
+    let mut regs: Vec<u64> = (0..34).collect();
+    regs[31] = 0x7000;
+    regs[32] = 0x1000;
+    regs[33] = 0x6000_0000;
+    let mut fpregs = vec![0u8; 528];
+    fpregs[..16].copy_from_slice(&core::array::from_fn::<u8, 16, _>(|i| i as u8 + 1));
+    let data = core_file(
+        183,
+        true,
+        false,
+        &regs,
+        &[(NT_FPREGSET, fpregs), (NT_ARM_TLS, 0xdead_0000u64.to_le_bytes().to_vec())],
+    );
+    let core = Core::parse(&data).expect("failed to parse ARM64 core");
+    assert_eq!((core.arch, core.threads[0].pid, core.threads[0].signal), (Arch::ARM64, 1234, 11));
+    assert_eq!(core.threads[0].tls, Some(0xdead_0000));
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM64, Mode::ARM)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(core.restore(&mut emu, 0), Ok(()));
+    assert_eq!(emu.reg_read(RegisterARM64::X1), Ok(1));
+    assert_eq!(emu.reg_read(RegisterARM64::X29), Ok(29));
+    assert_eq!(emu.reg_read(RegisterARM64::X30), Ok(30));
+    assert_eq!(emu.reg_read(RegisterARM64::SP), Ok(0x7000));
+    assert_eq!(emu.reg_read(RegisterARM64::PC), Ok(0x1000));
+    assert_eq!(emu.reg_read(RegisterARM64::NZCV), Ok(0x6000_0000));
+    assert_eq!(emu.reg_read(RegisterARM64::TPIDR_EL0), Ok(0xdead_0000));
+    assert_eq!(
+        emu.reg_read_long(RegisterARM64::Q0).map(|q| q.to_vec()),
+        Ok((1..=16).collect::<Vec<u8>>())
+    );
+    let mut short = core.clone();
+    short.threads[0].registers.truncate(33);
+    assert_eq!(short.restore(&mut emu, 0), Err(uc_error::ARG));
+    assert_eq!(core.restore(&mut emu, 1), Err(uc_error::ARG));
+
+    // ARM: r0 to r15, cpsr, orig_r0; user mode and Thumb, so sp and lr go to the user
+    // bank and the PC keeps its Thumb bit
+    let mut regs: Vec<u64> = (0..18).collect();
+    regs[13] = 0x7000;
+    regs[14] = 0x1235;
+    regs[15] = 0x1000;
+    regs[16] = 0x6000_0030;
+    let mut vfp = vec![0u8; 260];
+    vfp[8..16].copy_from_slice(&0x3ff0_0000_0000_0000u64.to_le_bytes());
+    vfp[256..260].copy_from_slice(&0x0040_0000u32.to_le_bytes());
+    let data = core_file(
+        40,
+        false,
+        false,
+        &regs,
+        &[(NT_ARM_VFP, vfp), (NT_ARM_TLS, 0xbeefu32.to_le_bytes().to_vec())],
+    );
+    let core = Core::parse(&data).expect("failed to parse ARM core");
+    assert_eq!(core.threads[0].registers.len(), 18);
+    let mut emu = unicorn_engine::Unicorn::new(Arch::ARM, Mode::ARM)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(core.restore(&mut emu, 0), Ok(()));
+    assert_eq!(emu.reg_read(RegisterARM::R12), Ok(12));
+    assert_eq!(emu.reg_read(RegisterARM::SP), Ok(0x7000));
+    assert_eq!(emu.reg_read(RegisterARM::LR), Ok(0x1235));
+    assert_eq!(emu.reg_read(RegisterARM::PC), Ok(0x1000));
+    assert_eq!(emu.reg_read(RegisterARM::CPSR).map(|cpsr| cpsr & 0xf000_003f), Ok(0x6000_0030));
+    assert_eq!(emu.reg_read(RegisterARM::D1), Ok(0x3ff0_0000_0000_0000));
+    assert_eq!(emu.reg_read(RegisterARM::FPSCR), Ok(0x0040_0000));
+    assert_eq!(emu.reg_read(RegisterARM::C13_C0_3), Ok(0xbeef));
+
+    // MIPS o32: six registers of padding, r0 to r31, lo, hi, epc, badvaddr, status,
+    // cause
+    let mut regs = vec![0xdead; 6];
+    regs.extend(0..32);
+    regs.extend([5, 6, 0x1000, 0, 0, 0, 0]);
+    let mut fpregs = vec![0u8; 264];
+    fpregs[16..24].copy_from_slice(&0x4000_0000_0000_0000u64.to_be_bytes());
+    fpregs[256..260].copy_from_slice(&3u32.to_be_bytes());
+    let data = core_file(8, false, true, &regs, &[(NT_FPREGSET, fpregs)]);
+    let core = Core::parse(&data).expect("failed to parse MIPS core");
+    assert_eq!(core.threads[0].registers.len(), 45);
+    let mut emu = unicorn_engine::Unicorn::new(Arch::MIPS, Mode::MODE_32 | Mode::BIG_ENDIAN)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(core.restore(&mut emu, 0), Ok(()));
+    assert_eq!(emu.reg_read(RegisterMIPS::V0), Ok(2));
+    assert_eq!(emu.reg_read(RegisterMIPS::RA), Ok(31));
+    assert_eq!(emu.reg_read(RegisterMIPS::LO), Ok(5));
+    assert_eq!(emu.reg_read(RegisterMIPS::HI), Ok(6));
+    assert_eq!(emu.reg_read(RegisterMIPS::PC), Ok(0x1000));
+    assert_eq!(emu.reg_read(RegisterMIPS::F2), Ok(0x4000_0000_0000_0000));
+    assert_eq!(emu.reg_read(RegisterMIPS::FCR31).map(|fcr31| fcr31 & 3), Ok(3));
+
+    // RISC-V: pc, then x1 to x31
+    let mut regs: Vec<u64> = (0..32).collect();
+    regs[0] = 0x1000;
+    let mut fpregs = vec![0u8; 264];
+    fpregs[8..16].copy_from_slice(&0x4000_0000_0000_0000u64.to_le_bytes());
+    fpregs[256..260].copy_from_slice(&0x20u32.to_le_bytes());
+    let data = core_file(243, true, false, &regs, &[(NT_FPREGSET, fpregs)]);
+    let core = Core::parse(&data).expect("failed to parse RISC-V core");
+    let mut emu = unicorn_engine::Unicorn::new(Arch::RISCV, Mode::RISCV64)
+        .expect("failed to initialize unicorn instance");
+    assert_eq!(core.restore(&mut emu, 0), Ok(()));
+    assert_eq!(emu.reg_read(RegisterRISCV::PC), Ok(0x1000));
+    assert_eq!(emu.reg_read(RegisterRISCV::RA), Ok(1));
+    assert_eq!(emu.reg_read(RegisterRISCV::SP), Ok(2));
+    assert_eq!(emu.reg_read(RegisterRISCV::X31), Ok(31));
+    assert_eq!(emu.reg_read(RegisterRISCV::F1), Ok(0x4000_0000_0000_0000));
+    assert_eq!(emu.reg_read(RegisterRISCV::FCSR), Ok(0x20));
+}
+
+#[test]
+fn x86_64_core_dump() {
+    let data = x86_64_core();
+    let core = Core::parse(&data).expect("failed to parse core");
+    assert_eq!(core.threads.len(), 1);
+    assert_eq!(core.threads[0].pid, 1234);
+    assert_eq!(core.threads[0].signal, 11);
+    assert_eq!(core.auxv_value(AT_PAGESZ), Some(0x1000));
+    assert_eq!(core.auxv_value(AT_ENTRY), Some(0x40_0000));
+    let file = core.file(0x40_0800).expect("no file at the code");
+    assert_eq!((file.path.as_str(), file.offset), ("/bin/crash", 0x2000));
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_32)
+        .expect("failed to initialize unicorn instance");
+    assert!(matches!(
+        coredump::load(&mut emu, &data),
+        Err(ElfError::ArchMismatch { .. })
+    ));
+
+    let mut emu = unicorn_engine::Unicorn::new(Arch::X86, Mode::MODE_64)
+        .expect("failed to initialize unicorn instance");
+    let core = coredump::load(&mut emu, &data).expect("failed to load core");
+    assert_eq!(core.segments[0].perms, Permission::READ | Permission::EXEC);
+    assert_eq!(emu.reg_read(RegisterX86::RIP), Ok(0x40_0000));
+    assert_eq!(emu.reg_read(RegisterX86::RSP), Ok(0x7fff_0000));
+    assert_eq!(emu.reg_read(RegisterX86::MXCSR), Ok(0x1f80));
+    assert_eq!(
+        emu.reg_read_long(RegisterX86::XMM0).map(|x| x.to_vec()),
+        Ok((1..=16).collect::<Vec<u8>>())
+    );
+    assert_eq!(emu.mem_read_as_vec(0x7ffe_fff8, 8), Ok(vec![0; 8]));
+    assert_eq!(
+        emu.emu_start(0x40_0000, 0x40_0003, 0, 0),
+        Ok(EmuExit::ReachedUntil)
+    );
+    assert_eq!(emu.reg_read(RegisterX86::RAX), Ok(42));
+    assert_eq!(
+        Core::parse(&x86_elf32()).map(|_| ()),
+        Err(ElfError::BadHeader)
+    );
+}
@@ -683,6 +683,7 @@ static void *_timeout_fn(void *arg)
     // timeout before emulation is done?
     if (!uc->emulation_done) {
         uc->timed_out = true;
+        uc->exit_reason = UC_EXIT_TIMEOUT;
         // force emulation to stop
         uc_emu_stop(uc);
     }
@@ -697,18 +698,65 @@ static void enable_emu_timer(uc_engine *uc, uint64_t timeout)
                        QEMU_THREAD_JOINABLE);
 }
 
+// check the host clock only every that many blocks
+#define DEADLINE_CHECK_MASK 0x3f
+static void hook_deadline_cb(struct uc_struct *uc, uint64_t address,
+                             uint32_t size, void *user_data)
+{
+    if (++uc->deadline_ticks & DEADLINE_CHECK_MASK) {
+        return;
+    }
+
+    if (get_clock() >= uc->deadline) {
+        uc->timed_out = true;
+        uc->exit_reason = UC_EXIT_TIMEOUT;
+        uc_emu_stop(uc);
+    }
+}
+
+static uc_err enable_emu_deadline(uc_engine *uc, uint64_t timeout)
+{
+    uc_err err;
+
+    uc->timeout = timeout;
+    uc->deadline = get_clock() + timeout;
+    // make sure the very first block already checks the clock
+    uc->deadline_ticks = DEADLINE_CHECK_MASK;
+
+    if (uc->deadline_hook != 0) {
+        return UC_ERR_OK;
+    }
+
+    // like the count hook, this must run before any user hook can stop us
+    uc->hook_insert = 1;
+    err = uc_hook_add(uc, &uc->deadline_hook, UC_HOOK_BLOCK, hook_deadline_cb,
+                      NULL, 1, 0);
+    uc->hook_insert = 0;
+    if (err != UC_ERR_OK) {
+        return err;
+    }
+
+    // blocks translated so far don't call the hook yet
+    uc->tb_flush(uc);
+
+    return UC_ERR_OK;
+}
+
 static void hook_count_cb(struct uc_struct *uc, uint64_t address, uint32_t size,
                           void *user_data)
 {
-    // count this instruction. ah ah ah.
-    uc->emu_counter++;
-    // printf(":: emu counter = %u, at %lx\n", uc->emu_counter, address);
-
-    if (uc->emu_counter > uc->emu_count) {
+    // this instruction would exceed the budget, so it is not counted
+    if (uc->emu_counter >= uc->emu_count) {
         // printf(":: emu counter = %u, stop emulation\n", uc->emu_counter);
-        uc->timed_out = true;
+        uc->insn_limit = true;
+        uc->exit_reason = UC_EXIT_COUNT;
         uc_emu_stop(uc);
+        return;
     }
+
+    // count this instruction. ah ah ah.
+    uc->emu_counter++;
+    // printf(":: emu counter = %u, at %lx\n", uc->emu_counter, address);
 }
 
 static void clear_deleted_hooks(uc_engine *uc)
@@ -730,11 +778,45 @@ static void clear_deleted_hooks(uc_engine *uc)
     list_clear(&uc->hooks_to_del);
 }
 
+// AFL's hit count buckets, see UC_COV_BUCKETS
+static inline uint8_t cov_bucket(uint8_t hits)
+{
+    if (hits <= 2) {
+        return hits;
+    } else if (hits == 3) {
+        return 4;
+    } else if (hits < 8) {
+        return 8;
+    } else if (hits < 16) {
+        return 16;
+    } else if (hits < 32) {
+        return 32;
+    } else if (hits < 128) {
+        return 64;
+    }
+    return 128;
+}
+
+static void cov_classify_counts(uc_engine *uc)
+{
+    uint64_t i;
+
+    for (i = 0; i <= uc->cov_mask; i++) {
+        if (uc->cov_map[i]) {
+            uc->cov_map[i] = cov_bucket(uc->cov_map[i]);
+        }
+    }
+}
+
 UNICORN_EXPORT
 uc_err uc_emu_start(uc_engine *uc, uint64_t begin, uint64_t until,
                     uint64_t timeout, size_t count)
 {
     uc_err err;
+    // why an outer uc_emu_start() stops, if this one is nested
+    bool timed_out = uc->timed_out;
+    bool insn_limit = uc->insn_limit;
+    uc_exit_reason exit_reason = uc->exit_reason;
 
     // reset the counter
     uc->emu_counter = 0;
@@ -742,6 +824,8 @@ uc_err uc_emu_start(uc_engine *uc, uint64_t begin, uint64_t until,
     uc->emulation_done = false;
     uc->size_recur_mem = 0;
     uc->timed_out = false;
+    uc->insn_limit = false;
+    uc->exit_reason = UC_EXIT_NONE;
     uc->first_tb = true;
 
     UC_INIT(uc);
@@ -842,16 +926,24 @@ uc_err uc_emu_start(uc_engine *uc, uint64_t begin, uint64_t until,
     uc->stop_request = false;
 
     uc->emu_count = count;
+    bool block_count = count > 0 && uc->count_mode == UC_COUNT_MODE_BLOCK;
+    bool insn_count = count > 0 && !block_count;
+
     // remove count hook if counting isn't necessary
-    if (count <= 0 && uc->count_hook != 0) {
+    if (!insn_count && uc->count_hook != 0) {
         uc_hook_del(uc, uc->count_hook);
         uc->count_hook = 0;
 
         // In this case, we have to drop all translated blocks.
         uc->tb_flush(uc);
     }
+    // blocks have to be translated again to (stop to) charge the budget
+    if (block_count != uc->block_count) {
+        uc->block_count = block_count;
+        uc->tb_flush(uc);
+    }
     // set up count hook to count instructions.
-    if (count > 0 && uc->count_hook == 0) {
+    if (insn_count && uc->count_hook == 0) {
         uc_err err;
         // callback to count instructions must be run before everything else,
         // so instead of appending, we must insert the hook at the begin
@@ -873,12 +965,80 @@ uc_err uc_emu_start(uc_engine *uc, uint64_t begin, uint64_t until,
         uc->exits[uc->nested_level - 1] = until;
     }
 
-    if (timeout) {
+    // the virtual clock keeps time without the host's help
+    bool virtual_timeout = timeout && uc->deterministic;
+    bool inline_timeout = timeout && !virtual_timeout &&
+                          uc->timeout_mode == UC_TIMEOUT_MODE_INLINE;
+    uint64_t vclock_limit = uc->vclock_limit;
+    // the deadline of an outer uc_emu_start(), if it has one
+    int64_t deadline = uc->deadline_hook != 0 ? uc->deadline : INT64_MAX;
+
+    // remove deadline hook if it isn't necessary anymore, a nested
+    // uc_emu_start() keeps it for the outer one
+    if (!inline_timeout && uc->nested_level == 1 && uc->deadline_hook != 0) {
+        uc_hook_del(uc, uc->deadline_hook);
+        uc->deadline_hook = 0;
+
+        // In this case, we have to drop all translated blocks.
+        uc->tb_flush(uc);
+    }
+
+    if (virtual_timeout) {
+        // microseconds -> instructions, rounded up so the limit is never 0
+        uc->timeout = timeout * 1000;
+        uc->vclock_limit =
+            uc->vclock + (uc->timeout + uc->vclock_ns - 1) / uc->vclock_ns;
+    } else if (inline_timeout) {
+        // microseconds -> nanoseconds
+        err = enable_emu_deadline(uc, timeout * 1000);
+        if (err != UC_ERR_OK) {
+            uc->nested_level--;
+            return err;
+        }
+        // a nested uc_emu_start() still stops at the outer deadline
+        if (uc->nested_level > 1 && deadline < uc->deadline) {
+            uc->deadline = deadline;
+        }
+    } else if (timeout) {
         enable_emu_timer(uc, timeout * 1000); // microseconds -> nanoseconds
     }
 
+    // every run starts a fresh path through the coverage map, and a bucketed
+    // map only describes the last run so counts are never classified twice
+    if (uc->nested_level == 1) {
+        uc->cov_prev_loc = 0;
+        if (uc->cov_map && (uc->cov_flags & UC_COV_BUCKETS)) {
+            memset(uc->cov_map, 0, uc->cov_mask + 1);
+        }
+    }
+
     uc->vm_start(uc);
 
+    // a nested uc_emu_start() with a timeout must not cut the outer one short
+    if (virtual_timeout) {
+        uc->vclock_limit = vclock_limit;
+    }
+    if (uc->nested_level > 1) {
+        uc->deadline = deadline;
+    }
+
+    // nobody told us why we stopped, so figure it out while the exits of this
+    // nested level are still valid
+    if (uc->invalid_error != UC_ERR_OK) {
+        uc->exit_reason = UC_EXIT_FAULT;
+    } else if (uc->exit_reason == UC_EXIT_NONE &&
+               uc_addr_is_exit(uc, uc->get_pc(uc))) {
+        uc->exit_reason = uc->use_exits ? UC_EXIT_EXITS : UC_EXIT_UNTIL;
+    }
+
+    // the outer uc_emu_start() carries on after a nested one unless that timed
+    // out, so it must not take the reason the nested one stopped for as its own
+    if (uc->nested_level > 1 && !uc->timed_out) {
+        uc->timed_out = timed_out;
+        uc->insn_limit = insn_limit;
+        uc->exit_reason = exit_reason;
+    }
+
     uc->nested_level--;
 
     // emulation is done if and only if we exit the outer uc_emu_start
@@ -889,9 +1049,13 @@ uc_err uc_emu_start(uc_engine *uc, uint64_t begin, uint64_t until,
         // remove hooks to delete
         // make sure we delete all hooks at the first level.
         clear_deleted_hooks(uc);
+
+        if (uc->cov_map && (uc->cov_flags & UC_COV_BUCKETS)) {
+            cov_classify_counts(uc);
+        }
     }
 
-    if (timeout) {
+    if (timeout && !inline_timeout && !virtual_timeout) {
         // wait for the timer to finish
         qemu_thread_join(&uc->timer);
     }
@@ -912,6 +1076,11 @@ uc_err uc_emu_stop(uc_engine *uc)
         return UC_ERR_OK;
     }
 
+    // a quit request only leaves the current TB, emulation carries on
+    if (uc->exit_reason == UC_EXIT_NONE && !uc->quit_request) {
+        uc->exit_reason = UC_EXIT_STOP;
+    }
+
     uc->stop_request = true;
     // TODO: make this atomic somehow?
     if (uc->cpu) {
@@ -1863,6 +2032,73 @@ void helper_uc_traceopcode(struct hook *hook, uint64_t arg1, uint64_t arg2,
     }
 }
 
+void helper_uc_insn_budget(void *handle, uint32_t icount);
+void helper_uc_insn_budget(void *handle, uint32_t icount)
+{
+    struct uc_struct *uc = handle;
+
+    if (unlikely(uc->stop_request)) {
+        return;
+    }
+
+    // both limits are checked before the block, so the last block may overshoot
+    if (uc->vclock_limit && uc->vclock >= uc->vclock_limit) {
+        uc->timed_out = true;
+        uc->exit_reason = UC_EXIT_TIMEOUT;
+        uc_emu_stop(uc);
+        return;
+    }
+
+    if (uc->block_count) {
+        if (uc->emu_counter >= uc->emu_count) {
+            uc->insn_limit = true;
+            uc->exit_reason = UC_EXIT_COUNT;
+            uc_emu_stop(uc);
+            return;
+        }
+
+        uc->emu_counter += icount;
+    }
+
+    if (uc->deterministic) {
+        uc->vclock += icount;
+    }
+}
+
+int64_t uc_clock_get_ns(struct uc_struct *uc)
+{
+    if (uc->deterministic) {
+        return (int64_t)(uc->vclock * uc->vclock_ns);
+    }
+
+    return get_clock();
+}
+
+int64_t uc_get_ticks(struct uc_struct *uc)
+{
+    if (uc->deterministic) {
+        return (int64_t)uc->vclock;
+    }
+
+    return cpu_get_host_ticks();
+}
+
+void helper_uc_edge_cov(void *handle, uint64_t cur_loc);
+void helper_uc_edge_cov(void *handle, uint64_t cur_loc)
+{
+    struct uc_struct *uc = handle;
+    // both locations are masked, so the edge is always inside the map
+    uint8_t *hits = &uc->cov_map[cur_loc ^ uc->cov_prev_loc];
+
+    (*hits)++;
+    if (unlikely(*hits == 0) && (uc->cov_flags & UC_COV_NEVER_ZERO)) {
+        *hits = 1;
+    }
+
+    // shift so that A->B and B->A, as well as A->A and B->B, differ
+    uc->cov_prev_loc = cur_loc >> 1;
+}
+
 void helper_uc_tracecode(int32_t size, uc_hook_idx index, void *handle,
                          int64_t address);
 void helper_uc_tracecode(int32_t size, uc_hook_idx index, void *handle,
@@ -1983,6 +2219,22 @@ uc_err uc_query(uc_engine *uc, uc_query_type type, size_t *result)
     case UC_QUERY_TIMEOUT:
         *result = uc->timed_out;
         break;
+
+    case UC_QUERY_EXIT_REASON:
+        *result = uc->exit_reason;
+        break;
+
+    case UC_QUERY_FAULT_ADDR:
+        *result = uc->invalid_addr;
+        break;
+
+    case UC_QUERY_INSN_LIMIT:
+        *result = uc->insn_limit;
+        break;
+
+    case UC_QUERY_INSN_COUNT:
+        *result = uc->emu_counter;
+        break;
     }
 
     return UC_ERR_OK;
@@ -2522,6 +2774,110 @@ uc_err uc_ctl(uc_engine *uc, uc_control_type control, ...)
         }
         break;
 
+    case UC_CTL_UC_TIMEOUT_MODE: {
+        if (rw == UC_CTL_IO_READ) {
+            int *mode = va_arg(args, int *);
+            *mode = uc->timeout_mode;
+        } else {
+            int mode = va_arg(args, int);
+
+            if (mode != UC_TIMEOUT_MODE_THREAD &&
+                mode != UC_TIMEOUT_MODE_INLINE) {
+                err = UC_ERR_ARG;
+                break;
+            }
+
+            uc->timeout_mode = mode;
+        }
+        break;
+    }
+
+    case UC_CTL_UC_COUNT_MODE: {
+        if (rw == UC_CTL_IO_READ) {
+            int *mode = va_arg(args, int *);
+            *mode = uc->count_mode;
+        } else {
+            int mode = va_arg(args, int);
+
+            if (mode != UC_COUNT_MODE_INSN && mode != UC_COUNT_MODE_BLOCK) {
+                err = UC_ERR_ARG;
+                break;
+            }
+
+            uc->count_mode = mode;
+        }
+        break;
+    }
+
+    case UC_CTL_UC_COVERAGE: {
+        if (rw == UC_CTL_IO_WRITE) {
+            uint8_t *map = va_arg(args, uint8_t *);
+            size_t size = va_arg(args, size_t);
+            uint64_t begin = va_arg(args, uint64_t);
+            uint64_t end = va_arg(args, uint64_t);
+            int flags = va_arg(args, int);
+
+            if (map && (size == 0 || (size & (size - 1)) != 0 || begin > end ||
+                        (flags & ~(UC_COV_BUCKETS | UC_COV_NEVER_ZERO)))) {
+                err = UC_ERR_ARG;
+                break;
+            }
+
+            UC_INIT(uc);
+
+            uc->cov_map = map;
+            uc->cov_mask = map ? size - 1 : 0;
+            uc->cov_begin = begin;
+            uc->cov_end = end;
+            uc->cov_flags = flags;
+            uc->cov_prev_loc = 0;
+
+            // the map and the filter are baked into the generated code
+            uc->tb_flush(uc);
+        } else {
+            err = UC_ERR_ARG;
+        }
+        break;
+    }
+
+    case UC_CTL_UC_DETERMINISTIC: {
+        if (rw == UC_CTL_IO_READ) {
+            int *enable = va_arg(args, int *);
+            *enable = uc->deterministic;
+        } else {
+            int enable = va_arg(args, int);
+            uint64_t ns_per_insn = va_arg(args, uint64_t);
+
+            if (enable && ns_per_insn == 0) {
+                err = UC_ERR_ARG;
+                break;
+            }
+
+            UC_INIT(uc);
+
+            if (enable) {
+                uc->vclock_ns = ns_per_insn;
+            }
+            if (uc->deterministic != !!enable) {
+                uc->deterministic = !!enable;
+                uc->vclock_limit = 0;
+                // blocks charge the virtual clock from the generated code
+                uc->tb_flush(uc);
+            }
+        }
+        break;
+    }
+
+    case UC_CTL_UC_VIRTUAL_CLOCK: {
+        if (rw == UC_CTL_IO_READ) {
+            uint64_t *insns = va_arg(args, uint64_t *);
+            *insns = uc->vclock;
+        } else {
+            uc->vclock = va_arg(args, uint64_t);
+        }
+        break;
+    }
+
     default:
         err = UC_ERR_ARG;
         break;